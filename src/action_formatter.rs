@@ -0,0 +1,358 @@
+use crate::lang::Lang;
+use crate::line_column::LineColumn;
+use crate::solver::{CandidatesChange, SolvingAction};
+
+/// Mise en forme personnalisée des actions de résolution
+///
+/// Chaque méthode reçoit les champs d'une variante de `SolvingAction` et retourne le texte
+/// correspondant. Les méthodes non redéfinies utilisent le texte en français de `Lang::French`.
+///
+/// ```rust
+/// use tectonic::{ActionFormatter, LineColumn, SolvingAction};
+///
+/// // Formatage court : seules les actions qui placent un chiffre sont redéfinies
+/// struct Short;
+///
+/// impl ActionFormatter for Short {
+///     fn single_possible_number(&self, line_column: LineColumn, n: u8) -> String {
+///         format!("{line_column} = {n}")
+///     }
+/// }
+///
+/// let action = SolvingAction::SinglePossibleNumber(LineColumn::new(0, 1), 3);
+/// assert_eq!(action.format_with(&Short), "(lin=0, col=1) = 3");
+/// ```
+pub trait ActionFormatter {
+    /// La grille est résolue
+    fn solved(&self) -> String {
+        Lang::French.solved()
+    }
+
+    /// Initialisation des chiffres possibles pour toutes les cases
+    fn init_possible_numbers(&self) -> String {
+        Lang::French.init_possible_numbers()
+    }
+
+    /// Case avec qu'une seule possibilité de chiffre
+    fn single_possible_number(&self, line_column: LineColumn, n: u8) -> String {
+        Lang::French.single_possible_number(line_column, n)
+    }
+
+    /// Suppression des chiffres possibles d'une case qui sont déjà dans la zone de cette case
+    fn numbers_in_zone(
+        &self,
+        line_column: LineColumn,
+        c_zone: char,
+        vec_n: &[u8],
+        change: &CandidatesChange,
+    ) -> String {
+        Lang::French.numbers_in_zone(line_column, c_zone, vec_n, change)
+    }
+
+    /// Seule case possible pour un chiffre d'une zone
+    fn only_number_in_zone(&self, c_zone: char, line_column: LineColumn, n: u8) -> String {
+        Lang::French.only_number_in_zone(c_zone, line_column, n)
+    }
+
+    /// Suppression des chiffres d'une case qui sont déjà dans une de ses cases voisines
+    fn numbers_neighboring(
+        &self,
+        line_column: LineColumn,
+        vec_n: &[u8],
+        change: &CandidatesChange,
+    ) -> String {
+        Lang::French.numbers_neighboring(line_column, vec_n, change)
+    }
+
+    /// Suppression des chiffres d'une paire de valeurs dans les cases voisines
+    fn dual_values_pair(
+        &self,
+        line_column_pair_1: LineColumn,
+        line_column_pair_2: LineColumn,
+        line_column: LineColumn,
+        vec_n: &[u8],
+        change: &CandidatesChange,
+    ) -> String {
+        Lang::French.dual_values_pair(
+            line_column_pair_1,
+            line_column_pair_2,
+            line_column,
+            vec_n,
+            change,
+        )
+    }
+
+    /// Valeur forcée dans une paire de possibilités car elle mène à une solution
+    fn try_and_solve(&self, line_column: LineColumn, n_ok: u8, autre_n: u8) -> String {
+        Lang::French.try_and_solve(line_column, n_ok, autre_n)
+    }
+
+    /// Valeur supprimée d'une paire de possibilités car elle mène à une incohérence
+    fn try_and_fail(&self, line_column: LineColumn, n_fail: u8, n_ok: u8) -> String {
+        Lang::French.try_and_fail(line_column, n_fail, n_ok)
+    }
+
+    /// Ensemble d'actions appliquées en une seule étape (une action par ligne par défaut)
+    fn batch(&self, vec_actions: &[SolvingAction]) -> String {
+        vec_actions
+            .iter()
+            .map(|action| self.format_action(action))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Aucune action de résolution trouvée
+    fn no_action(&self) -> String {
+        Lang::French.no_action()
+    }
+
+    /// Code de la langue des textes (attribut `lang` de la page de `Solver::to_html_walkthrough`)
+    fn language_tag(&self) -> String {
+        Lang::French.language_tag()
+    }
+
+    /// Titre de la première étape d'une résolution pas à pas : la grille à résoudre
+    fn puzzle_step(&self) -> String {
+        Lang::French.puzzle_step()
+    }
+
+    /// Libellés des boutons pour passer à l'étape précédente et à l'étape suivante d'une
+    /// résolution pas à pas
+    fn step_buttons(&self) -> (String, String) {
+        Lang::French.step_buttons()
+    }
+
+    /// Texte d'une action : appelle la méthode correspondant à la variante de l'action
+    fn format_action(&self, action: &SolvingAction) -> String {
+        match action {
+            SolvingAction::Solved => self.solved(),
+            SolvingAction::InitPossibleNumbers => self.init_possible_numbers(),
+            SolvingAction::SinglePossibleNumber(line_column, n) => {
+                self.single_possible_number(*line_column, *n)
+            }
+            SolvingAction::NumbersInZone(line_column, c_zone, vec_n, change) => {
+                self.numbers_in_zone(*line_column, *c_zone, vec_n, change)
+            }
+            SolvingAction::OnlyNumberInZone(c_zone, line_column, n) => {
+                self.only_number_in_zone(*c_zone, *line_column, *n)
+            }
+            SolvingAction::NumbersNeighboring(line_column, vec_n, change) => {
+                self.numbers_neighboring(*line_column, vec_n, change)
+            }
+            SolvingAction::DualValuesPair(
+                line_column_pair_1,
+                line_column_pair_2,
+                line_column,
+                vec_n,
+                change,
+            ) => self.dual_values_pair(
+                *line_column_pair_1,
+                *line_column_pair_2,
+                *line_column,
+                vec_n,
+                change,
+            ),
+            SolvingAction::TryAndSolve(line_column, n_ok, autre_n) => {
+                self.try_and_solve(*line_column, *n_ok, *autre_n)
+            }
+            SolvingAction::TryAndFail(line_column, n_fail, n_ok) => {
+                self.try_and_fail(*line_column, *n_fail, *n_ok)
+            }
+            SolvingAction::Batch(vec_actions) => self.batch(vec_actions),
+            SolvingAction::NoAction => self.no_action(),
+        }
+    }
+}
+
+/// Textes des actions dans les langues prédéfinies
+impl ActionFormatter for Lang {
+    fn solved(&self) -> String {
+        match self {
+            Lang::French => "Grille résolue".to_string(),
+            Lang::English => "Grid solved".to_string(),
+        }
+    }
+
+    fn init_possible_numbers(&self) -> String {
+        match self {
+            Lang::French => "Initialisation des chiffres possibles des cases...".to_string(),
+            Lang::English => "Initializing the possible numbers of the cells...".to_string(),
+        }
+    }
+
+    fn single_possible_number(&self, line_column: LineColumn, n: u8) -> String {
+        let line_column = line_column.display_in(*self);
+        match self {
+            Lang::French => format!("[{n}] est la seule possibilité pour la case {line_column}"),
+            Lang::English => format!("[{n}] is the only possibility for cell {line_column}"),
+        }
+    }
+
+    fn numbers_in_zone(
+        &self,
+        line_column: LineColumn,
+        c_zone: char,
+        vec_n: &[u8],
+        _change: &CandidatesChange,
+    ) -> String {
+        let line_column = line_column.display_in(*self);
+        match self {
+            Lang::French => {
+                format!("{vec_n:?} déjà placé dans la zone '{c_zone}' de la case {line_column}")
+            }
+            Lang::English => {
+                format!("{vec_n:?} already placed in zone '{c_zone}' of cell {line_column}")
+            }
+        }
+    }
+
+    fn only_number_in_zone(&self, c_zone: char, line_column: LineColumn, n: u8) -> String {
+        let line_column = line_column.display_in(*self);
+        match self {
+            Lang::French => {
+                format!("Zone '{c_zone}', seule la case {line_column} est possible pour [{n}]")
+            }
+            Lang::English => format!("Zone '{c_zone}', only cell {line_column} can hold [{n}]"),
+        }
+    }
+
+    fn numbers_neighboring(
+        &self,
+        line_column: LineColumn,
+        vec_n: &[u8],
+        _change: &CandidatesChange,
+    ) -> String {
+        let line_column = line_column.display_in(*self);
+        match self {
+            Lang::French => {
+                format!("{vec_n:?} est dans les cases voisines de la case {line_column}")
+            }
+            Lang::English => format!("{vec_n:?} is in the neighboring cells of cell {line_column}"),
+        }
+    }
+
+    fn dual_values_pair(
+        &self,
+        line_column_pair_1: LineColumn,
+        line_column_pair_2: LineColumn,
+        line_column: LineColumn,
+        vec_n: &[u8],
+        _change: &CandidatesChange,
+    ) -> String {
+        let line_column_pair_1 = line_column_pair_1.display_in(*self);
+        let line_column_pair_2 = line_column_pair_2.display_in(*self);
+        let line_column = line_column.display_in(*self);
+        match self {
+            Lang::French => format!(
+                "{vec_n:?} impossible dans la case {line_column} selon les cases voisines {line_column_pair_1} et {line_column_pair_2}"
+            ),
+            Lang::English => format!(
+                "{vec_n:?} impossible in cell {line_column} given the neighboring cells {line_column_pair_1} and {line_column_pair_2}"
+            ),
+        }
+    }
+
+    fn try_and_solve(&self, line_column: LineColumn, n_ok: u8, autre_n: u8) -> String {
+        let line_column = line_column.display_in(*self);
+        match self {
+            Lang::French => format!(
+                "Entre [{n_ok}] et [{autre_n}] pour {line_column}, [{n_ok}] mène à une solution"
+            ),
+            Lang::English => format!(
+                "Between [{n_ok}] and [{autre_n}] for {line_column}, [{n_ok}] leads to a solution"
+            ),
+        }
+    }
+
+    fn try_and_fail(&self, line_column: LineColumn, n_fail: u8, n_ok: u8) -> String {
+        let line_column = line_column.display_in(*self);
+        match self {
+            Lang::French => format!(
+                "[{n_ok}] est placé pour {line_column} car le choix de [{n_fail}] mène à une incohérence"
+            ),
+            Lang::English => format!(
+                "[{n_ok}] is placed in {line_column} because choosing [{n_fail}] leads to a contradiction"
+            ),
+        }
+    }
+
+    fn no_action(&self) -> String {
+        match self {
+            Lang::French => "Aucune action de résolution trouvée".to_string(),
+            Lang::English => "No solving action found".to_string(),
+        }
+    }
+
+    fn language_tag(&self) -> String {
+        match self {
+            Lang::French => "fr".to_string(),
+            Lang::English => "en".to_string(),
+        }
+    }
+
+    fn puzzle_step(&self) -> String {
+        match self {
+            Lang::French => "Grille à résoudre".to_string(),
+            Lang::English => "Grid to solve".to_string(),
+        }
+    }
+
+    fn step_buttons(&self) -> (String, String) {
+        match self {
+            Lang::French => ("Précédent".to_string(), "Suivant".to_string()),
+            Lang::English => ("Previous".to_string(), "Next".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    /// Formatage HTML des cases
+    struct Html;
+
+    impl ActionFormatter for Html {
+        fn single_possible_number(&self, line_column: LineColumn, n: u8) -> String {
+            format!("<b>{n}</b> en {line_column}")
+        }
+
+        fn batch(&self, vec_actions: &[SolvingAction]) -> String {
+            vec_actions
+                .iter()
+                .map(|action| format!("<li>{}</li>", self.format_action(action)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_custom_formatter() {
+        let line_column = LineColumn::new(1, 0);
+        let action = SolvingAction::Batch(vec![
+            SolvingAction::SinglePossibleNumber(line_column, 2),
+            SolvingAction::Solved,
+        ]);
+        assert_eq!(
+            action.format_with(&Html),
+            format!("<li><b>2</b> en {line_column}</li><li>Grille résolue</li>")
+        );
+    }
+
+    #[test]
+    fn test_lang_formatter() {
+        let action = SolvingAction::NumbersNeighboring(
+            LineColumn::new(2, 3),
+            vec![1, 4],
+            CandidatesChange {
+                before: vec![1, 2, 4],
+                after: vec![2],
+            },
+        );
+        assert_eq!(action.format_with(&Lang::French), action.to_string());
+        assert_eq!(
+            action.format_with(&Lang::English),
+            action.display_in(Lang::English).to_string()
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use crate::solver::{SolveOutcome, Solver, SolvingError, SolvingStrategy};
+use crate::solver_config::SolverConfig;
+
+/// Classement d'une grille selon le raisonnement nécessaire à sa résolution
+/// (voir `Solver::classify`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogicClassification {
+    /// La grille se résout par déduction, sans recherche par 'essai'
+    LogicSolvable,
+
+    /// La grille nécessite des recherches par 'essai' avec ce niveau minimal de récursion
+    GuessRequired {
+        /// Niveau minimal de récursion des recherches par 'essai' pour résoudre la grille
+        min_recursion: i32,
+    },
+
+    /// La grille n'est pas résolue, même avec le niveau max de récursion demandé
+    Unsolved,
+}
+
+impl LogicClassification {
+    /// Retourne true si la grille se résout par déduction, sans recherche par 'essai'
+    #[must_use]
+    pub fn is_logic_solvable(&self) -> bool {
+        matches!(self, LogicClassification::LogicSolvable)
+    }
+}
+
+impl fmt::Display for LogicClassification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogicClassification::LogicSolvable => write!(f, "Résolution par déduction"),
+            LogicClassification::GuessRequired { min_recursion } => write!(
+                f,
+                "Résolution par 'essai' (niveau de récursion minimal : {min_recursion})"
+            ),
+            LogicClassification::Unsolved => write!(f, "Non résolue"),
+        }
+    }
+}
+
+impl Solver {
+    /// Classe la grille d'origine du solver : grille résoluble par déduction seule ou grille qui
+    /// nécessite des recherches par 'essai' (avec le niveau minimal de récursion nécessaire)
+    ///
+    /// Les niveaux de récursion de 1 à `max_recursion` sont essayés successivement. La grille est
+    /// résolue à partir de son état d'origine : l'état de travail du solver n'est pas modifié.
+    /// # Errors
+    /// Une erreur est retournée si la grille n'est pas cohérente
+    pub fn classify(&self, max_recursion: i32) -> Result<LogicClassification, SolvingError> {
+        // Résolution sans recherche par 'essai'
+        let logic_strategies = SolvingStrategy::ALL
+            .into_iter()
+            .filter(|strategy| *strategy != SolvingStrategy::TryAndSee);
+        let config = SolverConfig::new().strategies(logic_strategies);
+        match Solver::new(self.shared_original_grid()).solve(&config) {
+            SolveOutcome::Solved => return Ok(LogicClassification::LogicSolvable),
+            SolveOutcome::Inconsistent(e) => return Err(e),
+            SolveOutcome::Stuck { .. } => (),
+        }
+
+        // Résolution avec un niveau de récursion croissant
+        for min_recursion in 1..=max_recursion {
+            let config = SolverConfig::new().max_recursion(min_recursion);
+            match Solver::new(self.shared_original_grid()).solve(&config) {
+                SolveOutcome::Solved => {
+                    return Ok(LogicClassification::GuessRequired { min_recursion })
+                }
+                SolveOutcome::Inconsistent(e) => return Err(e),
+                SolveOutcome::Stuck { .. } => (),
+            }
+        }
+
+        Ok(LogicClassification::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_classify_logic_solvable() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let solver = Solver::new(&grid);
+        let classification = solver.classify(3).unwrap();
+        assert_eq!(classification, LogicClassification::LogicSolvable);
+        assert!(classification.is_logic_solvable());
+
+        // L'état de travail du solver n'est pas modifié
+        assert!(solver.history().is_empty());
+    }
+
+    #[test]
+    fn test_classify_guess_required() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        let solver = Solver::new(&grid);
+        assert_eq!(
+            solver.classify(3).unwrap(),
+            LogicClassification::GuessRequired { min_recursion: 1 }
+        );
+
+        // Sans recherche par 'essai', la grille n'est pas résolue
+        assert_eq!(solver.classify(0).unwrap(), LogicClassification::Unsolved);
+    }
+
+    #[test]
+    fn test_classify_inconsistent() {
+        let grid = Grid::from_str(
+            "
+        a1 a1
+        ",
+        )
+        .unwrap();
+
+        let solver = Solver::new(&grid);
+        assert!(solver.classify(3).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+
+impl Grid {
+    /// Grille de solution (page de solutions d'un recueil, vérification automatique, etc.) : les
+    /// chiffres seuls, une ligne de texte par ligne de la grille, sans les noms des zones
+    ///
+    /// ```text
+    /// 132
+    /// 456
+    /// ```
+    ///
+    /// Une case sans chiffre est écrite '.' et une case absente de la grille ' '. Avec
+    /// `comma_separated`, les chiffres sont séparés par des virgules (comme en CSV) et une case
+    /// sans chiffre ou absente de la grille est vide : "1,3,2"
+    #[must_use]
+    pub fn to_answer_key(&self, comma_separated: bool) -> String {
+        let mut res = String::new();
+        for line in self.min_line_column.line..=self.max_line_column.line {
+            let vec_digits: Vec<String> = (self.min_line_column.column
+                ..=self.max_line_column.column)
+                .map(|column| {
+                    let option_cell = self.get_cell(LineColumn::new(line, column));
+                    match (option_cell.map(|cell| &cell.content), comma_separated) {
+                        (Some(CellContent::Number(n)), _) => n.to_string(),
+                        (_, true) => String::new(),
+                        (Some(_), false) => ".".to_string(),
+                        (None, false) => " ".to_string(),
+                    }
+                })
+                .collect();
+            let separator = if comma_separated { "," } else { "" };
+            res.push_str(vec_digits.join(separator).trim_end());
+            res.push('\n');
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{Grid, LineColumn, Solver, SolverConfig};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_answer_key() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(grid.to_answer_key(false), "1.2\n4..\n..2\n");
+        assert_eq!(grid.to_answer_key(true), "1,,2\n4,,\n,,2\n");
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::new()).is_solved());
+        assert_eq!(solver.to_grid().to_answer_key(false), "132\n451\n132\n");
+        assert_eq!(
+            solver.to_grid().to_answer_key(true),
+            "1,3,2\n4,5,1\n1,3,2\n"
+        );
+
+        // Case absente de la grille
+        let mut grid = Grid::from_str("a1 b1\nc1 d1").unwrap();
+        grid.remove_cell(LineColumn::new(1, 0));
+        assert_eq!(grid.to_answer_key(false), "11\n 1\n");
+        assert_eq!(grid.to_answer_key(true), "1,1\n,1\n");
+    }
+}
@@ -0,0 +1,159 @@
+use crate::grid::{normalized_text, Grid, ParseGridError};
+
+/// Caractères d'une bordure verticale entre deux cases
+const VERTICAL_WALLS: &[char] = &['|', '│', '┃', '║'];
+
+/// Caractères d'une bordure horizontale entre deux cases
+const HORIZONTAL_WALLS: &[char] = &['-', '─', '━', '═'];
+
+impl Grid {
+    /// Construction d'une grille dessinée en ASCII (ou avec des caractères Unicode de dessin de
+    /// boîtes) où les bordures des zones sont tracées autour des cases :
+    ///
+    /// ```text
+    /// +---+---+---+
+    /// | 1 |     2 |
+    /// +---+       +
+    /// | 4   .     |
+    /// +---+---+---+
+    /// |       . 2 |
+    /// +---+---+---+
+    /// ```
+    ///
+    /// Les lignes de bordures (avec `+`, `-` ou des caractères de coins et de traits) alternent
+    /// avec les lignes de cases. Toutes les cases ont la même largeur, donnée par le plus petit
+    /// écart entre deux coins d'une ligne de bordure. Une case contient un chiffre, un point ou
+    /// rien. Deux cases voisines sont dans la même zone s'il n'y a pas de bordure entre elles
+    /// (voir `from_walls`).
+    ///
+    /// Les lignes vides et les lignes de commentaires (qui commencent par `#`) sont ignorées
+    ///
+    /// # Errors
+    /// Une erreur est retournée (ligne, colonne et texte de la case) si une case ne contient pas
+    /// un chiffre, un point ou rien, ou si une ligne de cases est incomplète
+    pub fn from_ascii_art(s: &str) -> Result<Grid, ParseGridError> {
+        let vec_lines: Vec<Vec<char>> = normalized_text(s)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| line.chars().collect())
+            .collect();
+        let Some(first_line) = vec_lines.first() else {
+            return Ok(Grid::default());
+        };
+
+        // Largeur d'une case : plus petit écart entre deux coins d'une ligne de bordure
+        let mut cell_width = usize::MAX;
+        for border_line in vec_lines.iter().step_by(2) {
+            let vec_corner: Vec<usize> = border_line
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| !HORIZONTAL_WALLS.contains(c) && **c != ' ')
+                .map(|(index, _)| index)
+                .collect();
+            for corners in vec_corner.windows(2) {
+                cell_width = cell_width.min(corners[1] - corners[0]);
+            }
+        }
+        if cell_width == usize::MAX {
+            return Err(ParseGridError::InvalidLine(first_line.iter().collect()));
+        }
+
+        // Position des coins (limites des colonnes de cases)
+        let corners: Vec<usize> = (0..first_line.len()).step_by(cell_width).collect();
+
+        let mut vec_values: Vec<Vec<Option<u8>>> = Vec::new();
+        let mut vec_walls: Vec<Vec<u8>> = Vec::new();
+        for (index, cells_line) in vec_lines.iter().enumerate().skip(1).step_by(2) {
+            let line = i32::try_from(vec_values.len()).unwrap_or(i32::MAX);
+            let bottom_line = vec_lines.get(index + 1);
+            let mut line_values = Vec::new();
+            let mut line_walls = Vec::new();
+            for (column, bounds) in corners.windows(2).enumerate() {
+                let (left, right) = (bounds[0], bounds[1]);
+                let text: String = cells_line
+                    .get(left + 1..right.min(cells_line.len()))
+                    .unwrap_or_default()
+                    .iter()
+                    .collect();
+                let column = i32::try_from(column).unwrap_or(i32::MAX);
+                if cells_line.len() <= right {
+                    return Err(ParseGridError::shape_mismatch(line, column, text.trim()));
+                }
+                let value = match text.trim() {
+                    "" | "." => None,
+                    token => Some(ParseGridError::parse_digit(line, column, token)?),
+                };
+
+                let mut walls = 0;
+                if VERTICAL_WALLS.contains(&cells_line[right]) {
+                    walls |= Grid::WALL_RIGHT;
+                }
+                let is_bottom_wall = bottom_line.is_none_or(|bottom_line| {
+                    bottom_line
+                        .get(left + 1..right.min(bottom_line.len()))
+                        .is_some_and(|chars| chars.iter().any(|c| HORIZONTAL_WALLS.contains(c)))
+                });
+                if is_bottom_wall {
+                    walls |= Grid::WALL_BOTTOM;
+                }
+                line_values.push(value);
+                line_walls.push(walls);
+            }
+            vec_values.push(line_values);
+            vec_walls.push(line_walls);
+        }
+
+        let values: Vec<&[Option<u8>]> = vec_values.iter().map(Vec::as_slice).collect();
+        let walls: Vec<&[u8]> = vec_walls.iter().map(Vec::as_slice).collect();
+        Grid::from_walls(&values, &walls)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_ascii_art() {
+        let grid = Grid::from_ascii_art(
+            "
+        # Grille dessinée avec des bordures
+        +---+---+---+
+        | 1 |     2 |
+        +---+       +
+        | 4   .     |
+        +---+---+---+
+        |       . 2 |
+        +---+---+---+
+        ",
+        )
+        .unwrap();
+        assert_eq!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap());
+
+        let grid = Grid::from_ascii_art(
+            "
+        ┌───┬───────┐
+        │ 1 │     2 │
+        ├───┘       │
+        │ 4   .     │
+        ├───────────┤
+        │         2 │
+        └───────────┘
+        ",
+        )
+        .unwrap();
+        assert_eq!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap());
+    }
+
+    #[test]
+    fn test_from_ascii_art_nok() {
+        let error = Grid::from_ascii_art("+---+---+\n| 1 | x |\n+---+---+").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (0, 1, "x"));
+
+        let error = Grid::from_ascii_art("+---+---+\n| 1 |\n+---+---+").unwrap_err();
+        assert_eq!((error.line(), error.column()), (0, 1));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::backtracking::BacktrackingSolver;
+use crate::dlx::DlxSolver;
+use crate::grid::Grid;
+use crate::solver::{SolveOutcome, Solver, SolvingError};
+use crate::solver_config::SolverConfig;
+
+/// Interface commune aux différents moteurs de résolution d'une grille tectonic
+///
+/// Ce trait est implémenté par :
+/// * `Solver` : résolution 'logique' étape par étape (avec explications)
+/// * `BacktrackingSolver` : recherche exhaustive
+/// * `DlxSolver` : couverture exacte par 'Dancing Links'
+///
+/// Cela permet de changer de moteur de résolution sans modifier le code appelant.
+pub trait SolverBackend {
+    /// Tente de résoudre la grille
+    fn solve(&mut self) -> SolveOutcome;
+
+    /// Compte le nombre de solutions de la grille (au plus `limit`)
+    fn count_solutions(&mut self, limit: usize) -> usize;
+
+    /// Vérifie la cohérence de la grille
+    /// # Errors
+    /// Une erreur est retournée si la grille n'est pas cohérente
+    fn check(&self) -> Result<(), SolvingError>;
+
+    /// Etat courant de la grille
+    fn grid(&self) -> Grid;
+}
+
+impl SolverBackend for Solver {
+    fn solve(&mut self) -> SolveOutcome {
+        Solver::solve(self, &SolverConfig::default())
+    }
+
+    fn count_solutions(&mut self, limit: usize) -> usize {
+        BacktrackingSolver::new(&SolverBackend::grid(self)).count_solutions(limit)
+    }
+
+    fn check(&self) -> Result<(), SolvingError> {
+        Solver::check(self)
+    }
+
+    fn grid(&self) -> Grid {
+        self.to_grid()
+    }
+}
+
+impl SolverBackend for BacktrackingSolver {
+    fn solve(&mut self) -> SolveOutcome {
+        if let Err(e) = SolverBackend::check(self) {
+            return SolveOutcome::Inconsistent(e);
+        }
+        match BacktrackingSolver::solve(self) {
+            None => SolveOutcome::Inconsistent(SolvingError::NoSolution),
+            Some(grid) => {
+                *self = BacktrackingSolver::new(&grid);
+                SolveOutcome::Solved
+            }
+        }
+    }
+
+    fn count_solutions(&mut self, limit: usize) -> usize {
+        BacktrackingSolver::count_solutions(self, limit)
+    }
+
+    fn check(&self) -> Result<(), SolvingError> {
+        Solver::new(self.shared_grid()).check()
+    }
+
+    fn grid(&self) -> Grid {
+        BacktrackingSolver::grid(self).clone()
+    }
+}
+
+impl SolverBackend for DlxSolver {
+    fn solve(&mut self) -> SolveOutcome {
+        if let Err(e) = SolverBackend::check(self) {
+            return SolveOutcome::Inconsistent(e);
+        }
+        match DlxSolver::solve(self) {
+            None => SolveOutcome::Inconsistent(SolvingError::NoSolution),
+            Some(grid) => {
+                *self = DlxSolver::new(&grid);
+                SolveOutcome::Solved
+            }
+        }
+    }
+
+    fn count_solutions(&mut self, limit: usize) -> usize {
+        DlxSolver::count_solutions(self, limit)
+    }
+
+    fn check(&self) -> Result<(), SolvingError> {
+        Solver::new(self.shared_grid()).check()
+    }
+
+    fn grid(&self) -> Grid {
+        DlxSolver::grid(self).clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::CellContent;
+    use crate::line_column::LineColumn;
+    use std::str::FromStr;
+
+    /// Liste des moteurs de résolution pour une grille
+    fn backends(grid: &Grid) -> Vec<Box<dyn SolverBackend>> {
+        vec![
+            Box::new(Solver::new(grid)),
+            Box::new(BacktrackingSolver::new(grid)),
+            Box::new(DlxSolver::new(grid)),
+        ]
+    }
+
+    #[test]
+    fn test_backends_solve() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        let mut vec_solutions = Vec::new();
+        for mut backend in backends(&grid) {
+            assert!(backend.check().is_ok());
+            assert_eq!(backend.count_solutions(2), 1);
+            assert!(backend.solve().is_solved());
+            vec_solutions.push(backend.grid());
+        }
+
+        // Tous les moteurs trouvent la même solution
+        for line_column in grid.hashmap_cells.keys() {
+            let contents: Vec<&CellContent> = vec_solutions
+                .iter()
+                .map(|solution| &solution.get_cell(*line_column).unwrap().content)
+                .collect();
+            assert!(matches!(contents[0], CellContent::Number(_)));
+            assert!(contents.iter().all(|content| *content == contents[0]));
+        }
+        let cell = vec_solutions[0].get_cell(LineColumn::new(0, 1)).unwrap();
+        assert_eq!(cell.content, CellContent::Number(5));
+    }
+
+    #[test]
+    fn test_backends_inconsistent() {
+        let grid = Grid::from_str(
+            "
+        # NOK car a1 et b1 sont voisins
+        a1 b  b2
+        b4 b1 b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        for mut backend in backends(&grid) {
+            assert!(backend.check().is_err());
+            assert_eq!(backend.count_solutions(2), 0);
+            assert!(matches!(backend.solve(), SolveOutcome::Inconsistent(_)));
+        }
+    }
+}
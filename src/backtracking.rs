@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::neighboring_line_columns::NeighboringLineColumns;
+use crate::simple_09_set::Simple09Set;
+
+/// Résolution d'une grille tectonic par une recherche exhaustive (backtracking)
+///
+/// A chaque étape, la case avec le moins de chiffres possibles est choisie et tous ses chiffres
+/// possibles sont essayés.
+/// Comme `DlxSolver`, ce solver ne donne aucune explication mais permet de trouver une solution
+/// ou de compter les solutions d'une grille.
+#[derive(Clone, Debug)]
+pub struct BacktrackingSolver {
+    /// Grille tectonic
+    grid: Arc<Grid>,
+
+    /// Cases de la grille (triées par ligne puis colonne)
+    vec_line_columns: Vec<LineColumn>,
+
+    /// Chiffre de chaque case (0 si pas encore connu)
+    values: Vec<u8>,
+
+    /// Taille de la zone de chaque case
+    zone_sizes: Vec<usize>,
+
+    /// Index des autres cases de la zone de chaque case
+    zone_members: Vec<Vec<usize>>,
+
+    /// Index des cases voisines de chaque case
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl BacktrackingSolver {
+    /// Constructeur du solver d'après une grille
+    #[must_use]
+    pub fn new(grid: &Grid) -> Self {
+        let mut vec_line_columns: Vec<LineColumn> = grid.hashmap_cells.keys().copied().collect();
+        vec_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+        let indexes: HashMap<LineColumn, usize> = vec_line_columns
+            .iter()
+            .enumerate()
+            .map(|(index, line_column)| (*line_column, index))
+            .collect();
+
+        let mut values = Vec::new();
+        let mut zone_sizes = Vec::new();
+        let mut zone_members = Vec::new();
+        let mut neighbors = Vec::new();
+        for line_column in &vec_line_columns {
+            let cell = grid.get_cell(*line_column).unwrap();
+            values.push(match cell.content {
+                CellContent::Number(n) => n,
+                _ => 0,
+            });
+
+            let zone_line_columns = grid
+                .hashmap_zones
+                .get(&cell.c_zone)
+                .map(|zone| zone.set_line_column.clone())
+                .unwrap_or_default();
+            zone_sizes.push(zone_line_columns.len());
+            zone_members.push(
+                zone_line_columns
+                    .iter()
+                    .filter(|zone_line_column| *zone_line_column != line_column)
+                    .filter_map(|zone_line_column| indexes.get(zone_line_column).copied())
+                    .collect(),
+            );
+
+            neighbors.push(
+                NeighboringLineColumns::new(
+                    *line_column,
+                    grid.min_line_column,
+                    grid.max_line_column,
+                )
+                .filter_map(|neighboring_line_column| {
+                    indexes.get(&neighboring_line_column).copied()
+                })
+                .collect(),
+            );
+        }
+
+        BacktrackingSolver {
+            grid: Arc::new(grid.clone()),
+            vec_line_columns,
+            values,
+            zone_sizes,
+            zone_members,
+            neighbors,
+        }
+    }
+
+    /// Grille tectonic à résoudre (`solve` retourne une copie résolue sans modifier cette grille)
+    #[must_use]
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Grille tectonic partagée, pour construire un solver sans la copier
+    pub(crate) fn shared_grid(&self) -> Arc<Grid> {
+        Arc::clone(&self.grid)
+    }
+
+    /// Recherche une solution de la grille
+    /// Retourne None si la grille n'a pas de solution
+    #[must_use]
+    pub fn solve(&self) -> Option<Grid> {
+        if !self.is_consistent(&self.values) {
+            return None;
+        }
+        let mut values = self.values.clone();
+        let mut first_solution = None;
+        self.search(&mut values, 1, &mut first_solution);
+
+        first_solution.map(|values| {
+            let mut grid = Grid::clone(&self.grid);
+            for (line_column, n) in self.vec_line_columns.iter().zip(values) {
+                let cell = grid.get_mut_cell(*line_column).unwrap();
+                cell.content = CellContent::Number(n);
+            }
+            grid
+        })
+    }
+
+    /// Compte le nombre de solutions de la grille
+    /// La recherche s'arrête dès que `limit` solutions sont trouvées
+    #[must_use]
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        if limit == 0 || !self.is_consistent(&self.values) {
+            return 0;
+        }
+        let mut values = self.values.clone();
+        self.search(&mut values, limit, &mut None)
+    }
+
+    /// Compte le nombre de solutions de la grille en répartissant la recherche sur plusieurs threads
+    ///
+    /// L'arbre de recherche est développé jusqu'à avoir au moins autant de branches que de threads
+    /// et chaque branche est explorée en parallèle (avec la feature `rayon`, sinon l'exploration
+    /// est séquentielle). Les branches partagent le nombre de solutions trouvées et s'arrêtent
+    /// toutes dès que `limit` solutions sont trouvées.
+    /// Le résultat est le même que `count_solutions`
+    #[must_use]
+    pub fn count_solutions_parallel(&self, limit: usize) -> usize {
+        if limit == 0 || !self.is_consistent(&self.values) {
+            return 0;
+        }
+
+        #[cfg(feature = "rayon")]
+        let nb_threads = rayon::current_num_threads();
+        #[cfg(not(feature = "rayon"))]
+        let nb_threads = 1;
+
+        // Développement de l'arbre de recherche, niveau par niveau
+        let found = AtomicUsize::new(0);
+        let mut branches = vec![self.values.clone()];
+        while branches.len() < nb_threads {
+            let mut next_branches = Vec::new();
+            let mut expanded = false;
+            for values in branches {
+                if let Some((index, simple_09_set)) = self.choose_cell(&values) {
+                    expanded = true;
+                    for n in simple_09_set.as_vec_u8() {
+                        let mut next_values = values.clone();
+                        next_values[index] = n;
+                        next_branches.push(next_values);
+                    }
+                } else {
+                    // Toutes les cases sont renseignées : c'est une solution
+                    found.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            branches = next_branches;
+            if !expanded {
+                break;
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            branches
+                .into_par_iter()
+                .for_each(|mut values| self.search_shared(&mut values, limit, &found));
+        }
+        #[cfg(not(feature = "rayon"))]
+        for mut values in branches {
+            self.search_shared(&mut values, limit, &found);
+        }
+
+        found.into_inner().min(limit)
+    }
+
+    /// Chiffres possibles pour une case d'après les chiffres déjà placés dans sa zone et
+    /// dans les cases voisines
+    fn possible_numbers(&self, values: &[u8], index: usize) -> Simple09Set {
+        let mut simple_09_set = Simple09Set::default();
+        for n in 1..=self.zone_sizes[index].min(9) {
+            #[allow(clippy::cast_possible_truncation)]
+            simple_09_set.insert(n as u8);
+        }
+        for other in self.zone_members[index]
+            .iter()
+            .chain(self.neighbors[index].iter())
+        {
+            simple_09_set.remove(values[*other]);
+        }
+        simple_09_set
+    }
+
+    /// Vérifie que les chiffres placés sont compatibles entre eux
+    fn is_consistent(&self, values: &[u8]) -> bool {
+        values.iter().enumerate().all(|(index, n)| {
+            *n == 0
+                || (usize::from(*n) <= self.zone_sizes[index]
+                    && self.zone_members[index]
+                        .iter()
+                        .chain(self.neighbors[index].iter())
+                        .all(|other| values[*other] != *n))
+        })
+    }
+
+    /// Choix de la prochaine case à essayer : celle avec le moins de chiffres possibles
+    /// Retourne None si toutes les cases sont renseignées
+    fn choose_cell(&self, values: &[u8]) -> Option<(usize, Simple09Set)> {
+        let mut best: Option<(usize, Simple09Set)> = None;
+        for (index, n) in values.iter().enumerate() {
+            if *n == 0 {
+                let simple_09_set = self.possible_numbers(values, index);
+                if best.is_none_or(|(_, best_set)| simple_09_set.len() < best_set.len()) {
+                    best = Some((index, simple_09_set));
+                    if simple_09_set.len() <= 1 {
+                        break;
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    /// Recherche récursive des solutions
+    /// Retourne le nombre de solutions trouvées (au plus `limit`)
+    fn search(
+        &self,
+        values: &mut [u8],
+        limit: usize,
+        first_solution: &mut Option<Vec<u8>>,
+    ) -> usize {
+        let Some((index, simple_09_set)) = self.choose_cell(values) else {
+            // Toutes les cases sont renseignées : c'est une solution
+            if first_solution.is_none() {
+                *first_solution = Some(values.to_vec());
+            }
+            return 1;
+        };
+
+        let mut count = 0;
+        for n in simple_09_set.as_vec_u8() {
+            values[index] = n;
+            count += self.search(values, limit - count, first_solution);
+            if count >= limit {
+                break;
+            }
+        }
+        values[index] = 0;
+        count
+    }
+
+    /// Recherche récursive des solutions d'une branche de `count_solutions_parallel`
+    /// Les solutions sont comptées dans `found`, partagé par toutes les branches : la recherche
+    /// s'arrête dès que `limit` solutions sont trouvées
+    fn search_shared(&self, values: &mut [u8], limit: usize, found: &AtomicUsize) {
+        if found.load(Ordering::Relaxed) >= limit {
+            return;
+        }
+        let Some((index, simple_09_set)) = self.choose_cell(values) else {
+            found.fetch_add(1, Ordering::Relaxed);
+            return;
+        };
+
+        for n in simple_09_set.as_vec_u8() {
+            values[index] = n;
+            self.search_shared(values, limit, found);
+            if found.load(Ordering::Relaxed) >= limit {
+                break;
+            }
+        }
+        values[index] = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_backtracking_solve() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let solver = BacktrackingSolver::new(&grid);
+        let solution = solver.solve().unwrap();
+        let cell = solution.get_cell(LineColumn::new(1, 1)).unwrap();
+        assert_eq!(cell.content, CellContent::Number(5));
+        assert_eq!(solver.count_solutions(10), 1);
+        assert_eq!(solver.count_solutions_parallel(10), 1);
+    }
+
+    #[test]
+    fn test_backtracking_count_solutions() {
+        let grid = Grid::from_str(
+            "
+        a  b  b  b
+        ",
+        )
+        .unwrap();
+        let solver = BacktrackingSolver::new(&grid);
+        assert_eq!(solver.count_solutions(10), 4);
+        assert_eq!(solver.count_solutions(2), 2);
+        assert_eq!(solver.count_solutions_parallel(10), 4);
+        assert_eq!(solver.count_solutions_parallel(3), 3);
+        assert_eq!(solver.count_solutions_parallel(0), 0);
+
+        // Grille avec beaucoup de solutions : plus de branches que de chiffres possibles pour
+        // la première case
+        let grid = Grid::from_str(
+            "
+        a  a  a  a  a
+        ",
+        )
+        .unwrap();
+        let solver = BacktrackingSolver::new(&grid);
+        let nb_solutions = solver.count_solutions(usize::MAX);
+        assert_eq!(nb_solutions, 120);
+        assert_eq!(solver.count_solutions_parallel(usize::MAX), nb_solutions);
+        for limit in 1..=nb_solutions {
+            assert_eq!(solver.count_solutions_parallel(limit), limit);
+        }
+
+        // Grille incohérente
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b1 b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        let solver = BacktrackingSolver::new(&grid);
+        assert_eq!(solver.count_solutions(10), 0);
+        assert_eq!(solver.count_solutions_parallel(10), 0);
+        assert!(solver.solve().is_none());
+
+        // Grille complète mais incohérente
+        let grid = Grid::from_str("a1 b1").unwrap();
+        let solver = BacktrackingSolver::new(&grid);
+        assert_eq!(solver.count_solutions(3), 0);
+        assert!(solver.solve().is_none());
+    }
+}
@@ -0,0 +1,329 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::encoding::NO_CELL;
+use crate::grid::{CellContent, Grid, ParseGridError};
+use crate::line_column::LineColumn;
+
+/// Début de l'en-tête du format binaire
+const MAGIC: &[u8; 2] = b"TG";
+
+/// Version du format binaire écrite par `Grid::to_binary` (3e octet de l'en-tête)
+pub const BINARY_GRID_VERSION: u8 = 1;
+
+/// Indicateur (4e octet de l'en-tête) de la présence de la solution après les cases
+const FLAG_SOLUTION: u8 = 0x01;
+
+/// Taille de l'en-tête : début, version, indicateurs, largeur et hauteur (sur 2 octets chacune)
+const HEADER_LEN: usize = 8;
+
+/// Nombre de bits du code de chaque position (voir `Grid::position_codes`)
+const CODE_BITS: usize = 6;
+
+/// Nombre de bits du chiffre de chaque case de la solution
+const DIGIT_BITS: usize = 4;
+
+/// Erreur rencontrée lors de la lecture d'une grille au format binaire
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BinaryGridError {
+    /// Les octets ne commencent pas par l'en-tête du format binaire
+    InvalidHeader,
+
+    /// La version du format n'est pas connue
+    UnsupportedVersion(u8),
+
+    /// Le nombre d'octets ne correspond pas aux dimensions de la grille
+    InvalidLength,
+
+    /// Le code d'une case ou un chiffre de la solution est incorrect
+    Parse(ParseGridError),
+}
+
+impl fmt::Display for BinaryGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryGridError::InvalidHeader => write!(f, "En-tête du format binaire absent"),
+            BinaryGridError::UnsupportedVersion(version) => write!(
+                f,
+                "Version {version} du format binaire inconnue (version {BINARY_GRID_VERSION} \
+                 attendue)"
+            ),
+            BinaryGridError::InvalidLength => write!(
+                f,
+                "Le nombre d'octets ne correspond pas aux dimensions de la grille"
+            ),
+            BinaryGridError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for BinaryGridError {}
+
+impl From<ParseGridError> for BinaryGridError {
+    fn from(e: ParseGridError) -> Self {
+        BinaryGridError::Parse(e)
+    }
+}
+
+/// Valeurs de `bits` bits écrites à la suite (bit de poids fort en premier), le dernier octet
+/// étant complété par des 0
+fn pack(values: &[u8], bits: usize) -> Vec<u8> {
+    let mut res = Vec::with_capacity((values.len() * bits).div_ceil(8));
+    let (mut buffer, mut count) = (0_u32, 0);
+    for value in values {
+        buffer = buffer << bits | u32::from(*value);
+        count += bits;
+        while count >= 8 {
+            count -= 8;
+            res.push(u8::try_from(buffer >> count & 0xFF).unwrap());
+        }
+    }
+    if count > 0 {
+        res.push(u8::try_from(buffer << (8 - count) & 0xFF).unwrap());
+    }
+    res
+}
+
+/// Lecture de `count` valeurs de `bits` bits écrites par `pack`
+fn unpack(bytes: &[u8], bits: usize, count: usize) -> Vec<u8> {
+    let mask = (1_u32 << bits) - 1;
+    let mut res = Vec::with_capacity(count);
+    let (mut buffer, mut available) = (0_u32, 0);
+    let mut iter_bytes = bytes.iter();
+    while res.len() < count {
+        while available < bits {
+            buffer = buffer << 8 | u32::from(*iter_bytes.next().unwrap_or(&0));
+            available += 8;
+        }
+        available -= bits;
+        res.push(u8::try_from(buffer >> available & mask).unwrap());
+    }
+    res
+}
+
+impl Grid {
+    /// Encodage binaire compact de la grille (pour stocker un grand nombre de grilles)
+    ///
+    /// Les octets contiennent un en-tête de 8 octets (`TG`, version du format, indicateurs,
+    /// largeur et hauteur en little-endian sur 2 octets chacune) puis le code de chaque position
+    /// sur 6 bits, dans l'ordre de lecture, comme pour `encode` : la grille 3x3 de l'exemple de
+    /// `encode` tient ainsi en 15 octets. Les noms des zones ne sont pas conservés.
+    ///
+    /// # Panics
+    /// Panic si la largeur ou la hauteur de la grille dépasse 65535
+    #[must_use]
+    pub fn to_binary(&self) -> Vec<u8> {
+        let (width, height, vec_codes) = self.position_codes();
+        let mut res = Vec::with_capacity(HEADER_LEN + (vec_codes.len() * CODE_BITS).div_ceil(8));
+        res.extend_from_slice(MAGIC);
+        res.push(BINARY_GRID_VERSION);
+        res.push(0);
+        res.extend_from_slice(&u16::try_from(width).unwrap().to_le_bytes());
+        res.extend_from_slice(&u16::try_from(height).unwrap().to_le_bytes());
+        res.extend(pack(&vec_codes, CODE_BITS));
+        res
+    }
+
+    /// Encodage binaire de la grille (voir `to_binary`) suivi du chiffre de chaque case de la
+    /// grille résolue `solution` sur 4 bits, dans l'ordre de lecture des cases
+    ///
+    /// Retourne `None` si `solution` n'a pas exactement les mêmes cases que la grille ou si une
+    /// case de `solution` n'a pas de chiffre
+    #[must_use]
+    pub fn to_binary_with_solution(&self, solution: &Grid) -> Option<Vec<u8>> {
+        if solution.cell_count() != self.cell_count() {
+            return None;
+        }
+        let mut vec_digits = Vec::with_capacity(self.cell_count());
+        for line_column in self.sorted_line_columns() {
+            match solution.get_cell(line_column)?.content {
+                CellContent::Number(n) => vec_digits.push(n),
+                CellContent::Undefined | CellContent::PossibleNumbers(_) => return None,
+            }
+        }
+
+        let mut res = self.to_binary();
+        res[3] |= FLAG_SOLUTION;
+        res.extend(pack(&vec_digits, DIGIT_BITS));
+        Some(res)
+    }
+
+    /// Construction d'une grille à partir de son encodage binaire (voir `to_binary`)
+    ///
+    /// La solution éventuellement présente (voir `to_binary_with_solution`) est ignorée. Les
+    /// zones sont nommées 'a', 'b', 'c', etc. dans l'ordre de lecture de leur première case
+    /// (comme pour `normalized`).
+    ///
+    /// # Errors
+    /// Une erreur est retournée si l'en-tête est absent, si la version du format n'est pas connue,
+    /// si le nombre d'octets ne correspond pas aux dimensions ou si le code d'une case est
+    /// incorrect
+    pub fn from_binary(bytes: &[u8]) -> Result<Grid, BinaryGridError> {
+        Self::from_binary_with_solution(bytes).map(|(grid, _)| grid)
+    }
+
+    /// Construction d'une grille et de sa solution (si elle est présente) à partir de son
+    /// encodage binaire (voir `to_binary_with_solution`)
+    ///
+    /// Les chiffres de la solution qui ne sont pas des chiffres de la grille sont placés comme
+    /// par une résolution (ils ne sont pas connus au départ, voir `Cell::is_given`)
+    ///
+    /// # Errors
+    /// Une erreur est retournée dans les mêmes cas que pour `from_binary` ou si un chiffre de la
+    /// solution est incorrect (ligne, colonne et chiffre de la case)
+    pub fn from_binary_with_solution(
+        bytes: &[u8],
+    ) -> Result<(Grid, Option<Grid>), BinaryGridError> {
+        if bytes.len() < HEADER_LEN || &bytes[..2] != MAGIC || bytes[3] & !FLAG_SOLUTION != 0 {
+            return Err(BinaryGridError::InvalidHeader);
+        }
+        if bytes[2] != BINARY_GRID_VERSION {
+            return Err(BinaryGridError::UnsupportedVersion(bytes[2]));
+        }
+        let has_solution = bytes[3] & FLAG_SOLUTION != 0;
+        let width = usize::from(u16::from_le_bytes([bytes[4], bytes[5]]));
+        let height = usize::from(u16::from_le_bytes([bytes[6], bytes[7]]));
+
+        let position_count = width * height;
+        let codes_len = (position_count * CODE_BITS).div_ceil(8);
+        let Some(rest) = bytes.get(HEADER_LEN + codes_len..) else {
+            return Err(BinaryGridError::InvalidLength);
+        };
+        let vec_codes = unpack(&bytes[HEADER_LEN..], CODE_BITS, position_count);
+        let cell_count = vec_codes
+            .iter()
+            .filter(|code| *code & 0x0F != NO_CELL)
+            .count();
+        let digits_len = if has_solution {
+            (cell_count * DIGIT_BITS).div_ceil(8)
+        } else {
+            0
+        };
+        if rest.len() != digits_len {
+            return Err(BinaryGridError::InvalidLength);
+        }
+
+        let grid =
+            Self::from_position_codes(width, &vec_codes, |index| vec_codes[index].to_string())?;
+        if !has_solution {
+            return Ok((grid, None));
+        }
+
+        let mut solution = grid.clone();
+        let vec_digits = unpack(rest, DIGIT_BITS, cell_count);
+        for (line_column, n) in grid.sorted_line_columns().into_iter().zip(vec_digits) {
+            let cell = solution.get_mut_cell(line_column).unwrap();
+            if !(1..=9).contains(&n) || cell.is_given && cell.content != CellContent::Number(n) {
+                let e = ParseGridError::invalid_token(
+                    line_column.line,
+                    line_column.column,
+                    &n.to_string(),
+                );
+                return Err(e.into());
+            }
+            cell.content = CellContent::Number(n);
+        }
+        Ok((grid, Some(solution)))
+    }
+
+    /// Positions des cases de la grille dans l'ordre de lecture
+    fn sorted_line_columns(&self) -> Vec<LineColumn> {
+        let mut vec_line_columns: Vec<LineColumn> = self.cells().map(|(lc, _)| *lc).collect();
+        vec_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+        vec_line_columns
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::solver::Solver;
+    use crate::solver_config::SolverConfig;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_binary() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let bytes = grid.to_binary();
+        assert_eq!(bytes.len(), 15);
+        assert_eq!(&bytes[..8], &[b'T', b'G', 1, 0, 3, 0, 3, 0]);
+        assert_eq!(Grid::from_binary(&bytes).unwrap(), grid);
+        assert_eq!(Grid::from_binary_with_solution(&bytes).unwrap().1, None);
+
+        // Grille avec sa solution
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        let solved = solver.to_grid();
+        let bytes = grid.to_binary_with_solution(&solved).unwrap();
+        assert_eq!(bytes.len(), 20);
+        let (puzzle, solution) = Grid::from_binary_with_solution(&bytes).unwrap();
+        assert_eq!(puzzle, grid);
+        let solution = solution.unwrap();
+        assert_eq!(solution.to_puzzle_string(), solved.to_puzzle_string());
+        assert_eq!(solution.cleared(), grid);
+        assert_eq!(Grid::from_binary(&bytes).unwrap(), grid);
+        assert!(grid.to_binary_with_solution(&grid).is_none());
+
+        // Grille avec des trous
+        let mut grid = Grid::from_str("a1 b\nd  b\nc  c3\n").unwrap();
+        grid.remove_cell(LineColumn::new(1, 0));
+        assert_eq!(Grid::from_binary(&grid.to_binary()).unwrap(), grid);
+    }
+
+    #[test]
+    fn test_binary_nok() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let bytes = grid.to_binary();
+
+        assert_eq!(
+            Grid::from_binary(&bytes[..5]),
+            Err(BinaryGridError::InvalidHeader)
+        );
+        assert_eq!(
+            Grid::from_binary(b"XX\x01\x00\x01\x00\x01\x00\x00"),
+            Err(BinaryGridError::InvalidHeader)
+        );
+        let mut bytes_v2 = bytes.clone();
+        bytes_v2[2] = 2;
+        assert_eq!(
+            Grid::from_binary(&bytes_v2),
+            Err(BinaryGridError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            Grid::from_binary(&bytes[..14]),
+            Err(BinaryGridError::InvalidLength)
+        );
+        let mut bytes_longer = bytes.clone();
+        bytes_longer.push(0);
+        assert_eq!(
+            Grid::from_binary(&bytes_longer),
+            Err(BinaryGridError::InvalidLength)
+        );
+
+        // Code 10 (chiffre 10) de la 1re case
+        let mut bytes_code = bytes.clone();
+        bytes_code[8] = 10 << 2;
+        assert!(matches!(
+            Grid::from_binary(&bytes_code),
+            Err(BinaryGridError::Parse(ParseGridError::InvalidCellToken {
+                line: 0,
+                column: 0,
+                ..
+            }))
+        ));
+
+        // Solution avec un chiffre 0
+        let mut bytes_solution = bytes;
+        bytes_solution[3] = FLAG_SOLUTION;
+        bytes_solution.extend([0x10, 0, 0, 0, 0]);
+        assert!(matches!(
+            Grid::from_binary_with_solution(&bytes_solution),
+            Err(BinaryGridError::Parse(ParseGridError::InvalidCellToken {
+                line: 0,
+                column: 1,
+                ..
+            }))
+        ));
+    }
+}
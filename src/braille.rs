@@ -0,0 +1,136 @@
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+
+/// Caractères braille des chiffres 1 à 9 (lettres 'a' à 'i', sans le signe numérique)
+const DIGITS: [char; 9] = ['⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊'];
+
+/// Caractère braille vide (aucun point)
+const BLANK: char = '⠀';
+
+/// Bordure verticale entre deux zones (points 1, 2 et 3)
+const VERTICAL: char = '⠇';
+
+/// Bordure horizontale entre deux zones (points 2 et 5)
+const HORIZONTAL: char = '⠒';
+
+/// Croisement de bordures entre des zones (les 6 points)
+const JUNCTION: char = '⠿';
+
+impl Grid {
+    /// Dessin de la grille en caractères braille (bloc Unicode des motifs braille à 6 points)
+    /// pour une imprimante braille ou une plage braille
+    ///
+    /// Chaque case est un caractère braille : le chiffre de la case (motifs des lettres 'a' à 'i',
+    /// sans le signe numérique) ou un caractère vide. Les bordures entre deux zones (et autour de
+    /// la grille) sont dessinées entre les cases : '⠇' entre deux colonnes, '⠒' entre deux lignes
+    /// et '⠿' aux coins et aux croisements. Il n'y a pas de bordure entre deux cases d'une même
+    /// zone
+    ///
+    /// ```text
+    /// ⠿⠒⠿⠒⠒⠒⠿
+    /// ⠇⠁⠇⠀⠀⠃⠇
+    /// ⠿⠒⠿⠀⠀⠀⠇
+    /// ⠇⠙⠀⠀⠀⠀⠇
+    /// ⠿⠒⠒⠒⠒⠒⠿
+    /// ⠇⠀⠀⠀⠀⠃⠇
+    /// ⠿⠒⠒⠒⠒⠒⠿
+    /// ```
+    #[must_use]
+    pub fn to_braille(&self) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        let zone_of = |line, column| {
+            self.get_cell(LineColumn::new(line, column))
+                .map(|cell| cell.c_zone)
+        };
+
+        // Bordures entre les colonnes `column` et `column + 1` de la ligne `line`, et entre les
+        // lignes `line` et `line + 1` de la colonne `column`
+        let vertical = |line, column| zone_of(line, column) != zone_of(line, column + 1);
+        let horizontal = |line, column| zone_of(line, column) != zone_of(line + 1, column);
+        let border = |is_border, c_border| if is_border { c_border } else { BLANK };
+
+        // Croisement entre les lignes `line` et `line + 1` et les colonnes `column` et
+        // `column + 1` : une bordure qui continue tout droit est dessinée sans croisement
+        let junction = |line, column| match (
+            vertical(line, column),
+            vertical(line + 1, column),
+            horizontal(line, column),
+            horizontal(line, column + 1),
+        ) {
+            (false, false, false, false) => BLANK,
+            (true, true, false, false) => VERTICAL,
+            (false, false, true, true) => HORIZONTAL,
+            _ => JUNCTION,
+        };
+
+        let mut res = String::new();
+        let mut end_line = |mut line_text: String| {
+            line_text.truncate(line_text.trim_end_matches(BLANK).len());
+            res.push_str(&line_text);
+            res.push('\n');
+        };
+        for line in min.line - 1..=max.line {
+            if line >= min.line {
+                let mut line_text = String::new();
+                for column in min.column - 1..=max.column {
+                    if column >= min.column {
+                        let option_cell = self.get_cell(LineColumn::new(line, column));
+                        line_text.push(match option_cell.map(|cell| &cell.content) {
+                            Some(CellContent::Number(n @ 1..=9)) => DIGITS[usize::from(*n - 1)],
+                            _ => BLANK,
+                        });
+                    }
+                    line_text.push(border(vertical(line, column), VERTICAL));
+                }
+                end_line(line_text);
+            }
+
+            let mut line_text = String::new();
+            for column in min.column - 1..=max.column {
+                if column >= min.column {
+                    line_text.push(border(horizontal(line, column), HORIZONTAL));
+                }
+                line_text.push(junction(line, column));
+            }
+            end_line(line_text);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_braille() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(
+            grid.to_braille(),
+            "⠿⠒⠿⠒⠒⠒⠿\n\
+             ⠇⠁⠇⠀⠀⠃⠇\n\
+             ⠿⠒⠿⠀⠀⠀⠇\n\
+             ⠇⠙⠀⠀⠀⠀⠇\n\
+             ⠿⠒⠒⠒⠒⠒⠿\n\
+             ⠇⠀⠀⠀⠀⠃⠇\n\
+             ⠿⠒⠒⠒⠒⠒⠿\n"
+        );
+
+        // Case absente de la grille et grille vide
+        let mut grid = Grid::from_str("a1 a2\na  b1").unwrap();
+        grid.remove_cell(LineColumn::new(1, 1));
+        assert_eq!(
+            grid.to_braille(),
+            "⠿⠒⠒⠒⠿\n\
+             ⠇⠁⠀⠃⠇\n\
+             ⠇⠀⠿⠒⠿\n\
+             ⠇⠀⠇\n\
+             ⠿⠒⠿\n"
+        );
+        assert_eq!(Grid::default().to_braille(), "");
+    }
+}
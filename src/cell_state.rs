@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::grid::CellContent;
+use crate::line_column::LineColumn;
+use crate::solver::Solver;
+
+/// État d'une case pendant la résolution
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum CellStatus {
+    /// Chiffre connu au départ
+    Given,
+
+    /// Chiffre placé pendant la résolution
+    Solved,
+
+    /// Chiffre pas encore trouvé
+    Open,
+}
+
+/// Contenu d'une case à une étape de la résolution (voir `Solver::cell_states`), par exemple pour
+/// afficher les annotations des cases dans une interface graphique
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellState {
+    /// Position de la case
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub line_column: LineColumn,
+
+    /// Nom de la zone de la case
+    pub zone: String,
+
+    /// État de la case
+    pub status: CellStatus,
+
+    /// Chiffre de la case (si la case n'est pas `CellStatus::Open`)
+    pub digit: Option<u8>,
+
+    /// Chiffres encore possibles d'une case `CellStatus::Open` (tous les chiffres de la zone si
+    /// les chiffres possibles ne sont pas encore calculés)
+    pub candidates: Vec<u8>,
+}
+
+impl Solver {
+    /// État de chaque case de la grille (triées par ligne puis par colonne) à l'étape courante de
+    /// la résolution : chiffre connu au départ, chiffre placé ou chiffres encore possibles
+    #[must_use]
+    pub fn cell_states(&self) -> Vec<CellState> {
+        let grid = self.to_grid();
+        let zone_sizes: HashMap<char, usize> = grid
+            .zones()
+            .map(|(c_zone, zone)| (*c_zone, zone.size()))
+            .collect();
+        grid.cells()
+            .map(|(line_column, cell)| {
+                let (status, digit, candidates) = match &cell.content {
+                    CellContent::Number(n) if cell.is_given => {
+                        (CellStatus::Given, Some(*n), vec![])
+                    }
+                    CellContent::Number(n) => (CellStatus::Solved, Some(*n), vec![]),
+                    CellContent::PossibleNumbers(simple_09_set) => {
+                        (CellStatus::Open, None, simple_09_set.as_vec_u8())
+                    }
+                    CellContent::Undefined => {
+                        let size = u8::try_from(zone_sizes[&cell.c_zone]).unwrap_or(u8::MAX);
+                        (CellStatus::Open, None, (1..=size.min(9)).collect())
+                    }
+                };
+                CellState {
+                    line_column: *line_column,
+                    zone: grid.zone_name(cell.c_zone),
+                    status,
+                    digit,
+                    candidates,
+                }
+            })
+            .collect()
+    }
+
+    /// État de chaque case (voir `cell_states`) en JSON, pour qu'une interface web affiche
+    /// exactement l'état de la résolution entre deux étapes :
+    ///
+    /// ```text
+    /// [{"line":0,"column":1,"zone":"b","status":"open","digit":null,"candidates":[1,3,5]}, ...]
+    /// ```
+    #[cfg(feature = "json")]
+    #[must_use]
+    pub fn candidates_json(&self) -> String {
+        serde_json::to_string(&self.cell_states()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use crate::solver::SolvingStrategy;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_cell_states() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let mut solver = Solver::new(&grid);
+        let cell_states = solver.cell_states();
+        assert_eq!(cell_states.len(), 9);
+        assert_eq!(
+            cell_states[0],
+            CellState {
+                line_column: LineColumn::new(0, 0),
+                zone: "a".to_string(),
+                status: CellStatus::Given,
+                digit: Some(1),
+                candidates: vec![],
+            }
+        );
+        assert_eq!(cell_states[1].status, CellStatus::Open);
+        assert_eq!(cell_states[1].candidates, vec![1, 2, 3, 4, 5]);
+
+        // Après l'initialisation des chiffres possibles et une étape
+        let _ = solver.apply_strategy_all(SolvingStrategy::NumbersInZone);
+        let cell_states = solver.cell_states();
+        assert_eq!(cell_states[1].candidates, vec![1, 3, 5]);
+        assert!(solver.solve(&crate::SolverConfig::new()).is_solved());
+        let cell_states = solver.cell_states();
+        assert!(cell_states
+            .iter()
+            .all(|cell_state| cell_state.status != CellStatus::Open));
+        assert_eq!(cell_states[1].status, CellStatus::Solved);
+        assert_eq!(cell_states[1].digit, Some(3));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_candidates_json() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let solver = Solver::new(&grid);
+        let json = solver.candidates_json();
+        assert!(json.starts_with(
+            "[{\"line\":0,\"column\":0,\"zone\":\"a\",\"status\":\"given\",\"digit\":1,\
+             \"candidates\":[]},{\"line\":0,\"column\":1,\"zone\":\"b\",\"status\":\"open\",\
+             \"digit\":null,\"candidates\":[1,2,3,4,5]}"
+        ));
+        let cell_states: Vec<CellState> = serde_json::from_str(&json).unwrap();
+        assert_eq!(cell_states, solver.cell_states());
+    }
+}
@@ -0,0 +1,368 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufReader, Read};
+use std::str::FromStr;
+
+use crate::grid::{cells_text, Grid, ParseGridError};
+use crate::puzzle::Puzzle;
+use crate::reader::SourceLines;
+
+/// Début (et fin facultative) de la ligne d'en-tête d'une grille dans une collection
+const HEADER_MARK: &str = "===";
+
+/// Erreur rencontrée lors de la lecture d'une collection de grilles
+#[derive(Debug)]
+pub enum CollectionError {
+    /// Erreur de lecture du texte de la collection
+    Io(io::Error),
+
+    /// Une grille de la collection (désignée par son nom) n'a pas le format de `Grid::from_str`
+    Parse(String, ParseGridError),
+}
+
+impl fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectionError::Io(e) => write!(f, "{e}"),
+            CollectionError::Parse(name, e) => write!(f, "Grille '{name}' : {e}"),
+        }
+    }
+}
+
+impl Error for CollectionError {}
+
+impl From<io::Error> for CollectionError {
+    fn from(e: io::Error) -> Self {
+        CollectionError::Io(e)
+    }
+}
+
+/// Collection de grilles nommées (un livre de grilles par exemple) lue dans un seul texte
+///
+/// Chaque grille a le format de `Grid::from_str` et commence par une ligne d'en-tête avec son
+/// nom entre `===` :
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use tectonic::PuzzleCollection;
+///
+/// let collection = PuzzleCollection::from_str(
+///     "
+/// === Grille 1 ===
+/// a1 b  b2
+/// b4 b  b
+/// c  c  c2
+///
+/// === Grille 2 ===
+/// a  a2
+/// ",
+/// )
+/// .unwrap();
+/// assert_eq!(collection.len(), 2);
+/// let names: Vec<&str> = collection.iter().map(|(name, _)| name).collect();
+/// assert_eq!(names, vec!["Grille 1", "Grille 2"]);
+/// ```
+///
+/// Les cases placées avant le premier en-tête forment une grille sans en-tête : un fichier qui ne
+/// contient qu'une seule grille est donc aussi une collection. Une grille sans nom (avant le
+/// premier en-tête ou avec un en-tête vide) est désignée par son titre (voir `PuzzleMetadata`)
+/// ou, à défaut, par son numéro dans la collection (à partir de 1).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzleCollection {
+    /// Nom et grille (avec ses informations) de chaque grille de la collection (dans l'ordre du
+    /// texte)
+    puzzles: Vec<(String, Puzzle)>,
+}
+
+impl PuzzleCollection {
+    /// Lecture d'une collection de grilles (voir `from_str`) depuis un fichier, l'entrée
+    /// standard, etc. : le texte est lu et analysé ligne par ligne (voir `PuzzleReader`)
+    ///
+    /// # Errors
+    /// Une erreur est retournée si le texte ne peut pas être lu ou si une grille est incorrecte
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, CollectionError> {
+        let mut collection = PuzzleCollection::default();
+        for result in PuzzleReader::new(reader) {
+            let (name, puzzle) = result?;
+            collection.push(&name, puzzle);
+        }
+        Ok(collection)
+    }
+
+    /// Nombre de grilles de la collection
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.puzzles.len()
+    }
+
+    /// Indique si la collection ne contient aucune grille
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.puzzles.is_empty()
+    }
+
+    /// Grille de nom `name`
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Grid> {
+        self.puzzles
+            .iter()
+            .find(|(puzzle_name, _)| puzzle_name == name)
+            .map(|(_, puzzle)| &puzzle.grid)
+    }
+
+    /// Nom et grille de chaque grille de la collection (dans l'ordre du texte)
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Grid)> {
+        self.puzzles
+            .iter()
+            .map(|(name, puzzle)| (name.as_str(), &puzzle.grid))
+    }
+
+    /// Nom et grille avec ses informations de chaque grille de la collection (dans l'ordre du
+    /// texte)
+    pub fn puzzles(&self) -> impl Iterator<Item = (&str, &Puzzle)> {
+        self.puzzles
+            .iter()
+            .map(|(name, puzzle)| (name.as_str(), puzzle))
+    }
+
+    /// Ajoute une grille à la fin de la collection
+    pub fn push(&mut self, name: &str, puzzle: Puzzle) {
+        self.puzzles.push((name.to_string(), puzzle));
+    }
+}
+
+impl IntoIterator for PuzzleCollection {
+    type Item = (String, Grid);
+    type IntoIter = std::vec::IntoIter<(String, Grid)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let vec_grids: Vec<(String, Grid)> = self
+            .puzzles
+            .into_iter()
+            .map(|(name, puzzle)| (name, puzzle.grid))
+            .collect();
+        vec_grids.into_iter()
+    }
+}
+
+impl FromStr for PuzzleCollection {
+    type Err = CollectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_reader(s.as_bytes())
+    }
+}
+
+/// Lecture au fur et à mesure des grilles d'une collection (voir `PuzzleCollection`) depuis un
+/// fichier, l'entrée standard, etc.
+///
+/// Chaque grille est retournée (avec son nom) dès que son texte est lu : une grande collection
+/// peut ainsi être traitée sans être entièrement chargée en mémoire. L'itération s'arrête après
+/// une erreur de lecture du texte.
+///
+/// ```rust
+/// use tectonic::PuzzleReader;
+///
+/// let text = "=== ex01 ===\na1 b\nb  b2\n=== ex02 ===\na  a2\n";
+/// let names: Vec<String> = PuzzleReader::new(text.as_bytes())
+///     .map(|result| result.unwrap().0)
+///     .collect();
+/// assert_eq!(names, vec!["ex01", "ex02"]);
+/// ```
+pub struct PuzzleReader<R> {
+    lines: SourceLines<BufReader<R>>,
+
+    // Nombre de lignes lues
+    line_count: usize,
+
+    // Nom (s'il est donné dans l'en-tête) et nombre de lignes avant la grille en cours de
+    // lecture dans le texte (None avant le premier en-tête)
+    header: Option<(Option<String>, usize)>,
+
+    // Nombre de grilles déjà retournées
+    puzzle_count: usize,
+
+    // Dernière grille retournée précédée d'un en-tête
+    has_header: bool,
+
+    is_done: bool,
+}
+
+impl<R: Read> PuzzleReader<R> {
+    /// Lecture des grilles du texte de `reader`
+    pub fn new(reader: R) -> Self {
+        PuzzleReader {
+            lines: SourceLines::new(BufReader::new(reader)),
+            line_count: 0,
+            header: None,
+            puzzle_count: 0,
+            has_header: false,
+            is_done: false,
+        }
+    }
+
+    /// Retourne true si la dernière grille retournée est précédée d'un en-tête '==='
+    #[must_use]
+    pub fn has_header(&self) -> bool {
+        self.has_header
+    }
+
+    /// Grille (avec son nom) du texte `text` de l'en-tête `header`
+    ///
+    /// Les lignes avant le premier en-tête ne sont qu'une grille si elles contiennent des cases
+    fn puzzle(
+        &mut self,
+        header: Option<(Option<String>, usize)>,
+        text: &str,
+    ) -> Option<Result<(String, Puzzle), CollectionError>> {
+        if header.is_none() && text.lines().all(|line| cells_text(line).is_empty()) {
+            return None;
+        }
+        self.has_header = header.is_some();
+        let (name, start_line) = header.unwrap_or_default();
+        self.puzzle_count += 1;
+        let index_name = self.puzzle_count.to_string();
+        Some(match Puzzle::from_str(text) {
+            Ok(puzzle) => {
+                let name = name
+                    .or_else(|| puzzle.metadata.title.clone())
+                    .unwrap_or(index_name);
+                Ok((name, puzzle))
+            }
+            Err(e) => Err(CollectionError::Parse(
+                name.unwrap_or(index_name),
+                e.offset_source_line(start_line),
+            )),
+        })
+    }
+}
+
+impl<R: Read> Iterator for PuzzleReader<R> {
+    type Item = Result<(String, Puzzle), CollectionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut text = String::new();
+        while !self.is_done {
+            let str_line = match self.lines.next() {
+                Some(Ok(str_line)) => str_line,
+                Some(Err(e)) => {
+                    self.is_done = true;
+                    return Some(Err(CollectionError::Io(e)));
+                }
+                None => {
+                    self.is_done = true;
+                    let header = self.header.take();
+                    return self.puzzle(header, &text);
+                }
+            };
+            self.line_count += 1;
+
+            if let Some(str_header) = str_line.trim().strip_prefix(HEADER_MARK) {
+                let name = str_header.trim_end_matches('=').trim();
+                let name = (!name.is_empty()).then(|| name.to_string());
+                let header = self.header.replace((name, self.line_count));
+                if let Some(result) = self.puzzle(header, &text) {
+                    return Some(result);
+                }
+                text.clear();
+            } else {
+                text.push_str(&str_line);
+                text.push('\n');
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_collection() {
+        let collection = PuzzleCollection::from_str(
+            "
+        # Livre de grilles
+        === ex01 ===
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+
+        ===
+        #! title: Petite grille
+        #! difficulty: 1
+        a  a2
+        === ex03
+        a1
+        ",
+        )
+        .unwrap();
+        assert_eq!(collection.len(), 3);
+        let names: Vec<&str> = collection.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["ex01", "Petite grille", "ex03"]);
+        let (_, puzzle) = collection.puzzles().nth(1).unwrap();
+        assert_eq!(puzzle.metadata.difficulty, Some(1));
+        assert_eq!(
+            collection.get("ex01"),
+            Some(&Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap())
+        );
+        assert!(collection.get("ex02").is_none());
+
+        // Une seule grille sans en-tête
+        let collection = PuzzleCollection::from_reader("a1 b\nb  b2\n".as_bytes()).unwrap();
+        let vec_puzzles: Vec<(String, Grid)> = collection.into_iter().collect();
+        assert_eq!(vec_puzzles.len(), 1);
+        assert_eq!(vec_puzzles[0].0, "1");
+        assert_eq!(vec_puzzles[0].1.cell_count(), 4);
+
+        assert!(PuzzleCollection::from_str("# Vide\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_puzzle_reader() {
+        let text =
+            "# Livre\r\n=== ex01 ===\r\na1 b\r\nb  b2\r\n=== ex02\r\na b0\r\n===\r\na  a2\r\n";
+        let mut reader = PuzzleReader::new(text.as_bytes());
+        let (name, puzzle) = reader.next().unwrap().unwrap();
+        assert_eq!((name.as_str(), puzzle.grid.cell_count()), ("ex01", 4));
+        assert!(reader.has_header());
+        // Une grille incorrecte n'arrête pas la lecture des grilles suivantes
+        match reader.next().unwrap() {
+            Err(CollectionError::Parse(name, e)) => {
+                assert_eq!(name, "ex02");
+                assert_eq!(e.source_line().unwrap().number, 6);
+            }
+            _ => panic!("Erreur attendue"),
+        }
+        let (name, puzzle) = reader.next().unwrap().unwrap();
+        assert_eq!((name.as_str(), puzzle.grid.cell_count()), ("3", 2));
+        assert!(reader.next().is_none());
+
+        // Erreur de lecture (texte qui n'est pas en UTF-8)
+        let bytes: &[u8] = b"=== ex01 ===\na1\n\xff\n";
+        let vec_results: Vec<_> = PuzzleReader::new(bytes).collect();
+        assert_eq!(vec_results.len(), 1);
+        assert!(matches!(vec_results[0], Err(CollectionError::Io(_))));
+
+        // Grille seule sans en-tête
+        let mut reader = PuzzleReader::new("a1 b\nb  b2\n".as_bytes());
+        assert!(reader.next().unwrap().is_ok());
+        assert!(!reader.has_header());
+    }
+
+    #[test]
+    fn test_collection_nok() {
+        let error =
+            PuzzleCollection::from_str("=== ex01 ===\na1\n=== ex02 ===\na b0\n").unwrap_err();
+        match error {
+            CollectionError::Parse(name, e) => {
+                assert_eq!(name, "ex02");
+                assert_eq!((e.line(), e.column(), e.token()), (0, 1, "b0"));
+                // Ligne 4 du texte de la collection
+                assert_eq!(e.source_line().unwrap().number, 4);
+            }
+            CollectionError::Io(_) => panic!("Erreur inattendue"),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+
+/// Couleurs de fond des zones (palette ANSI de 256 couleurs, couleurs claires pour des chiffres
+/// écrits en noir)
+const ZONE_COLORS: [u8; 8] = [224, 194, 153, 230, 189, 223, 159, 252];
+
+/// Retour aux couleurs par défaut du terminal
+const RESET: &str = "\x1b[0m";
+
+impl Grid {
+    /// Couleur de fond de chaque zone (indice dans `ZONE_COLORS`) : deux zones voisines (par un
+    /// côté) ont des couleurs différentes tant que la palette le permet
+    fn zone_colors(&self) -> BTreeMap<char, usize> {
+        let mut neighbors: BTreeMap<char, BTreeSet<char>> = BTreeMap::new();
+        for (line_column, cell) in self.cells() {
+            let set_neighbors = neighbors.entry(cell.c_zone).or_default();
+            for (d_line, d_column) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let neighbor =
+                    LineColumn::new(line_column.line + d_line, line_column.column + d_column);
+                if let Some(neighbor_cell) = self.get_cell(neighbor) {
+                    if neighbor_cell.c_zone != cell.c_zone {
+                        set_neighbors.insert(neighbor_cell.c_zone);
+                    }
+                }
+            }
+        }
+
+        let mut res = BTreeMap::new();
+        for (c_zone, set_neighbors) in &neighbors {
+            let used: BTreeSet<usize> = set_neighbors
+                .iter()
+                .filter_map(|neighbor| res.get(neighbor).copied())
+                .collect();
+            let color = (0..ZONE_COLORS.len())
+                .find(|color| !used.contains(color))
+                .unwrap_or(res.len() % ZONE_COLORS.len());
+            res.insert(*c_zone, color);
+        }
+        res
+    }
+
+    /// Affichage de la grille en couleurs dans un terminal (séquences ANSI) : chaque zone a sa
+    /// couleur de fond et le chiffre de la case `highlight` (par exemple le dernier chiffre placé)
+    /// est affiché en rouge et en gras
+    ///
+    /// Une case contient son chiffre ou ses chiffres possibles en cours de résolution
+    #[must_use]
+    pub fn to_ansi_string(&self, highlight: Option<LineColumn>) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        let zone_colors = self.zone_colors();
+
+        let content = |content: &CellContent| match content {
+            CellContent::Number(n) => n.to_string(),
+            CellContent::PossibleNumbers(simple_09_set) => simple_09_set
+                .as_vec_u8()
+                .iter()
+                .map(u8::to_string)
+                .collect(),
+            CellContent::Undefined => String::new(),
+        };
+        let width = self
+            .cells()
+            .map(|(_, cell)| content(&cell.content).len())
+            .max()
+            .unwrap_or_default()
+            .max(1)
+            + 2;
+
+        let mut res = String::new();
+        for line in min.line..=max.line {
+            for column in min.column..=max.column {
+                let line_column = LineColumn::new(line, column);
+                let Some(cell) = self.get_cell(line_column) else {
+                    write!(res, "{RESET}{:width$}", "").unwrap();
+                    continue;
+                };
+                let background = ZONE_COLORS[zone_colors[&cell.c_zone]];
+                let foreground = if highlight == Some(line_column) {
+                    "1;31"
+                } else {
+                    "30"
+                };
+                write!(
+                    res,
+                    "\x1b[0;48;5;{background};{foreground}m{:^width$}",
+                    content(&cell.content)
+                )
+                .unwrap();
+            }
+            writeln!(res, "{RESET}").unwrap();
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_zone_colors() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let zone_colors = grid.zone_colors();
+        assert_eq!(zone_colors.len(), 3);
+        assert_ne!(zone_colors[&'a'], zone_colors[&'b']);
+        assert_ne!(zone_colors[&'b'], zone_colors[&'c']);
+        // 'a' et 'c' ne sont pas voisines
+        assert_eq!(zone_colors[&'a'], zone_colors[&'c']);
+    }
+
+    #[test]
+    fn test_to_ansi_string() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let ansi = grid.to_ansi_string(None);
+        assert_eq!(ansi.lines().count(), 3);
+        assert!(ansi
+            .lines()
+            .next()
+            .unwrap()
+            .starts_with("\x1b[0;48;5;224;30m 1 \x1b[0;48;5;194;30m   "));
+        assert!(ansi.lines().all(|line| line.ends_with(RESET)));
+        assert!(!ansi.contains("1;31m"));
+
+        let ansi = grid.to_ansi_string(Some(LineColumn::new(0, 2)));
+        assert_eq!(ansi.matches("1;31m").count(), 1);
+        assert!(ansi.contains("\x1b[0;48;5;194;1;31m 2 "));
+
+        // Case absente de la grille
+        let grid = Grid::from_str("a1 b1\nc1").unwrap();
+        assert!(grid
+            .to_ansi_string(None)
+            .ends_with("\x1b[0;48;5;194;30m 1 \x1b[0m   \x1b[0m\n"));
+        assert_eq!(Grid::default().to_ansi_string(None), "");
+    }
+}
@@ -0,0 +1,122 @@
+use crate::grid::{normalized_text, CellContent, Grid, ParseGridError};
+use crate::line_column::LineColumn;
+
+impl Grid {
+    /// Construction d'une grille à partir d'un texte CSV (par exemple exporté d'un tableur) : une
+    /// ligne CSV par ligne de la grille et deux colonnes CSV par case, le nom de la zone de la case
+    /// puis son chiffre (vide si le chiffre n'est pas connu)
+    ///
+    /// ```text
+    /// a,1,b,,b,2
+    /// b,4,b,,b,
+    /// c,,c,,c,2
+    /// ```
+    ///
+    /// Une case sans zone (et sans chiffre) est absente de la grille. Les espaces et les
+    /// guillemets autour des valeurs sont ignorés.
+    ///
+    /// # Errors
+    /// Une erreur est retournée (ligne, colonne et texte de la case) si une zone n'est pas
+    /// désignée par des lettres, si un chiffre n'est pas entre 1 et 9 ou si une case sans zone a un
+    /// chiffre
+    pub fn from_csv(s: &str) -> Result<Grid, ParseGridError> {
+        let mut grid = Grid::default();
+        for (line, str_line) in normalized_text(s).lines().enumerate() {
+            let line = i32::try_from(line).unwrap();
+            let vec_fields: Vec<&str> = str_line
+                .split(',')
+                .map(|field| field.trim().trim_matches('"').trim())
+                .collect();
+            for (column, fields) in vec_fields.chunks(2).enumerate() {
+                let column = i32::try_from(column).unwrap();
+                let (name, str_value) = (fields[0], fields.get(1).copied().unwrap_or_default());
+                let token = format!("{name},{str_value}");
+                if name.is_empty() {
+                    if str_value.is_empty() {
+                        continue;
+                    }
+                    return Err(ParseGridError::invalid_token(line, column, &token));
+                }
+                if !Grid::is_valid_zone_name(name) {
+                    return Err(ParseGridError::invalid_token(line, column, &token));
+                }
+                let content = match str_value {
+                    "" => None,
+                    _ => match str_value.parse::<u8>() {
+                        Ok(n) if (1..=9).contains(&n) => Some(n),
+                        Ok(_) => {
+                            return Err(ParseGridError::digit_out_of_range(line, column, &token))
+                        }
+                        Err(_) => return Err(ParseGridError::invalid_token(line, column, &token)),
+                    },
+                };
+                let c_zone = grid.zone_id(name)?;
+                grid.add_cell((line, column), c_zone, content);
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Texte CSV de la grille dans le format lu par `Grid::from_csv`
+    ///
+    /// Toutes les lignes ont le même nombre de colonnes : une case absente de la grille a une zone
+    /// et un chiffre vides. Les chiffres possibles d'une case en cours de résolution ne sont pas
+    /// conservés et les chiffres placés pendant la résolution sont relus comme des chiffres
+    /// connus au départ (voir `Grid::cleared`).
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut res = String::new();
+        for line in self.min_line_column.line..=self.max_line_column.line {
+            let vec_fields: Vec<String> = (self.min_line_column.column
+                ..=self.max_line_column.column)
+                .map(
+                    |column| match self.get_cell(LineColumn::new(line, column)) {
+                        None => ",".to_string(),
+                        Some(cell) => {
+                            let zone = self.zone_name(cell.c_zone);
+                            match cell.content {
+                                CellContent::Number(n) => format!("{zone},{n}"),
+                                CellContent::Undefined | CellContent::PossibleNumbers(_) => {
+                                    format!("{zone},")
+                                }
+                            }
+                        }
+                    },
+                )
+                .collect();
+            res.push_str(&vec_fields.join(","));
+            res.push('\n');
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_csv() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let csv = grid.to_csv();
+        assert_eq!(csv, "a,1,b,,b,2\nb,4,b,,b,\nc,,c,,c,2\n");
+        assert_eq!(Grid::from_csv(&csv).unwrap(), grid);
+
+        // Valeurs entourées d'espaces ou de guillemets et case absente
+        let grid = Grid::from_csv("\"a\", 1 ,b,\n,,\"b\",\"2\"").unwrap();
+        assert_eq!(grid.cell_count(), 3);
+        assert!(grid.get_cell(LineColumn::new(1, 0)).is_none());
+        assert_eq!(grid.to_csv(), "a,1,b,\n,,b,2\n");
+    }
+
+    #[test]
+    fn test_from_csv_nok() {
+        let error = Grid::from_csv("a,1,b,0").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (0, 1, "b,0"));
+        let error = Grid::from_csv("a,1\n,2").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 0, ",2"));
+        assert!(Grid::from_csv("a1,").is_err());
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+
+use crate::line_column::LineColumn;
+use crate::neighboring_line_columns::NeighboringLineColumns;
+use crate::solver::{Solver, SolvingAction};
+
+/// Arbre de déduction d'une action de la résolution (voir `Solver::derivation`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Derivation {
+    /// Action de la résolution
+    pub action: SolvingAction,
+
+    /// Déductions des actions nécessaires à cette action (dans l'ordre de la résolution)
+    pub prerequisites: Vec<Derivation>,
+}
+
+impl Solver {
+    /// Arbre de déduction du chiffre d'une case : action qui a placé ce chiffre et, récursivement,
+    /// les actions de la résolution nécessaires à cette action
+    ///
+    /// Chaque action n'apparaît qu'une seule fois dans l'arbre (à sa première référence).
+    ///
+    /// Retourne `None` si le chiffre de cette case n'a pas été placé par la résolution (case
+    /// définie dans la grille d'origine ou pas encore résolue)
+    #[must_use]
+    pub fn derivation(&self, line_column: LineColumn) -> Option<Derivation> {
+        let actions = self.flatten_history();
+        let index = actions
+            .iter()
+            .position(|action| is_placement(action, line_column))?;
+        let mut visited = HashSet::new();
+        Some(self.derivation_at(&actions, index, &mut visited))
+    }
+
+    /// Historique des actions (les actions `Batch` sont remplacées par leurs actions)
+    fn flatten_history(&self) -> Vec<&SolvingAction> {
+        let mut actions = Vec::new();
+        let mut stack: Vec<&SolvingAction> = self.history().iter().rev().collect();
+        while let Some(action) = stack.pop() {
+            if let SolvingAction::Batch(batch_actions) = action {
+                stack.extend(batch_actions.iter().rev());
+            } else {
+                actions.push(action);
+            }
+        }
+        actions
+    }
+
+    /// Arbre de déduction de l'action à cet index de l'historique
+    fn derivation_at(
+        &self,
+        actions: &[&SolvingAction],
+        index: usize,
+        visited: &mut HashSet<usize>,
+    ) -> Derivation {
+        visited.insert(index);
+
+        let mut prerequisites = Vec::new();
+        for prerequisite_index in self.prerequisite_indexes(actions, index) {
+            if !visited.contains(&prerequisite_index) {
+                prerequisites.push(self.derivation_at(actions, prerequisite_index, visited));
+            }
+        }
+
+        Derivation {
+            action: actions[index].clone(),
+            prerequisites,
+        }
+    }
+
+    /// Index (triés) des actions de l'historique nécessaires à l'action à cet index
+    fn prerequisite_indexes(&self, actions: &[&SolvingAction], index: usize) -> Vec<usize> {
+        let previous = &actions[..index];
+
+        // Éliminations de chiffres faites précédemment dans une case
+        let eliminations = |line_column: LineColumn| -> Vec<usize> {
+            previous
+                .iter()
+                .enumerate()
+                .filter(|(_, action)| {
+                    action.candidates_change().is_some() && action.affected_cells() == [line_column]
+                })
+                .map(|(index, _)| index)
+                .collect()
+        };
+
+        // Placement fait précédemment d'un chiffre dans une case
+        let placement = |line_column: LineColumn, n: u8| -> Option<usize> {
+            previous.iter().position(|action| {
+                is_placement(action, line_column) && action.placed_digit() == Some(n)
+            })
+        };
+
+        let grid = self.original_grid();
+        let mut indexes = Vec::new();
+        match actions[index] {
+            SolvingAction::SinglePossibleNumber(line_column, _)
+            | SolvingAction::TryAndSolve(line_column, _, _)
+            | SolvingAction::TryAndFail(line_column, _, _) => {
+                indexes.extend(eliminations(*line_column));
+            }
+            SolvingAction::OnlyNumberInZone(c_zone, line_column, n) => {
+                // Le chiffre a été éliminé des autres cases de la zone
+                for zone_line_column in &grid.hashmap_zones[c_zone].set_line_column {
+                    if zone_line_column != line_column {
+                        indexes.extend(eliminations(*zone_line_column).into_iter().filter(
+                            |elimination_index| {
+                                actions[*elimination_index].removed_digits().contains(n)
+                            },
+                        ));
+                    }
+                }
+            }
+            SolvingAction::NumbersInZone(line_column, c_zone, vec_n, _) => {
+                // Les chiffres ont été placés dans d'autres cases de la zone
+                for zone_line_column in &grid.hashmap_zones[c_zone].set_line_column {
+                    if zone_line_column != line_column {
+                        indexes.extend(
+                            vec_n
+                                .iter()
+                                .filter_map(|n| placement(*zone_line_column, *n)),
+                        );
+                    }
+                }
+            }
+            SolvingAction::NumbersNeighboring(line_column, vec_n, _) => {
+                // Les chiffres ont été placés dans des cases voisines
+                let neighboring_line_columns = NeighboringLineColumns::new(
+                    *line_column,
+                    grid.min_line_column,
+                    grid.max_line_column,
+                );
+                for neighboring_line_column in neighboring_line_columns {
+                    indexes.extend(
+                        vec_n
+                            .iter()
+                            .filter_map(|n| placement(neighboring_line_column, *n)),
+                    );
+                }
+            }
+            SolvingAction::DualValuesPair(line_column_pair_1, line_column_pair_2, _, _, _) => {
+                // Les cases de la paire ont été réduites à 2 chiffres possibles
+                indexes.extend(eliminations(*line_column_pair_1));
+                indexes.extend(eliminations(*line_column_pair_2));
+            }
+            _ => (),
+        }
+
+        indexes.sort_unstable();
+        indexes.dedup();
+        indexes
+    }
+}
+
+/// L'action place-t-elle un chiffre dans cette case ?
+fn is_placement(action: &SolvingAction, line_column: LineColumn) -> bool {
+    action.placed_digit().is_some() && action.affected_cells() == [line_column]
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use crate::solver_config::SolverConfig;
+    use std::str::FromStr;
+
+    /// Nombre d'actions dans un arbre de déduction
+    fn derivation_len(derivation: &Derivation) -> usize {
+        1 + derivation
+            .prerequisites
+            .iter()
+            .map(derivation_len)
+            .sum::<usize>()
+    }
+
+    #[test]
+    fn test_derivation() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+
+        // Case définie dans la grille d'origine
+        assert!(solver.derivation(LineColumn::new(0, 1)).is_none());
+
+        // Toutes les autres cases ont un arbre de déduction
+        for line_column in grid.hashmap_cells.keys() {
+            if grid.hashmap_cells[line_column].content == crate::grid::CellContent::Undefined {
+                let derivation = solver.derivation(*line_column).unwrap();
+                assert_eq!(derivation.action.affected_cells(), vec![*line_column]);
+                assert!(derivation.action.placed_digit().is_some());
+                assert!(derivation_len(&derivation) <= solver.history().len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_derivation_single_possible_number() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+
+        // Le chiffre de la case 'c' en (2, 0) est déduit après élimination de chiffres possibles
+        let derivation = solver.derivation(LineColumn::new(2, 0)).unwrap();
+        assert!(!derivation.prerequisites.is_empty());
+        for prerequisite in &derivation.prerequisites {
+            assert!(prerequisite.action.candidates_change().is_some());
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::Write;
+
+use crate::grid::{CellContent, Grid};
+use crate::lang::Lang;
+use crate::line_column::LineColumn;
+
+/// Nombre suivi d'un mot au singulier ou au pluriel
+fn count(n: usize, singular: &str, plural: &str) -> String {
+    if n > 1 {
+        format!("{n} {plural}")
+    } else {
+        format!("{n} {singular}")
+    }
+}
+
+/// Texte avec une majuscule au début
+fn capitalized(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+impl Grid {
+    /// Description textuelle de la grille, lisible ligne par ligne par un lecteur d'écran (voir
+    /// `describe_in`)
+    #[must_use]
+    pub fn describe(&self) -> String {
+        self.describe_in(Lang::French)
+    }
+
+    /// Description textuelle de la grille dans la langue choisie, lisible ligne par ligne par un
+    /// lecteur d'écran ou une plage braille : dimensions de la grille, contenu de chaque case
+    /// ligne par ligne, puis les cases de chaque zone
+    ///
+    /// ```text
+    /// Grille de 3 lignes, 3 colonnes et 3 zones.
+    /// Ligne 1 : case 1 zone a chiffre 1, case 2 zone b vide, case 3 zone b chiffre 2.
+    /// ...
+    /// Zone a : 1 case, ligne 1 case 1.
+    /// ```
+    ///
+    /// Les lignes et les cases sont numérotées à partir de 1 depuis le coin supérieur gauche de
+    /// la grille
+    #[must_use]
+    pub fn describe_in(&self, lang: Lang) -> String {
+        let words = lang.description_words();
+        let Some((min, max)) = self.bounds() else {
+            return format!("{}\n", words.empty_grid);
+        };
+        let position = |line_column: LineColumn| {
+            format!(
+                "{} {} {} {}",
+                words.line,
+                line_column.line - min.line + 1,
+                words.cell,
+                line_column.column - min.column + 1
+            )
+        };
+
+        let mut res = String::new();
+        writeln!(
+            res,
+            "{} {}, {} {} {}.",
+            words.grid,
+            count(self.height(), words.line, words.lines),
+            count(self.width(), words.column, words.columns),
+            words.and,
+            count(self.hashmap_zones.len(), words.zone, words.zones)
+        )
+        .unwrap();
+
+        for line in min.line..=max.line {
+            let vec_cells: Vec<String> = (min.column..=max.column)
+                .map(|column| {
+                    let index = column - min.column + 1;
+                    match self.get_cell(LineColumn::new(line, column)) {
+                        None => format!("{} {index} {}", words.cell, words.missing),
+                        Some(cell) => {
+                            let content = match cell.content {
+                                CellContent::Number(n) => format!("{} {n}", words.number),
+                                _ => words.empty.to_string(),
+                            };
+                            format!(
+                                "{} {index} {} {} {content}",
+                                words.cell,
+                                words.zone,
+                                self.zone_name(cell.c_zone)
+                            )
+                        }
+                    }
+                })
+                .collect();
+            writeln!(
+                res,
+                "{} {}{} {}.",
+                capitalized(words.line),
+                line - min.line + 1,
+                words.colon,
+                vec_cells.join(", ")
+            )
+            .unwrap();
+        }
+
+        let mut vec_zones: Vec<_> = self
+            .zones()
+            .map(|(c_zone, zone)| (self.zone_name(*c_zone), zone))
+            .collect();
+        vec_zones.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+        for (name, zone) in vec_zones {
+            let vec_positions: Vec<String> = zone.cells().map(position).collect();
+            let (nb_cells, positions) = (
+                count(zone.size(), words.cell, words.cells),
+                vec_positions.join(", "),
+            );
+            writeln!(
+                res,
+                "{}",
+                lang.zone_description(&name, &nb_cells, &positions)
+            )
+            .unwrap();
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_describe() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let description = grid.describe();
+        let lines: Vec<&str> = description.lines().collect();
+        assert_eq!(lines.len(), 1 + 3 + 3);
+        assert_eq!(lines[0], "Grille de 3 lignes, 3 colonnes et 3 zones.");
+        assert_eq!(
+            lines[1],
+            "Ligne 1 : case 1 zone a chiffre 1, case 2 zone b vide, case 3 zone b chiffre 2."
+        );
+        assert_eq!(lines[4], "Zone a : 1 case, ligne 1 case 1.");
+        assert_eq!(
+            lines[5],
+            "Zone b : 5 cases, ligne 1 case 2, ligne 1 case 3, ligne 2 case 1, ligne 2 case 2, \
+             ligne 2 case 3."
+        );
+
+        let description = grid.describe_in(Lang::English);
+        let lines: Vec<&str> = description.lines().collect();
+        assert_eq!(lines[0], "Grid of 3 rows, 3 columns and 3 zones.");
+        assert_eq!(
+            lines[3],
+            "Row 3: cell 1 zone c empty, cell 2 zone c empty, cell 3 zone c value 2."
+        );
+        assert_eq!(lines[4], "Zone a has 1 cell: row 1 cell 1.");
+
+        // Case absente et grille vide
+        let mut grid = Grid::from_str("a1 b1\nc1 d1").unwrap();
+        grid.remove_cell(LineColumn::new(1, 0));
+        assert!(grid
+            .describe()
+            .contains("Ligne 2 : case 1 absente, case 2 zone d chiffre 1."));
+        assert_eq!(Grid::default().describe(), "Grille vide.\n");
+    }
+}
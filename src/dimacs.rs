@@ -0,0 +1,264 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::neighboring_line_columns::NeighboringLineColumns;
+
+impl Grid {
+    /// Variables booléennes du codage SAT de la grille
+    ///
+    /// La variable numéro `i + 1` est vraie si la case `vec[i].0` contient le chiffre `vec[i].1`.
+    /// Les cases sont parcourues par ligne puis colonne et, pour chaque case, les chiffres de 1 à
+    /// la taille de sa zone.
+    fn dimacs_variables(&self) -> Vec<(LineColumn, u8)> {
+        let mut vec_line_columns: Vec<LineColumn> = self.hashmap_cells.keys().copied().collect();
+        vec_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+
+        let mut vec_variables = Vec::new();
+        for line_column in vec_line_columns {
+            for n in 1..=self.dimacs_zone_len(line_column) {
+                vec_variables.push((line_column, n));
+            }
+        }
+        vec_variables
+    }
+
+    /// Taille (limitée à 9) de la zone d'une case
+    fn dimacs_zone_len(&self, line_column: LineColumn) -> u8 {
+        let zone_len = self
+            .get_cell(line_column)
+            .and_then(|cell| self.hashmap_zones.get(&cell.c_zone))
+            .map_or(0, |zone| zone.set_line_column.len());
+        u8::try_from(zone_len.min(9)).unwrap()
+    }
+
+    /// Codage des contraintes de la grille au format DIMACS CNF
+    ///
+    /// Une variable booléenne est associée à chaque couple (case, chiffre) et les clauses imposent :
+    /// * Exactement un chiffre par case
+    /// * Chaque chiffre de la zone exactement une fois dans la zone
+    /// * Deux cases voisines (y compris en diagonale) sans le même chiffre
+    /// * Les chiffres déjà placés
+    ///
+    /// La correspondance entre variables et cases est rappelée en commentaire ('c') et
+    /// `from_dimacs_model` permet de relire la solution trouvée par un solver SAT.
+    #[must_use]
+    pub fn to_dimacs(&self) -> String {
+        let vec_variables = self.dimacs_variables();
+        let hash_map_variables: HashMap<(LineColumn, u8), usize> = vec_variables
+            .iter()
+            .enumerate()
+            .map(|(index, variable)| (*variable, index + 1))
+            .collect();
+        let variable = |line_column: LineColumn, n: u8| -> Option<usize> {
+            hash_map_variables.get(&(line_column, n)).copied()
+        };
+
+        let mut vec_clauses: Vec<Vec<i64>> = Vec::new();
+        let positive = |index: usize| i64::try_from(index).unwrap();
+        let negative = |index: usize| -i64::try_from(index).unwrap();
+
+        // Exactement un chiffre par case
+        let mut vec_line_columns: Vec<LineColumn> = self.hashmap_cells.keys().copied().collect();
+        vec_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+        for line_column in &vec_line_columns {
+            let vec_cell_variables: Vec<usize> = (1..=self.dimacs_zone_len(*line_column))
+                .filter_map(|n| variable(*line_column, n))
+                .collect();
+            vec_clauses.push(vec_cell_variables.iter().map(|v| positive(*v)).collect());
+            for (i, v1) in vec_cell_variables.iter().enumerate() {
+                for v2 in &vec_cell_variables[i + 1..] {
+                    vec_clauses.push(vec![negative(*v1), negative(*v2)]);
+                }
+            }
+        }
+
+        // Chaque chiffre exactement une fois par zone
+        let mut vec_zones: Vec<char> = self.hashmap_zones.keys().copied().collect();
+        vec_zones.sort_unstable();
+        for c_zone in vec_zones {
+            let mut zone_line_columns: Vec<LineColumn> = self.hashmap_zones[&c_zone]
+                .set_line_column
+                .iter()
+                .copied()
+                .collect();
+            zone_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+            let zone_len = zone_line_columns
+                .first()
+                .map_or(0, |line_column| self.dimacs_zone_len(*line_column));
+            for n in 1..=zone_len {
+                let vec_n_variables: Vec<usize> = zone_line_columns
+                    .iter()
+                    .filter_map(|line_column| variable(*line_column, n))
+                    .collect();
+                vec_clauses.push(vec_n_variables.iter().map(|v| positive(*v)).collect());
+                for (i, v1) in vec_n_variables.iter().enumerate() {
+                    for v2 in &vec_n_variables[i + 1..] {
+                        vec_clauses.push(vec![negative(*v1), negative(*v2)]);
+                    }
+                }
+            }
+        }
+
+        // Deux cases voisines sans le même chiffre
+        for line_column in &vec_line_columns {
+            for neighboring_line_column in NeighboringLineColumns::new(
+                *line_column,
+                self.min_line_column,
+                self.max_line_column,
+            ) {
+                if (neighboring_line_column.line, neighboring_line_column.column)
+                    <= (line_column.line, line_column.column)
+                {
+                    continue;
+                }
+                for n in 1..=self.dimacs_zone_len(*line_column) {
+                    if let (Some(v1), Some(v2)) = (
+                        variable(*line_column, n),
+                        variable(neighboring_line_column, n),
+                    ) {
+                        vec_clauses.push(vec![negative(v1), negative(v2)]);
+                    }
+                }
+            }
+        }
+
+        // Chiffres déjà placés (clause vide si le chiffre n'est pas possible dans la zone)
+        for line_column in &vec_line_columns {
+            let cell = self.get_cell(*line_column).unwrap();
+            if let CellContent::Number(n) = cell.content {
+                vec_clauses.push(
+                    variable(*line_column, n)
+                        .map(positive)
+                        .into_iter()
+                        .collect(),
+                );
+            }
+        }
+
+        let mut res = String::new();
+        res.push_str("c Grille tectonic\n");
+        for (index, (line_column, n)) in vec_variables.iter().enumerate() {
+            let _ = writeln!(res, "c {} = {n} en {line_column}", index + 1);
+        }
+        let _ = writeln!(res, "p cnf {} {}", vec_variables.len(), vec_clauses.len());
+        for clause in vec_clauses {
+            for literal in clause {
+                let _ = write!(res, "{literal} ");
+            }
+            res.push_str("0\n");
+        }
+        res
+    }
+
+    /// Relit le modèle trouvé par un solver SAT pour le codage `to_dimacs` de cette grille
+    /// et retourne la grille complétée
+    ///
+    /// Le modèle est une liste de littéraux (éventuellement sur des lignes préfixées par 'v',
+    /// les lignes de commentaires 'c' et de statut 's' sont ignorées).
+    /// Retourne None si le modèle est insatisfiable ou ne donne pas un chiffre pour chaque case
+    #[must_use]
+    pub fn from_dimacs_model(&self, model: &str) -> Option<Grid> {
+        let mut true_variables = HashSet::new();
+        for str_line in model.lines() {
+            let str_line = str_line.trim();
+            if str_line.contains("UNSAT") {
+                return None;
+            }
+            if str_line.starts_with('c') || str_line.starts_with('s') || str_line == "SAT" {
+                continue;
+            }
+            let str_line = str_line.strip_prefix('v').unwrap_or(str_line);
+            for str_literal in str_line.split_whitespace() {
+                let literal: i64 = str_literal.parse().ok()?;
+                if literal > 0 {
+                    true_variables.insert(usize::try_from(literal).ok()?);
+                }
+            }
+        }
+
+        // Chiffres vrais pour chaque case
+        let mut hash_map_numbers: HashMap<LineColumn, Vec<u8>> = HashMap::new();
+        for (index, (line_column, n)) in self.dimacs_variables().into_iter().enumerate() {
+            if true_variables.contains(&(index + 1)) {
+                hash_map_numbers.entry(line_column).or_default().push(n);
+            }
+        }
+
+        let mut grid = self.clone();
+        for cell in grid.hashmap_cells.values_mut() {
+            match hash_map_numbers.get(&cell.line_column).map(Vec::as_slice) {
+                Some([n]) => cell.content = CellContent::Number(*n),
+                _ => return None,
+            }
+        }
+        Some(grid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::backtracking::BacktrackingSolver;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_dimacs() {
+        let grid = Grid::from_str(
+            "
+        a1 b
+        b  b
+        ",
+        )
+        .unwrap();
+
+        let cnf = grid.to_dimacs();
+        // 1 variable pour 'a' et 3 pour chacune des 3 cases de 'b'
+        assert!(cnf.contains("p cnf 10 "));
+        assert!(cnf.contains("c 1 = 1 en (lin=0, col=0)\n"));
+        assert!(cnf.contains("c 2 = 1 en (lin=0, col=1)\n"));
+        // Chiffre placé en (0, 0)
+        assert!(cnf.ends_with("\n1 0\n"));
+        // La case (0, 0) et sa voisine (0, 1) ne peuvent pas contenir toutes les deux 1
+        assert!(cnf.contains("\n-1 -2 0\n"));
+    }
+
+    #[test]
+    fn test_from_dimacs_model() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        let solution = BacktrackingSolver::new(&grid).solve().unwrap();
+
+        // Modèle correspondant à la solution
+        let mut model = String::from("s SATISFIABLE\nv");
+        for (index, (line_column, n)) in grid.dimacs_variables().iter().enumerate() {
+            let cell = solution.get_cell(*line_column).unwrap();
+            let sign = if cell.content == CellContent::Number(*n) {
+                ""
+            } else {
+                "-"
+            };
+            model.push_str(&format!(" {sign}{}", index + 1));
+        }
+        model.push_str(" 0\n");
+
+        let decoded = grid.from_dimacs_model(&model).unwrap();
+        for (line_column, cell) in &solution.hashmap_cells {
+            assert_eq!(
+                decoded.get_cell(*line_column).unwrap().content,
+                cell.content
+            );
+        }
+
+        assert!(grid.from_dimacs_model("s UNSATISFIABLE\n").is_none());
+        assert!(grid.from_dimacs_model("v 1 0\n").is_none());
+    }
+}
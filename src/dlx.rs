@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+
+/// Résolution d'une grille tectonic par 'Dancing Links' (algorithme X de Knuth)
+///
+/// Les contraintes de la grille sont modélisées comme un problème de couverture exacte :
+/// * Chaque case contient exactement un chiffre (contrainte primaire)
+/// * Chaque chiffre de 1 à la taille d'une zone est placé exactement une fois dans cette zone
+///   (contrainte primaire)
+/// * Un chiffre apparaît au plus une fois dans chaque carré de 2x2 cases (contrainte secondaire).
+///   Deux cases voisines (y compris en diagonale) sont toujours dans un même carré de 2x2 cases
+///
+/// Ce solver ne donne aucune explication mais il est rapide pour trouver une solution ou
+/// vérifier qu'une grille n'a qu'une seule solution.
+/// Le solver `Solver` reste la référence pour une résolution 'logique' étape par étape.
+#[derive(Debug)]
+pub struct DlxSolver {
+    /// Grille tectonic
+    grid: Arc<Grid>,
+
+    /// Ligne de la matrice de couverture : case et chiffre placé dans cette case
+    rows: Vec<(LineColumn, u8)>,
+
+    /// Colonnes couvertes par chaque ligne de la matrice de couverture
+    rows_columns: Vec<Vec<usize>>,
+
+    /// Nombre de colonnes primaires (les premières colonnes)
+    nb_primary_columns: usize,
+
+    /// Nombre total de colonnes
+    nb_columns: usize,
+}
+
+impl DlxSolver {
+    /// Grille tectonic (résolue après un appel réussi à `SolverBackend::solve`)
+    #[must_use]
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Grille tectonic partagée, pour construire un solver sans la copier
+    pub(crate) fn shared_grid(&self) -> Arc<Grid> {
+        Arc::clone(&self.grid)
+    }
+
+    /// Constructeur du solver d'après une grille
+    ///
+    /// Une grille 'incorrecte' (zone trop grande, chiffre plus grand que la taille de sa zone)
+    /// n'a simplement aucune solution
+    #[must_use]
+    pub fn new(grid: &Grid) -> Self {
+        // Cases de la grille (triées pour une construction déterministe)
+        let mut vec_line_columns: Vec<LineColumn> = grid.hashmap_cells.keys().copied().collect();
+        vec_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+
+        // Colonnes primaires : une par case puis une par (zone, chiffre)
+        let mut columns_cells: HashMap<LineColumn, usize> = HashMap::new();
+        for (index, line_column) in vec_line_columns.iter().enumerate() {
+            columns_cells.insert(*line_column, index);
+        }
+        let mut nb_columns = vec_line_columns.len();
+        let mut vec_zones: Vec<char> = grid.hashmap_zones.keys().copied().collect();
+        vec_zones.sort_unstable();
+        let mut columns_zones: HashMap<(char, u8), usize> = HashMap::new();
+        for c_zone in &vec_zones {
+            let zone_len = grid.hashmap_zones[c_zone].set_line_column.len();
+            for n in 1..=zone_len.min(9) {
+                #[allow(clippy::cast_possible_truncation)]
+                columns_zones.insert((*c_zone, n as u8), nb_columns);
+                nb_columns += 1;
+            }
+        }
+        let nb_primary_columns = nb_columns;
+
+        // Colonnes secondaires : une par (carré 2x2, chiffre), créées au besoin
+        let mut columns_squares: HashMap<(LineColumn, u8), usize> = HashMap::new();
+
+        let mut rows = Vec::new();
+        let mut rows_columns = Vec::new();
+        for line_column in &vec_line_columns {
+            let cell = grid.get_cell(*line_column).unwrap();
+            let Some(zone) = grid.hashmap_zones.get(&cell.c_zone) else {
+                continue;
+            };
+            let zone_len = zone.set_line_column.len();
+
+            // Chiffres possibles pour cette case
+            let vec_n: Vec<u8> = match cell.content {
+                CellContent::Number(n) => vec![n],
+                #[allow(clippy::cast_possible_truncation)]
+                _ => (1..=zone_len.min(9)).map(|n| n as u8).collect(),
+            };
+
+            for n in vec_n {
+                let Some(column_zone) = columns_zones.get(&(cell.c_zone, n)) else {
+                    // Chiffre plus grand que la taille de la zone
+                    continue;
+                };
+                let mut columns = vec![columns_cells[line_column], *column_zone];
+                for (delta_line, delta_column) in [(-1, -1), (-1, 0), (0, -1), (0, 0)] {
+                    let square = *line_column + LineColumn::new(delta_line, delta_column);
+                    let column = *columns_squares.entry((square, n)).or_insert_with(|| {
+                        nb_columns += 1;
+                        nb_columns - 1
+                    });
+                    columns.push(column);
+                }
+                rows.push((*line_column, n));
+                rows_columns.push(columns);
+            }
+        }
+
+        DlxSolver {
+            grid: Arc::new(grid.clone()),
+            rows,
+            rows_columns,
+            nb_primary_columns,
+            nb_columns,
+        }
+    }
+
+    /// Recherche une solution de la grille
+    /// Retourne None si la grille n'a pas de solution
+    #[must_use]
+    pub fn solve(&self) -> Option<Grid> {
+        let mut dancing_links = self.dancing_links();
+        let mut search = DlxSearch {
+            limit: 1,
+            ..DlxSearch::default()
+        };
+        dancing_links.search(&mut search);
+
+        search.first_solution.map(|vec_rows| {
+            let mut grid = Grid::clone(&self.grid);
+            for row in vec_rows {
+                let (line_column, n) = self.rows[row];
+                let cell = grid.get_mut_cell(line_column).unwrap();
+                cell.content = CellContent::Number(n);
+            }
+            grid
+        })
+    }
+
+    /// Compte le nombre de solutions de la grille
+    /// La recherche s'arrête dès que `limit` solutions sont trouvées
+    /// (`count_solutions(2) == 1` permet de vérifier qu'une grille n'a qu'une seule solution)
+    #[must_use]
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        if limit == 0 {
+            return 0;
+        }
+        let mut dancing_links = self.dancing_links();
+        let mut search = DlxSearch {
+            limit,
+            ..DlxSearch::default()
+        };
+        dancing_links.search(&mut search);
+        search.count
+    }
+
+    /// Construit la structure 'dancing links' de la matrice de couverture
+    fn dancing_links(&self) -> DancingLinks {
+        let mut dancing_links = DancingLinks::new(self.nb_primary_columns, self.nb_columns);
+        for (row, columns) in self.rows_columns.iter().enumerate() {
+            dancing_links.add_row(row, columns);
+        }
+        dancing_links
+    }
+}
+
+/// État d'une recherche de solutions
+#[derive(Debug, Default)]
+struct DlxSearch {
+    /// Nombre de solutions au-delà duquel on arrête la recherche
+    limit: usize,
+
+    /// Nombre de solutions trouvées
+    count: usize,
+
+    /// Lignes de la solution en cours de construction
+    vec_rows: Vec<usize>,
+
+    /// Lignes de la première solution trouvée
+    first_solution: Option<Vec<usize>>,
+}
+
+/// Matrice de couverture représentée par des listes doublement chaînées ('dancing links')
+///
+/// Le nœud 0 est la racine, les nœuds 1..=`nb_columns` sont les entêtes des colonnes et les
+/// nœuds suivants sont les '1' des lignes de la matrice.
+/// Seules les colonnes primaires sont chaînées à la racine : ce sont les seules qui doivent
+/// obligatoirement être couvertes.
+#[derive(Debug)]
+struct DancingLinks {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    column: Vec<usize>,
+    row: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DancingLinks {
+    fn new(nb_primary_columns: usize, nb_columns: usize) -> Self {
+        let nb_nodes = nb_columns + 1;
+        let mut dancing_links = DancingLinks {
+            left: (0..nb_nodes).collect(),
+            right: (0..nb_nodes).collect(),
+            up: (0..nb_nodes).collect(),
+            down: (0..nb_nodes).collect(),
+            column: (0..nb_nodes).collect(),
+            row: vec![usize::MAX; nb_nodes],
+            size: vec![0; nb_nodes],
+        };
+
+        // Chaînage des colonnes primaires à la racine
+        for header in 1..=nb_primary_columns {
+            dancing_links.left[header] = header - 1;
+            dancing_links.right[header - 1] = header;
+        }
+        dancing_links.right[nb_primary_columns] = 0;
+        dancing_links.left[0] = nb_primary_columns;
+
+        dancing_links
+    }
+
+    /// Ajoute une ligne qui couvre les colonnes (numérotées à partir de 0) indiquées
+    fn add_row(&mut self, row: usize, columns: &[usize]) {
+        let first_node = self.left.len();
+        for (index, column) in columns.iter().enumerate() {
+            let header = column + 1;
+            let node = first_node + index;
+
+            // Chaînage vertical en bas de la colonne
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            let last = self.up[header];
+            self.down[last] = node;
+            self.up[header] = node;
+            self.size[header] += 1;
+
+            // Chaînage horizontal circulaire dans la ligne
+            self.left.push(if index == 0 {
+                first_node + columns.len() - 1
+            } else {
+                node - 1
+            });
+            self.right.push(if index == columns.len() - 1 {
+                first_node
+            } else {
+                node + 1
+            });
+
+            self.column.push(header);
+            self.row.push(row);
+        }
+    }
+
+    /// Retire une colonne et toutes les lignes qui la couvrent
+    fn cover(&mut self, header: usize) {
+        let (left, right) = (self.left[header], self.right[header]);
+        self.right[left] = right;
+        self.left[right] = left;
+
+        let mut i = self.down[header];
+        while i != header {
+            let mut j = self.right[i];
+            while j != i {
+                let (up, down) = (self.up[j], self.down[j]);
+                self.down[up] = down;
+                self.up[down] = up;
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    /// Opération inverse de `cover`
+    fn uncover(&mut self, header: usize) {
+        let mut i = self.up[header];
+        while i != header {
+            let mut j = self.left[i];
+            while j != i {
+                let (up, down) = (self.up[j], self.down[j]);
+                self.down[up] = j;
+                self.up[down] = j;
+                self.size[self.column[j]] += 1;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+
+        let (left, right) = (self.left[header], self.right[header]);
+        self.right[left] = header;
+        self.left[right] = header;
+    }
+
+    /// Recherche récursive des solutions
+    /// Retourne true si la recherche doit s'arrêter (limite du nombre de solutions atteinte)
+    fn search(&mut self, search: &mut DlxSearch) -> bool {
+        if self.right[0] == 0 {
+            // Toutes les colonnes primaires sont couvertes : c'est une solution
+            search.count += 1;
+            if search.first_solution.is_none() {
+                search.first_solution = Some(search.vec_rows.clone());
+            }
+            return search.count >= search.limit;
+        }
+
+        // Choix de la colonne avec le moins de lignes possibles
+        let mut header = self.right[0];
+        let mut j = self.right[header];
+        while j != 0 {
+            if self.size[j] < self.size[header] {
+                header = j;
+            }
+            j = self.right[j];
+        }
+        if self.size[header] == 0 {
+            return false;
+        }
+
+        self.cover(header);
+        let mut stop = false;
+        let mut i = self.down[header];
+        while i != header && !stop {
+            search.vec_rows.push(self.row[i]);
+            let mut j = self.right[i];
+            while j != i {
+                self.cover(self.column[j]);
+                j = self.right[j];
+            }
+
+            stop = self.search(search);
+
+            let mut j = self.left[i];
+            while j != i {
+                self.uncover(self.column[j]);
+                j = self.left[j];
+            }
+            search.vec_rows.pop();
+            i = self.down[i];
+        }
+        self.uncover(header);
+
+        stop
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::solver::Solver;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_dlx_solve() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let solution = DlxSolver::new(&grid).solve().unwrap();
+        let expected = [[1, 3, 2], [4, 5, 1], [1, 3, 2]];
+        for (line, vec_n) in expected.iter().enumerate() {
+            for (column, n) in vec_n.iter().enumerate() {
+                let line_column =
+                    LineColumn::new(i32::try_from(line).unwrap(), i32::try_from(column).unwrap());
+                let cell = solution.get_cell(line_column).unwrap();
+                assert_eq!(cell.content, CellContent::Number(*n));
+            }
+        }
+        assert!(Solver::new(&solution).is_solved());
+    }
+
+    #[test]
+    fn test_dlx_count_solutions() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+        assert_eq!(DlxSolver::new(&grid).count_solutions(10), 1);
+
+        // Grilles impossibles
+        let grid = Grid::from_str(
+            "
+        a  a
+        b  b
+        ",
+        )
+        .unwrap();
+        let dlx_solver = DlxSolver::new(&grid);
+        assert_eq!(dlx_solver.count_solutions(10), 0);
+
+        let grid = Grid::from_str(
+            "
+        a  a  b
+        c  c  b
+        ",
+        )
+        .unwrap();
+        let dlx_solver = DlxSolver::new(&grid);
+        assert_eq!(dlx_solver.count_solutions(10), 0);
+
+        let grid = Grid::from_str(
+            "
+        a  b  b  b
+        ",
+        )
+        .unwrap();
+        let dlx_solver = DlxSolver::new(&grid);
+        // Sans indice, cette grille a plusieurs solutions :
+        // a=1 et la case de 'b' voisine de 'a' ne peut pas être 1 : b = 213, 231, 312 ou 321
+        assert_eq!(dlx_solver.count_solutions(10), 4);
+        assert_eq!(dlx_solver.count_solutions(1), 1);
+        assert_eq!(dlx_solver.count_solutions(0), 0);
+    }
+
+    #[test]
+    fn test_dlx_all_examples() {
+        // Tous les fichiers "./examples/ex*.txt" n'ont qu'une seule solution
+        for entry in std::fs::read_dir("./examples").unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_file()
+                && path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .starts_with("ex")
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"))
+            {
+                let file_content = std::fs::read_to_string(&path).unwrap();
+                let grid = Grid::from_str(&file_content).unwrap();
+                assert_eq!(DlxSolver::new(&grid).count_solutions(2), 1, "{path:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_dlx_no_solution() {
+        let grid = Grid::from_str(
+            "
+        # NOK car b7 n'est pas possible dans une zone de 5 cases
+        a1 b  b
+        b  b7  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let dlx_solver = DlxSolver::new(&grid);
+        assert!(dlx_solver.solve().is_none());
+        assert_eq!(dlx_solver.count_solutions(2), 0);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::grid::{CellContent, Grid, ParseGridError};
+use crate::line_column::LineColumn;
+use crate::zone_border::CellSide;
+
+/// Caractères de l'encodage compact (base64 sans caractère réservé dans une URL)
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Valeur d'une position sans case dans l'encodage compact
+pub(crate) const NO_CELL: u8 = 15;
+
+/// Séparateur des dimensions et des cases dans l'encodage compact
+const SEPARATOR: char = '.';
+
+impl Grid {
+    /// Encodage compact de la grille, utilisable tel quel dans une URL ou une query string
+    ///
+    /// Le texte contient la largeur et la hauteur de la grille suivies d'un caractère par
+    /// position, dans l'ordre de lecture : `3.3.xASkgwggy` pour la grille
+    ///
+    /// ```text
+    /// a1 b  b2
+    /// b4 b  b
+    /// c  c  c2
+    /// ```
+    ///
+    /// Chaque caractère code le chiffre de la case (ou l'absence de case) et les bordures à droite
+    /// et en bas de la case. Les zones sont donc retrouvées par leurs bordures : le nom des zones
+    /// n'est pas conservé et une zone non contiguë est décodée en plusieurs zones. Les chiffres
+    /// possibles d'une case en cours de résolution ne sont pas conservés.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        let (width, height, vec_codes) = self.position_codes();
+        let mut res = format!("{width}{SEPARATOR}{height}{SEPARATOR}");
+        for code in vec_codes {
+            res.push(char::from(ALPHABET[usize::from(code)]));
+        }
+        res
+    }
+
+    /// Largeur et hauteur de la grille normalisée (voir `normalized`) et code de chaque position
+    /// dans l'ordre de lecture : bordures à droite et en bas de la case (`WALL_RIGHT` et
+    /// `WALL_BOTTOM`) sur les 2 bits de poids fort et chiffre de la case (0 si le chiffre n'est pas
+    /// connu, `NO_CELL` sans case) sur les 4 bits de poids faible
+    pub(crate) fn position_codes(&self) -> (usize, usize, Vec<u8>) {
+        let grid = self.normalized();
+        let (width, height) = (grid.width(), grid.height());
+        let mut vec_codes = Vec::with_capacity(width * height);
+        for line in 0..i32::try_from(height).unwrap() {
+            for column in 0..i32::try_from(width).unwrap() {
+                let line_column = LineColumn::new(line, column);
+                let Some(cell) = grid.get_cell(line_column) else {
+                    vec_codes.push(NO_CELL);
+                    continue;
+                };
+                let mut walls = 0;
+                if grid.is_zone_border(line_column, CellSide::Right) {
+                    walls |= Grid::WALL_RIGHT;
+                }
+                if grid.is_zone_border(line_column, CellSide::Bottom) {
+                    walls |= Grid::WALL_BOTTOM;
+                }
+                let value = match cell.content {
+                    CellContent::Number(n) => n,
+                    CellContent::Undefined | CellContent::PossibleNumbers(_) => 0,
+                };
+                vec_codes.push(walls << 4 | value);
+            }
+        }
+        (width, height, vec_codes)
+    }
+
+    /// Construction d'une grille à partir de son encodage compact (voir `encode`)
+    ///
+    /// Les zones sont nommées 'a', 'b', 'c', etc. dans l'ordre de lecture de leur première case
+    /// (comme pour `normalized`)
+    ///
+    /// # Errors
+    /// Une erreur est retournée si les dimensions ne correspondent pas au nombre de cases
+    /// (`ParseGridError::InvalidDimensions`) ou si un caractère n'est pas valide (ligne, colonne et
+    /// caractère de la case)
+    pub fn decode(s: &str) -> Result<Grid, ParseGridError> {
+        let mut parts = s.trim().splitn(3, SEPARATOR);
+        let (Some(str_width), Some(str_height), Some(str_cells)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ParseGridError::InvalidDimensions(s.trim().to_string()));
+        };
+        let dimensions = format!("{str_width}{SEPARATOR}{str_height}");
+        let (Ok(width), Ok(height)) = (str_width.parse::<usize>(), str_height.parse::<usize>())
+        else {
+            return Err(ParseGridError::InvalidDimensions(dimensions));
+        };
+        let vec_chars: Vec<char> = str_cells.chars().collect();
+        if width.checked_mul(height) != Some(vec_chars.len()) {
+            return Err(ParseGridError::InvalidDimensions(dimensions));
+        }
+
+        let mut vec_codes = Vec::with_capacity(vec_chars.len());
+        for (index, c) in vec_chars.iter().enumerate() {
+            match ALPHABET.iter().position(|a| char::from(*a) == *c) {
+                Some(code) => vec_codes.push(u8::try_from(code).unwrap()),
+                None => {
+                    return Err(ParseGridError::invalid_token(
+                        i32::try_from(index / width).unwrap(),
+                        i32::try_from(index % width).unwrap(),
+                        &c.to_string(),
+                    ))
+                }
+            }
+        }
+        Self::from_position_codes(width, &vec_codes, |index| vec_chars[index].to_string())
+    }
+
+    /// Construction d'une grille de largeur `width` à partir du code de chaque position (voir
+    /// `position_codes`)
+    ///
+    /// `token_of` donne le texte de la position d'un code incorrect pour l'erreur retournée
+    pub(crate) fn from_position_codes(
+        width: usize,
+        codes: &[u8],
+        token_of: impl Fn(usize) -> String,
+    ) -> Result<Grid, ParseGridError> {
+        let height = codes.len().checked_div(width).unwrap_or_default();
+        let mut vec_values: Vec<Vec<Option<u8>>> = vec![Vec::new(); height];
+        let mut vec_walls: Vec<Vec<u8>> = vec![Vec::new(); height];
+        let mut vec_no_cell: Vec<LineColumn> = Vec::new();
+        for (index, code) in codes.iter().enumerate() {
+            let (line, column) = (index / width, index % width);
+            let line_column =
+                LineColumn::new(i32::try_from(line).unwrap(), i32::try_from(column).unwrap());
+            let (walls, value) = match code {
+                code if code & 0x0F == NO_CELL => {
+                    vec_no_cell.push(line_column);
+                    (Grid::WALL_RIGHT | Grid::WALL_BOTTOM, None)
+                }
+                code if code & 0x0F == 0 => (code >> 4, None),
+                code if code & 0x0F <= 9 => (code >> 4, Some(code & 0x0F)),
+                _ => {
+                    return Err(ParseGridError::invalid_token(
+                        line_column.line,
+                        line_column.column,
+                        &token_of(index),
+                    ))
+                }
+            };
+            vec_values[line].push(value);
+            vec_walls[line].push(walls);
+        }
+
+        // Une position sans case est isolée de ses voisines avant d'être retirée de la grille
+        for line_column in &vec_no_cell {
+            let line = usize::try_from(line_column.line).unwrap();
+            let column = usize::try_from(line_column.column).unwrap();
+            if column > 0 {
+                vec_walls[line][column - 1] |= Grid::WALL_RIGHT;
+            }
+            if line > 0 {
+                vec_walls[line - 1][column] |= Grid::WALL_BOTTOM;
+            }
+        }
+
+        let values: Vec<&[Option<u8>]> = vec_values.iter().map(Vec::as_slice).collect();
+        let walls: Vec<&[u8]> = vec_walls.iter().map(Vec::as_slice).collect();
+        let mut grid = Grid::from_walls(&values, &walls)?;
+        for line_column in vec_no_cell {
+            grid.remove_cell(line_column);
+        }
+        Ok(grid.normalized())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_encode_decode() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let code = grid.encode();
+        assert_eq!(code, "3.3.xASkgwggy");
+        assert!(code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c)));
+        let decoded = Grid::decode(&code).unwrap();
+        assert_eq!(decoded, grid);
+        assert_eq!(decoded.to_puzzle_string(), grid.to_puzzle_string());
+
+        // Grille avec des trous et position d'origine quelconque
+        let mut grid = Grid::from_str("a1 b\nd  b\nc  c3\n").unwrap();
+        grid.remove_cell(LineColumn::new(1, 0));
+        grid.translate(5, -2);
+        let decoded = Grid::decode(&grid.encode()).unwrap();
+        assert_eq!(decoded.cell_count(), 5);
+        assert!(decoded.get_cell(LineColumn::new(1, 0)).is_none());
+        assert_eq!(decoded, grid.normalized());
+
+        assert_eq!(
+            Grid::decode(&Grid::default().encode()).unwrap(),
+            Grid::default()
+        );
+    }
+
+    #[test]
+    fn test_decode_nok() {
+        let error = Grid::decode("3.3.BwAci").unwrap_err();
+        assert_eq!(error.token(), "3.3");
+
+        let error = Grid::decode("2.1.B*").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (0, 1, "*"));
+
+        // Chiffre 10 : caractère 'K'
+        let error = Grid::decode("1.1.K").unwrap_err();
+        assert_eq!(error.token(), "K");
+
+        assert!(Grid::decode("BwAci").is_err());
+    }
+}
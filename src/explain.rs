@@ -0,0 +1,215 @@
+use std::fmt;
+
+use crate::grid::CellContent;
+use crate::line_column::LineColumn;
+use crate::neighboring_line_columns::NeighboringLineColumns;
+use crate::solver::{Solver, SolvingAction};
+
+/// Raison pour laquelle un chiffre n'est pas (ou plus) possible dans une case
+/// (voir `Solver::explain_exclusion`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExclusionReason {
+    /// La case contient déjà un autre chiffre
+    CellHasNumber(u8),
+
+    /// Le chiffre est plus grand que la taille de la zone de la case (nom de la zone, taille de
+    /// la zone)
+    ZoneTooSmall(String, usize),
+
+    /// Le chiffre est déjà placé dans une autre case de la zone (nom de la zone, case qui
+    /// contient ce chiffre)
+    ZoneHasNumber(String, LineColumn),
+
+    /// Le chiffre est déjà placé dans une case voisine
+    NeighborHasNumber(LineColumn),
+
+    /// Le chiffre a été éliminé par cette action de la résolution (élimination par une paire de
+    /// valeurs voisines, réfutation par une recherche par 'essai', etc.)
+    EliminatedBy(SolvingAction),
+}
+
+impl fmt::Display for ExclusionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExclusionReason::CellHasNumber(n) => write!(f, "La case contient déjà [{n}]"),
+            ExclusionReason::ZoneTooSmall(zone_name, zone_len) => {
+                write!(f, "La zone '{zone_name}' n'a que {zone_len} cases")
+            }
+            ExclusionReason::ZoneHasNumber(zone_name, line_column) => {
+                write!(
+                    f,
+                    "La case {line_column} de la zone '{zone_name}' contient ce chiffre"
+                )
+            }
+            ExclusionReason::NeighborHasNumber(line_column) => {
+                write!(f, "La case voisine {line_column} contient ce chiffre")
+            }
+            ExclusionReason::EliminatedBy(action) => write!(f, "{action}"),
+        }
+    }
+}
+
+impl Solver {
+    /// Explique pourquoi le chiffre `n` n'est pas (ou plus) possible dans une case
+    ///
+    /// Les raisons sont recherchées dans l'état courant de la grille (chiffre déjà placé dans la
+    /// zone ou dans une case voisine, etc.) puis dans l'historique des actions de la résolution
+    /// (élimination par une paire de valeurs voisines, réfutation par une recherche par 'essai', etc.)
+    ///
+    /// Retourne une liste vide si le chiffre est toujours possible dans la case (ou si la case
+    /// n'existe pas dans la grille)
+    #[must_use]
+    pub fn explain_exclusion(&self, line_column: LineColumn, n: u8) -> Vec<ExclusionReason> {
+        let mut reasons = Vec::new();
+
+        let Some(content) = self.cell_content(line_column) else {
+            return reasons;
+        };
+        match content {
+            CellContent::Number(cell_n) if *cell_n == n => return reasons,
+            CellContent::Number(cell_n) => reasons.push(ExclusionReason::CellHasNumber(*cell_n)),
+            _ => (),
+        }
+
+        // Taille de la zone de la case
+        let grid = self.original_grid();
+        let c_zone = self.cell_zone(line_column);
+        let zone = &grid.hashmap_zones[&c_zone];
+        let zone_name = grid.zone_name(c_zone);
+        if usize::from(n) > zone.set_line_column.len() {
+            reasons.push(ExclusionReason::ZoneTooSmall(
+                zone_name.clone(),
+                zone.set_line_column.len(),
+            ));
+        }
+
+        // Chiffre déjà placé dans la zone
+        let mut zone_line_columns: Vec<LineColumn> = zone
+            .set_line_column
+            .iter()
+            .copied()
+            .filter(|zone_line_column| *zone_line_column != line_column)
+            .filter(|zone_line_column| {
+                self.cell_content(*zone_line_column) == Some(&CellContent::Number(n))
+            })
+            .collect();
+        zone_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+        for zone_line_column in zone_line_columns {
+            reasons.push(ExclusionReason::ZoneHasNumber(
+                zone_name.clone(),
+                zone_line_column,
+            ));
+        }
+
+        // Chiffre déjà placé dans une case voisine
+        let neighboring_line_columns =
+            NeighboringLineColumns::new(line_column, grid.min_line_column, grid.max_line_column);
+        for neighboring_line_column in neighboring_line_columns {
+            if self.cell_content(neighboring_line_column) == Some(&CellContent::Number(n)) {
+                reasons.push(ExclusionReason::NeighborHasNumber(neighboring_line_column));
+            }
+        }
+
+        // Actions de la résolution qui ont éliminé ce chiffre de cette case
+        let mut vec_actions: Vec<&SolvingAction> = self.history().iter().rev().collect();
+        while let Some(action) = vec_actions.pop() {
+            if let SolvingAction::Batch(batch_actions) = action {
+                vec_actions.extend(batch_actions.iter().rev());
+                continue;
+            }
+            if action.affected_cells() != [line_column] {
+                continue;
+            }
+            let excluded = match action {
+                SolvingAction::TryAndSolve(_, _, autre_n) => *autre_n == n,
+                SolvingAction::TryAndFail(_, n_fail, _) => *n_fail == n,
+                _ => action.removed_digits().contains(&n),
+            };
+            if excluded {
+                reasons.push(ExclusionReason::EliminatedBy(action.clone()));
+            }
+        }
+
+        // Chiffre encore possible pour cette case ?
+        if let CellContent::PossibleNumbers(simple_09_set) = content {
+            if simple_09_set.contains(n) {
+                reasons.clear();
+            }
+        }
+
+        reasons
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use crate::solver::SolvingStrategy;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_explain_exclusion() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        let _ = solver.apply_strategy_all(SolvingStrategy::NumbersInZone);
+        let _ = solver.apply_strategy_all(SolvingStrategy::NumbersNeighboring);
+
+        // [1] n'est pas possible en (1, 1) : la case voisine (0, 0) contient 1
+        let line_column = LineColumn::new(1, 1);
+        let reasons = solver.explain_exclusion(line_column, 1);
+        assert!(reasons.contains(&ExclusionReason::NeighborHasNumber(LineColumn::new(0, 0))));
+        assert!(reasons
+            .iter()
+            .any(|reason| matches!(reason, ExclusionReason::EliminatedBy(_))));
+
+        // [2] n'est pas possible en (0, 1) : la case (0, 2) de la zone 'b' contient 2
+        let reasons = solver.explain_exclusion(LineColumn::new(0, 1), 2);
+        assert!(reasons.contains(&ExclusionReason::ZoneHasNumber(
+            "b".to_string(),
+            LineColumn::new(0, 2)
+        )));
+
+        // [6] n'est pas possible dans une zone de 5 cases
+        let reasons = solver.explain_exclusion(LineColumn::new(0, 1), 6);
+        assert!(reasons.contains(&ExclusionReason::ZoneTooSmall("b".to_string(), 5)));
+
+        // Une case définie ne peut contenir que son chiffre
+        assert!(solver
+            .explain_exclusion(LineColumn::new(0, 0), 1)
+            .is_empty());
+        assert_eq!(
+            solver.explain_exclusion(LineColumn::new(0, 0), 2)[0],
+            ExclusionReason::CellHasNumber(1)
+        );
+    }
+
+    #[test]
+    fn test_explain_exclusion_possible() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        let _ = solver.solve_step();
+
+        // [3] est encore possible dans (0, 1)
+        assert!(solver
+            .explain_exclusion(LineColumn::new(0, 1), 3)
+            .is_empty());
+    }
+}
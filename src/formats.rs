@@ -0,0 +1,12 @@
+//! Formats d'import et d'export des grilles utilisés par d'autres applications
+//!
+//! ```rust
+//! use tectonic::formats::app_string;
+//!
+//! let grid = app_string::parse("3x3 102400002 011111222").unwrap();
+//! assert_eq!(grid.cell_count(), 9);
+//! assert_eq!(app_string::emit(&grid).unwrap(), "3x3 102400002 011111222");
+//! ```
+
+pub mod app_string;
+pub mod janko;
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use crate::grid::{CellContent, Grid, ParseGridError};
+use crate::line_column::LineColumn;
+
+/// Caractères des numéros de zone (dans l'ordre des numéros)
+const ZONE_INDEX_CHARS: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Séparateurs des parties du texte
+const SEPARATORS: [char; 4] = [',', ';', ':', '|'];
+
+impl Grid {
+    /// Construction d'une grille depuis le texte d'export des applications Suguru : le chiffre de
+    /// chaque case (`0` ou `.` si le chiffre n'est pas connu) puis le numéro de la zone de chaque
+    /// case, case après case dans l'ordre de lecture, éventuellement précédés des dimensions de
+    /// la grille (largeur x hauteur)
+    ///
+    /// ```text
+    /// 3x3 102400002 011111222
+    /// ```
+    ///
+    /// Les parties du texte sont séparées par des espaces, des ',', des ';', des ':' ou des '|'.
+    /// Sans dimensions, la grille est carrée. Un numéro de zone est un chiffre puis une lettre
+    /// (`a` à `z` puis `A` à `Z`) au-delà de 10 zones. Les zones sont nommées 'a', 'b', 'c', etc.
+    /// dans l'ordre de lecture de leur première case (comme pour `normalized`).
+    ///
+    /// # Errors
+    /// Une erreur est retournée si le texte n'a pas deux ou trois parties, si les dimensions ne
+    /// correspondent pas au nombre de cases ou si un caractère est incorrect (ligne, colonne et
+    /// caractère de la case)
+    pub fn from_app_string(s: &str) -> Result<Grid, ParseGridError> {
+        let vec_parts: Vec<&str> = s
+            .split(|c: char| c.is_whitespace() || SEPARATORS.contains(&c))
+            .filter(|part| !part.is_empty())
+            .collect();
+        let (option_dimensions, values, zones) = match vec_parts.as_slice() {
+            [values, zones] => (None, *values, *zones),
+            [dimensions, values, zones] => (Some(*dimensions), *values, *zones),
+            _ => return Err(ParseGridError::InvalidLine(s.trim().to_string())),
+        };
+        let vec_values: Vec<char> = values.chars().collect();
+        let vec_zones: Vec<char> = zones.chars().collect();
+
+        let (width, height) = match option_dimensions {
+            Some(dimensions) => {
+                let invalid = || ParseGridError::InvalidDimensions(dimensions.to_string());
+                let (width, height) = dimensions.split_once(['x', 'X']).ok_or_else(invalid)?;
+                let width = width.parse::<usize>().map_err(|_| invalid())?;
+                let height = height.parse::<usize>().map_err(|_| invalid())?;
+                (width, height)
+            }
+            None => {
+                // Grille carrée
+                let side = (1..=vec_values.len())
+                    .find(|side| side * side >= vec_values.len())
+                    .unwrap_or_default();
+                (side, side)
+            }
+        };
+        let cell_count = width.checked_mul(height).unwrap_or_default();
+        if cell_count == 0 || vec_values.len() != cell_count || vec_zones.len() != cell_count {
+            let dimensions = format!("{width}x{height}");
+            return Err(ParseGridError::InvalidDimensions(
+                option_dimensions.unwrap_or(&dimensions).to_string(),
+            ));
+        }
+
+        let mut grid = Grid::default();
+        let mut hashmap_zones: HashMap<char, char> = HashMap::new();
+        for (index, (c_value, c_index)) in vec_values.into_iter().zip(vec_zones).enumerate() {
+            let line = i32::try_from(index / width).unwrap();
+            let column = i32::try_from(index % width).unwrap();
+            let content = match c_value {
+                '0' | '.' => None,
+                _ => Some(ParseGridError::parse_digit(
+                    line,
+                    column,
+                    &c_value.to_string(),
+                )?),
+            };
+            if !ZONE_INDEX_CHARS.contains(c_index) {
+                return Err(ParseGridError::invalid_token(
+                    line,
+                    column,
+                    &c_index.to_string(),
+                ));
+            }
+            let c_zone = match hashmap_zones.get(&c_index) {
+                Some(c_zone) => *c_zone,
+                None => {
+                    let name = Grid::canonical_zone_name(hashmap_zones.len());
+                    let c_zone = grid.zone_id(&name)?;
+                    hashmap_zones.insert(c_index, c_zone);
+                    c_zone
+                }
+            };
+            grid.add_cell((line, column), c_zone, content);
+        }
+
+        Ok(grid)
+    }
+
+    /// Texte d'export des applications Suguru de la grille (lu par `from_app_string`) avec ses
+    /// dimensions
+    ///
+    /// Les zones sont numérotées dans l'ordre de lecture de leur première case. Retourne `None`
+    /// si la grille a des trous (ce format n'a que des grilles rectangulaires complètes) ou plus
+    /// de 62 zones.
+    #[must_use]
+    pub fn to_app_string(&self) -> Option<String> {
+        let (min, max) = self.bounds()?;
+        let mut values = String::new();
+        let mut zones = String::new();
+        let mut hashmap_indexes: HashMap<char, char> = HashMap::new();
+        for line in min.line..=max.line {
+            for column in min.column..=max.column {
+                let cell = self.get_cell(LineColumn::new(line, column))?;
+                match cell.content {
+                    CellContent::Number(n) => values.push(char::from(b'0' + n)),
+                    CellContent::Undefined | CellContent::PossibleNumbers(_) => values.push('0'),
+                }
+                let c_index = match hashmap_indexes.get(&cell.c_zone) {
+                    Some(c_index) => *c_index,
+                    None => {
+                        let c_index = ZONE_INDEX_CHARS.chars().nth(hashmap_indexes.len())?;
+                        hashmap_indexes.insert(cell.c_zone, c_index);
+                        c_index
+                    }
+                };
+                zones.push(c_index);
+            }
+        }
+
+        Some(format!(
+            "{}x{} {values} {zones}",
+            self.width(),
+            self.height()
+        ))
+    }
+}
+
+/// Grille du texte d'export des applications Suguru (voir `Grid::from_app_string`)
+///
+/// # Errors
+/// Une erreur est retournée si le texte est incorrect (voir `Grid::from_app_string`)
+pub fn parse(s: &str) -> Result<Grid, ParseGridError> {
+    Grid::from_app_string(s)
+}
+
+/// Texte d'export des applications Suguru d'une grille (voir `Grid::to_app_string`)
+#[must_use]
+pub fn emit(grid: &Grid) -> Option<String> {
+    grid.to_app_string()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_app_string() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let app_string = grid.to_app_string().unwrap();
+        assert_eq!(app_string, "3x3 102400002 011111222");
+        assert_eq!(Grid::from_app_string(&app_string).unwrap(), grid);
+
+        // Grille carrée sans dimensions et autres séparateurs
+        assert_eq!(Grid::from_app_string("102400002,577777333").unwrap(), grid);
+        assert_eq!(
+            Grid::from_app_string(" 3X3 | 1.24....2 | 0bbbbbccc ").unwrap(),
+            grid
+        );
+
+        // Grille rectangulaire
+        let grid = Grid::from_str("a1 a2 b\nc  c  b").unwrap();
+        assert_eq!(grid.to_app_string().unwrap(), "3x2 120000 001221");
+        assert_eq!(Grid::from_app_string("3x2 120000 001221").unwrap(), grid);
+
+        // Grille avec un trou
+        let grid = Grid::from_str("a1 b\nb").unwrap();
+        assert!(grid.to_app_string().is_none());
+    }
+
+    #[test]
+    fn test_app_string_nok() {
+        let error = Grid::from_app_string("102400002").unwrap_err();
+        assert_eq!(error, ParseGridError::InvalidLine("102400002".to_string()));
+
+        let error = Grid::from_app_string("3x2 102400002 011111222").unwrap_err();
+        assert_eq!(error, ParseGridError::InvalidDimensions("3x2".to_string()));
+        let error = Grid::from_app_string("10240000 01111122").unwrap_err();
+        assert_eq!(error, ParseGridError::InvalidDimensions("3x3".to_string()));
+        let error = Grid::from_app_string("3y3 102400002 011111222").unwrap_err();
+        assert_eq!(error, ParseGridError::InvalidDimensions("3y3".to_string()));
+
+        let error = Grid::from_app_string("3x3 102x00002 011111222").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 0, "x"));
+        let error = Grid::from_app_string("3x3 102400002 0111_1222").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 1, "_"));
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::grid::{normalized_text, Grid, ParseGridError};
+
+impl Grid {
+    /// Construction d'une grille au format texte des problèmes Suguru du site janko.at
+    ///
+    /// Seules les sections `problem` (chiffres de chaque case, `-` si le chiffre n'est pas connu) et
+    /// `areas` (numéro de la zone de chaque case) sont utilisées. Les autres lignes (`begin`,
+    /// `puzzle`, `author`, `size`, `solution`, etc.) sont ignorées.
+    ///
+    /// ```text
+    /// begin
+    /// puzzle suguru
+    /// size 3
+    /// problem
+    /// 1 - 2
+    /// 4 - -
+    /// - - 2
+    /// areas
+    /// 1 2 2
+    /// 2 2 2
+    /// 3 3 3
+    /// end
+    /// ```
+    ///
+    /// Les zones sont nommées 'a', 'b', 'c', etc. dans l'ordre de lecture de leur première case
+    /// (comme pour `normalized`)
+    ///
+    /// # Errors
+    /// Une erreur est retournée (ligne, colonne et texte de la case) si une section manque, si
+    /// les deux sections n'ont pas la même forme ou si un chiffre n'est pas entre 1 et 9
+    pub fn from_janko(s: &str) -> Result<Grid, ParseGridError> {
+        let mut hashmap_sections: HashMap<String, Vec<Vec<&str>>> = HashMap::new();
+        let mut current_section: Option<String> = None;
+
+        let s = normalized_text(s);
+        for str_line in s.lines() {
+            let vec_tokens: Vec<&str> = str_line.split_whitespace().collect();
+            let Some(first_token) = vec_tokens.first() else {
+                continue;
+            };
+
+            // Un mot clef commence une nouvelle section (ou est une ligne d'information)
+            if first_token.len() > 1 && first_token.chars().all(|c| c.is_ascii_alphabetic()) {
+                let keyword = first_token.to_lowercase();
+                current_section = if vec_tokens.len() == 1 {
+                    hashmap_sections.insert(keyword.clone(), Vec::new());
+                    Some(keyword)
+                } else {
+                    None
+                };
+                continue;
+            }
+            if let Some(section) = &current_section {
+                hashmap_sections.get_mut(section).unwrap().push(vec_tokens);
+            }
+        }
+
+        let problem = hashmap_sections
+            .get("problem")
+            .ok_or_else(|| ParseGridError::MissingSection("problem".to_string()))?;
+        let areas = hashmap_sections
+            .get("areas")
+            .ok_or_else(|| ParseGridError::MissingSection("areas".to_string()))?;
+
+        let mut grid = Grid::default();
+        let mut hashmap_zones: HashMap<&str, char> = HashMap::new();
+        for line in 0..problem.len().max(areas.len()) {
+            let line_values = problem.get(line).map(Vec::as_slice).unwrap_or_default();
+            let line_areas = areas.get(line).map(Vec::as_slice).unwrap_or_default();
+            let i_line = i32::try_from(line).unwrap();
+            for column in 0..line_values.len().max(line_areas.len()) {
+                let i_column = i32::try_from(column).unwrap();
+                let (str_value, area) = match (line_values.get(column), line_areas.get(column)) {
+                    (Some(str_value), Some(area)) => (*str_value, *area),
+                    (Some(token), None) | (None, Some(token)) => {
+                        return Err(ParseGridError::shape_mismatch(i_line, i_column, token))
+                    }
+                    (None, None) => unreachable!(),
+                };
+                let content = match str_value {
+                    "-" | "." => None,
+                    _ => Some(ParseGridError::parse_digit(i_line, i_column, str_value)?),
+                };
+                let c_zone = match hashmap_zones.get(area) {
+                    Some(c_zone) => *c_zone,
+                    None => {
+                        let name = Grid::canonical_zone_name(hashmap_zones.len());
+                        let c_zone = grid.zone_id(&name)?;
+                        hashmap_zones.insert(area, c_zone);
+                        c_zone
+                    }
+                };
+                grid.add_cell((i_line, i_column), c_zone, content);
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+/// Grille du texte d'un problème Suguru du site janko.at (voir `Grid::from_janko`)
+///
+/// # Errors
+/// Une erreur est retournée si le texte est incorrect (voir `Grid::from_janko`)
+pub fn parse(s: &str) -> Result<Grid, ParseGridError> {
+    Grid::from_janko(s)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_janko() {
+        let grid = Grid::from_janko(
+            "
+        begin
+        puzzle suguru
+        author Moi
+        size 3
+        problem
+        1 - 2
+        4 - -
+        - - 2
+        areas
+        1 7 7
+        7 7 7
+        3 3 3
+        solution
+        1 3 2
+        4 5 1
+        1 3 2
+        end
+        ",
+        )
+        .unwrap();
+        assert_eq!(
+            grid.to_puzzle_string(),
+            Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2")
+                .unwrap()
+                .to_puzzle_string()
+        );
+    }
+
+    #[test]
+    fn test_from_janko_nok() {
+        let error = Grid::from_janko("problem\n1 -\n").unwrap_err();
+        assert_eq!(error.token(), "areas");
+
+        let error = Grid::from_janko("problem\n1 -\nareas\n1 2 2\n").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (0, 2, "2"));
+
+        let error = Grid::from_janko("problem\n1 x\nareas\n1 2\n").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (0, 1, "x"));
+        assert!(parse("problem\n1 x\nareas\n1 2\n").is_err());
+    }
+}
@@ -1,13 +1,19 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
+use crate::grid_formatter::GridFormatter;
 use crate::line_column::LineColumn;
+use crate::neighboring_line_columns::NeighboringLineColumns;
+use crate::parse_options::{EmptyCellMarker, ParseOptions, RaggedRows};
+use crate::rng::Rng;
 use crate::simple_09_set::Simple09Set;
 
 /// Information pour une zone de la grille tectonic
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Zone {
     // Lettre qui représente cette zone
     pub c_zone: char,
@@ -16,8 +22,46 @@ pub struct Zone {
     pub set_line_column: HashSet<LineColumn>,
 }
 
+impl Zone {
+    /// Nombre de cases de la zone (qui contiendront les chiffres 1 à `size()`)
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.set_line_column.len()
+    }
+
+    /// Itérateur sur les cases de la zone, triées par ligne puis par colonne
+    pub fn cells(&self) -> impl Iterator<Item = LineColumn> {
+        let mut vec_line_column: Vec<LineColumn> = self.set_line_column.iter().copied().collect();
+        vec_line_column.sort_by_key(|line_column| (line_column.line, line_column.column));
+        vec_line_column.into_iter()
+    }
+
+    /// Retourne true si la case fait partie de la zone
+    #[must_use]
+    pub fn contains(&self, line_column: LineColumn) -> bool {
+        self.set_line_column.contains(&line_column)
+    }
+
+    /// Chiffres de 1 à `size()` qui ne sont encore placés dans aucune case de la zone de la
+    /// grille `grid` (par ordre croissant)
+    #[must_use]
+    pub fn missing_digits(&self, grid: &Grid) -> Vec<u8> {
+        let placed: HashSet<u8> = self
+            .set_line_column
+            .iter()
+            .filter_map(|line_column| match grid.get_cell(*line_column)?.content {
+                CellContent::Number(n) => Some(n),
+                CellContent::Undefined | CellContent::PossibleNumbers(_) => None,
+            })
+            .collect();
+        let size = u8::try_from(self.size()).unwrap_or(u8::MAX);
+        (1..=size).filter(|n| !placed.contains(n)).collect()
+    }
+}
+
 /// Contenu d'une case
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CellContent {
     // Case avec un contenu non défini (construction initiale)
     #[default]
@@ -30,8 +74,17 @@ pub enum CellContent {
     PossibleNumbers(Simple09Set),
 }
 
+impl CellContent {
+    /// Retourne true si la case contient un chiffre
+    #[must_use]
+    pub fn is_number(&self) -> bool {
+        matches!(self, CellContent::Number(_))
+    }
+}
+
 /// Information pour une case de la grille tectonic
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell {
     // Zone de la case
     pub c_zone: char,
@@ -41,10 +94,22 @@ pub struct Cell {
 
     // Contenu de la case
     pub content: CellContent,
+
+    // Chiffre de la case connu au départ (et non placé pendant la résolution)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub is_given: bool,
 }
 
 /// Représentation d'une grille tectonic
+///
+/// Avec la feature `serde`, une grille est sérialisée par la liste de ses cases (triées par ligne
+/// puis colonne) : les zones et les dimensions de la grille sont reconstruites à la désérialisation
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "GridCells", into = "GridCells")
+)]
 pub struct Grid {
     // Numéro de ligne/column min et max.
     pub min_line_column: LineColumn,
@@ -57,43 +122,71 @@ pub struct Grid {
     // HashMap des différentes cases de la grille
     // La clef est la ligne_colonne de la case dans la grille
     pub hashmap_cells: HashMap<LineColumn, Cell>,
+
+    // Noms des zones désignées par plusieurs lettres (voir `zone_id`)
+    // La clef est le caractère qui représente la zone dans la grille
+    zone_names: HashMap<char, String>,
 }
 
+/// Caractères (zones Unicode à usage privé) utilisés pour représenter les zones désignées par
+/// plusieurs lettres, dans l'ordre d'attribution
+const MULTI_CHAR_ZONE_IDS: [(u32, u32); 3] = [
+    (0xE000, 0xF8FF),
+    (0xF_0000, 0xF_FFFD),
+    (0x10_0000, 0x10_FFFD),
+];
+
+/// Affichage de la grille avec les noms des zones et, avec `{grid:#}`, affichage avec les
+/// bordures des zones (voir `GridFormatter` pour les autres affichages)
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut res = String::new();
-        // La première ligne contient les numéros de colonnes
-        res.push_str("  ");
-        for column in self.min_line_column.column..=self.max_line_column.column {
-            res.push_str(&format!(" {column:2}"));
+        let formatter = if f.alternate() {
+            GridFormatter::boxed()
+        } else {
+            GridFormatter::default()
+        };
+        write!(f, "{}", formatter.format(self))
+    }
+}
+
+/// Deux grilles sont égales si elles ont les mêmes cases (positions et contenus) réparties de la
+/// même manière dans leurs zones, quels que soient les noms de ces zones
+impl PartialEq for Grid {
+    fn eq(&self, other: &Self) -> bool {
+        if self.hashmap_cells.len() != other.hashmap_cells.len()
+            || self.hashmap_zones.len() != other.hashmap_zones.len()
+        {
+            return false;
         }
-        res.push('\n');
-        // Contenu de la grille
-        for line in self.min_line_column.line..=self.max_line_column.line {
-            res.push_str(&format!("{line:2} ")); // Numéro de ligne à gauche
-            for column in self.min_line_column.column..=self.max_line_column.column {
-                let line_column = LineColumn::new(line, column);
-                let option_cell = self.get_cell(line_column);
-                let str_cell = match option_cell {
-                    None => String::new(),
-                    Some(cell) => {
-                        let zone = cell.c_zone;
-                        match &cell.content {
-                            CellContent::Undefined => format!("{zone}"),
-                            CellContent::Number(n) => format!("{zone}{n}"),
-                            // CellContent::PossibleNumbers(_) => format!("{zone}?"),
-                            CellContent::PossibleNumbers(simple_09_set) => {
-                                format!("{zone}{simple_09_set} ",)
-                            }
-                        }
-                    }
-                };
-                res.push_str(&format!("{str_cell:3}"));
+
+        // Correspondance entre les zones des deux grilles
+        let mut hashmap_zones: HashMap<char, char> = HashMap::new();
+        for (line_column, cell) in &self.hashmap_cells {
+            let Some(other_cell) = other.hashmap_cells.get(line_column) else {
+                return false;
+            };
+            if cell.content != other_cell.content {
+                return false;
+            }
+            let c_zone = hashmap_zones
+                .entry(cell.c_zone)
+                .or_insert(other_cell.c_zone);
+            if *c_zone != other_cell.c_zone {
+                return false;
             }
-            res.push('\n');
         }
 
-        write!(f, "{res}")
+        // Deux zones différentes ne correspondent pas à une même zone de l'autre grille
+        hashmap_zones.values().collect::<HashSet<_>>().len() == hashmap_zones.len()
+    }
+}
+
+impl Eq for Grid {}
+
+/// Hash cohérent avec l'égalité des grilles (voir `Grid::fingerprint`)
+impl Hash for Grid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.fingerprint());
     }
 }
 
@@ -110,6 +203,13 @@ impl Grid {
         self.min_line_column.min(line_column);
         self.max_line_column.max(line_column);
 
+        // Une case redéfinie dans une autre zone quitte sa zone précédente
+        if let Some(c_zone_before) = self.get_cell(line_column).map(|cell| cell.c_zone) {
+            if c_zone_before != c_zone {
+                self.remove_from_zone(c_zone_before, line_column);
+            }
+        }
+
         let zone = self.get_or_create_zone(c_zone);
         zone.c_zone = c_zone;
         zone.set_line_column.insert(line_column);
@@ -121,6 +221,7 @@ impl Grid {
             None => CellContent::Undefined,
             Some(n) => CellContent::Number(n),
         };
+        cell.is_given = content.is_some();
     }
 
     /// Ajoute une ligne (à partir de la colonne 0) dans la grille tectonic en précisant :
@@ -139,237 +240,2646 @@ impl Grid {
         }
     }
 
-    /// Accesseur (privé) à une zone de la grille (créée si elle n'existe pas)
-    #[must_use]
-    fn get_or_create_zone(&mut self, c_zone: char) -> &mut Zone {
-        self.hashmap_zones
-            .entry(c_zone)
-            .or_insert_with(Zone::default)
+    /// Supprime une case de la grille
+    ///
+    /// La case est retirée de sa zone (une zone sans case est supprimée) et les dimensions de la
+    /// grille sont recalculées
+    ///
+    /// Retourne la case supprimée (None si elle n'existe pas)
+    pub fn remove_cell(&mut self, line_column: LineColumn) -> Option<Cell> {
+        let cell = self.hashmap_cells.remove(&line_column)?;
+        self.remove_from_zone(cell.c_zone, line_column);
+        self.update_min_max_line_column();
+        Some(cell)
     }
 
-    /// Accesseur (public) à une zone de la grille (None) si elle n'existe pas
-    #[must_use]
-    pub fn get_zone(&mut self, c_zone: char) -> Option<&mut Zone> {
-        self.hashmap_zones.get_mut(&c_zone)
+    /// Efface le contenu d'une case de la grille (qui n'a alors plus de chiffre)
+    ///
+    /// Retourne le chiffre effacé (None si la case n'existe pas ou n'avait pas de chiffre)
+    pub fn clear_number(&mut self, line_column: LineColumn) -> Option<u8> {
+        let cell = self.get_mut_cell(line_column)?;
+        cell.is_given = false;
+        match std::mem::take(&mut cell.content) {
+            CellContent::Number(n) => Some(n),
+            CellContent::Undefined | CellContent::PossibleNumbers(_) => None,
+        }
     }
 
-    /// Accesseur (privé) à une case de la grille (créée si elle n'existe pas)
+    /// Place (ou remplace) le chiffre d'une case existante de la grille
+    ///
+    /// Ce chiffre est considéré comme connu au départ (voir `Cell::is_given`)
+    ///
+    /// Retourne false si la case n'existe pas
+    pub fn set_number(&mut self, line_column: LineColumn, n: u8) -> bool {
+        match self.get_mut_cell(line_column) {
+            None => false,
+            Some(cell) => {
+                cell.content = CellContent::Number(n);
+                cell.is_given = true;
+                true
+            }
+        }
+    }
+
+    /// Copie de la grille qui ne contient que les chiffres connus au départ (voir
+    /// `Cell::is_given`) : grille à résoudre d'une grille partiellement ou totalement résolue
+    ///
+    /// Les formats texte (`to_puzzle_string`, `to_csv`) n'enregistrent pas quels chiffres sont
+    /// connus au départ : tous les chiffres d'une grille relue sont des chiffres connus au départ
+    /// et `cleared` n'efface alors rien. Pour relire une grille en cours de résolution, il faut
+    /// la grille à résoudre et sa solution (`to_binary_with_solution`) ou la sérialisation des
+    /// cases avec la feature `serde`.
     #[must_use]
-    fn get_or_create_cell(&mut self, line_column: LineColumn) -> &mut Cell {
-        self.hashmap_cells
-            .entry(line_column)
-            .or_insert_with(Cell::default)
+    pub fn cleared(&self) -> Grid {
+        let mut grid = self.clone();
+        grid.reset_to_givens();
+        grid
     }
 
-    /// Accesseur (public) à une case non mutable de la grille (None) si elle n'existe pas
+    /// Grille à résoudre obtenue à partir d'une grille résolue en ne conservant que les chiffres
+    /// des cases `keep` (qui deviennent les chiffres connus au départ)
+    ///
+    /// Le contenu de toutes les autres cases est effacé
     #[must_use]
-    pub fn get_cell(&self, line_column: LineColumn) -> Option<&Cell> {
-        self.hashmap_cells.get(&line_column)
+    pub fn puzzle_from_solution(&self, keep: &HashSet<LineColumn>) -> Grid {
+        let mut grid = self.clone();
+        for (line_column, cell) in &mut grid.hashmap_cells {
+            if keep.contains(line_column) && cell.content.is_number() {
+                cell.is_given = true;
+            } else {
+                cell.content = CellContent::Undefined;
+                cell.is_given = false;
+            }
+        }
+        grid
     }
 
-    /// Accesseur (public) à une case mutable de la grille (None) si elle n'existe pas
+    /// Grille à résoudre obtenue à partir d'une grille résolue en ne conservant que les chiffres
+    /// de `count` cases tirées au hasard (voir `puzzle_from_solution`)
+    ///
+    /// Le tirage est reproductible : une même graine `seed` conserve toujours les mêmes cases
     #[must_use]
-    pub fn get_mut_cell(&mut self, line_column: LineColumn) -> Option<&mut Cell> {
-        self.hashmap_cells.get_mut(&line_column)
+    pub fn puzzle_from_solution_random(&self, count: usize, seed: u64) -> Grid {
+        let mut vec_line_column: Vec<LineColumn> = self
+            .cells()
+            .filter(|(_, cell)| cell.content.is_number())
+            .map(|(line_column, _)| *line_column)
+            .collect();
+        Rng::new(seed).shuffle(&mut vec_line_column);
+        vec_line_column.truncate(count);
+        self.puzzle_from_solution(&vec_line_column.into_iter().collect())
     }
-}
 
-/// Erreur rencontrée lors du parsing d'une grille avec `FromStr`
-#[derive(Debug)]
-pub struct ParseGridError(i32, i32);
+    /// Grille dont les chiffres sont renommés selon `permutation` : le chiffre `n` devient
+    /// `permutation[n - 1]`
+    ///
+    /// La grille obtenue est vérifiée (voir `validate`) : retourne None si `permutation` n'est pas
+    /// une permutation des chiffres 1 à 9 ou si elle ajoute un problème de structure (chiffre trop
+    /// grand pour sa zone, chiffres voisins égaux, etc.)
+    #[must_use]
+    pub fn relabeled_digits(&self, permutation: &[u8; 9]) -> Option<Grid> {
+        let mut sorted_permutation = *permutation;
+        sorted_permutation.sort_unstable();
+        if sorted_permutation != [1, 2, 3, 4, 5, 6, 7, 8, 9] {
+            return None;
+        }
 
-impl fmt::Display for ParseGridError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Grid parsing error on line={}, column={}",
-            self.0, self.1
-        )
+        let mut grid = self.clone();
+        for cell in grid.hashmap_cells.values_mut() {
+            cell.content = match &cell.content {
+                CellContent::Number(n) if (1..=9).contains(n) => {
+                    CellContent::Number(permutation[usize::from(*n) - 1])
+                }
+                CellContent::PossibleNumbers(simple_09_set) => {
+                    let mut new_set = Simple09Set::default();
+                    for n in simple_09_set.as_vec_u8() {
+                        new_set.insert(permutation[usize::from(n) - 1]);
+                    }
+                    CellContent::PossibleNumbers(new_set)
+                }
+                content => content.clone(),
+            };
+        }
+
+        if grid.validate().len() > self.validate().len() {
+            return None;
+        }
+        Some(grid)
     }
-}
 
-impl Error for ParseGridError {}
+    /// Variante de la grille dont les chiffres sont renommés au hasard (voir `relabeled_digits`)
+    ///
+    /// Pour respecter la taille de toutes les zones, seuls les chiffres compris entre deux tailles
+    /// de zones successives de la grille sont permutés entre eux : avec des zones de 2 et 5 cases,
+    /// les chiffres 1 et 2 sont permutés et les chiffres 3, 4 et 5 sont permutés. La grille
+    /// obtenue a ainsi une solution si et seulement si la grille d'origine en a une.
+    ///
+    /// Le tirage est reproductible : une même graine `seed` produit toujours la même variante
+    #[must_use]
+    pub fn shuffled_digits(&self, seed: u64) -> Grid {
+        let mut sizes: Vec<u8> = self
+            .hashmap_zones
+            .values()
+            .map(|zone| u8::try_from(zone.size().min(9)).unwrap())
+            .collect();
+        sizes.push(9);
+        sizes.sort_unstable();
+        sizes.dedup();
 
-impl FromStr for Grid {
-    type Err = ParseGridError;
+        let mut rng = Rng::new(seed);
+        let mut permutation = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut first_digit = 1;
+        for size in sizes {
+            let band = &mut permutation[usize::from(first_digit) - 1..usize::from(size)];
+            rng.shuffle(band);
+            first_digit = size + 1;
+        }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut grid = Grid::default();
+        self.relabeled_digits(&permutation)
+            .expect("Permutation compatible avec la taille des zones")
+    }
 
-        // Numéro de ligne initialement
-        let mut line = -1;
+    /// Efface le contenu de toutes les cases dont le chiffre n'était pas connu au départ (voir
+    /// `Cell::is_given`) : la grille revient à son état avant résolution
+    pub fn reset_to_givens(&mut self) {
+        for cell in self.hashmap_cells.values_mut() {
+            if !cell.is_given {
+                cell.content = CellContent::Undefined;
+            }
+        }
+    }
 
-        for str_line in s.lines() {
-            let str_line = str_line.trim();
-            if str_line.starts_with('#') {
-                continue;
+    /// Retire une case d'une zone et supprime cette zone si elle ne contient plus de case
+    fn remove_from_zone(&mut self, c_zone: char, line_column: LineColumn) {
+        if let Some(zone) = self.hashmap_zones.get_mut(&c_zone) {
+            zone.set_line_column.remove(&line_column);
+            if zone.set_line_column.is_empty() {
+                self.hashmap_zones.remove(&c_zone);
             }
-            if !str_line.is_empty() {
-                line += 1;
+        }
+    }
 
-                // Numéro de colonne initialement
-                let mut column = -1;
+    /// Recalcule les numéros de ligne/colonne min et max d'après les cases restantes de la
+    /// grille (voir `bounds`), (0, 0) pour une grille vide
+    fn update_min_max_line_column(&mut self) {
+        (self.min_line_column, self.max_line_column) = self.bounds().unwrap_or_default();
+    }
 
-                for str_cell in str_line.split(' ') {
-                    let vec_char: Vec<char> = str_cell.chars().collect();
-                    if vec_char.is_empty() {
-                        // Espace entre les espaces...
-                        continue;
+    /// Construction d'une grille à partir de la matrice des zones et de la matrice des chiffres
+    /// (une ligne de matrice par ligne de la grille, à partir de la ligne et de la colonne 0) :
+    ///
+    /// * `zones` contient la lettre de la zone de chaque case
+    /// * `values` contient le chiffre de chaque case s'il est connu
+    ///
+    /// # Errors
+    /// Une erreur est retournée (ligne, colonne et texte de la case) si les deux matrices n'ont
+    /// pas les mêmes dimensions ou si un chiffre n'est pas entre 1 et 9
+    pub fn from_matrices(
+        zones: &[&[char]],
+        values: &[&[Option<u8>]],
+    ) -> Result<Grid, ParseGridError> {
+        let mut grid = Grid::default();
+        for line in 0..zones.len().max(values.len()) {
+            let line_zones = zones.get(line).copied().unwrap_or_default();
+            let line_values = values.get(line).copied().unwrap_or_default();
+            let i_line = i32::try_from(line).unwrap();
+            for column in 0..line_zones.len().max(line_values.len()) {
+                let i_column = i32::try_from(column).unwrap();
+                let (c_zone, content) = match (line_zones.get(column), line_values.get(column)) {
+                    (Some(c_zone), Some(content)) => (*c_zone, *content),
+                    (Some(c_zone), None) => {
+                        let token = c_zone.to_string();
+                        return Err(ParseGridError::shape_mismatch(i_line, i_column, &token));
                     }
-
-                    column += 1;
-
-                    if vec_char.len() == 1 {
-                        // Ne contient qu'un caractère pour la zone et pas de chiffre
-                        let c_zone = vec_char[0];
-                        grid.add_cell((line, column), c_zone, None);
-                    } else if vec_char.len() == 2 {
-                        // Contient un caractère pour la zone et un chiffre (1..=9)
-                        let c_zone = vec_char[0];
-                        let option_n = vec_char[1].to_digit(10);
-                        match option_n {
-                            None => return Err(ParseGridError(line, column)),
-                            Some(n) => {
-                                let n = u8::try_from(n).unwrap();
-                                if !(1..=9).contains(&n) {
-                                    return Err(ParseGridError(line, column));
-                                }
-                                grid.add_cell((line, column), c_zone, Some(n));
-                            }
-                        }
-                    } else {
-                        // Définition incorrecte d'une case
-                        return Err(ParseGridError(line, column));
+                    (None, Some(content)) => {
+                        let token = content.map(|n| n.to_string()).unwrap_or_default();
+                        return Err(ParseGridError::shape_mismatch(i_line, i_column, &token));
+                    }
+                    (None, None) => unreachable!(),
+                };
+                if let Some(n) = content {
+                    if !(1..=9).contains(&n) {
+                        let token = format!("{c_zone}{n}");
+                        return Err(ParseGridError::digit_out_of_range(i_line, i_column, &token));
                     }
                 }
+                grid.add_cell((i_line, i_column), c_zone, content);
             }
         }
-
         Ok(grid)
     }
-}
 
-#[cfg(test)]
-mod test {
+    /// Bordure à droite d'une case (voir `from_walls`)
+    pub const WALL_RIGHT: u8 = 1;
 
-    use super::*;
+    /// Bordure en bas d'une case (voir `from_walls`)
+    pub const WALL_BOTTOM: u8 = 2;
 
-    #[test]
-    fn test_grid_add_cell() {
-        // Grille vierge
-        let mut grid = Grid::default();
+    /// Construction d'une grille à partir de la matrice des chiffres et de la matrice des
+    /// bordures entre les zones (une ligne de matrice par ligne de la grille, à partir de la ligne
+    /// et de la colonne 0) :
+    ///
+    /// * `values` contient le chiffre de chaque case s'il est connu
+    /// * `walls` contient les bordures de chaque case (`WALL_RIGHT` et/ou `WALL_BOTTOM`)
+    ///
+    /// Deux cases voisines horizontalement ou verticalement sont dans la même zone s'il n'y a pas
+    /// de bordure entre elles. Les zones sont nommées 'a', 'b', 'c', etc. dans l'ordre de lecture
+    /// de leur première case (comme pour `normalized`).
+    ///
+    /// # Errors
+    /// Une erreur est retournée (ligne, colonne et texte de la case) si les deux matrices n'ont
+    /// pas les mêmes dimensions ou si un chiffre n'est pas entre 1 et 9
+    pub fn from_walls(values: &[&[Option<u8>]], walls: &[&[u8]]) -> Result<Grid, ParseGridError> {
+        // Vérification des dimensions avec une matrice des zones provisoire
+        let vec_line_zones: Vec<Vec<char>> = walls
+            .iter()
+            .map(|line_walls| vec!['?'; line_walls.len()])
+            .collect();
+        let vec_zones: Vec<&[char]> = vec_line_zones.iter().map(Vec::as_slice).collect();
+        let mut grid = Grid::from_matrices(&vec_zones, values)?;
 
-        // Ligne/Colonne/Zone/Number de la case placée
-        let line_column = (1, 2);
-        let c_zone = 'a';
-        let content_number = 1;
+        // Bordure entre deux cases voisines
+        let wall = |line_column: LineColumn, direction: u8| {
+            let line = usize::try_from(line_column.line).unwrap();
+            let column = usize::try_from(line_column.column).unwrap();
+            walls[line][column] & direction != 0
+        };
+        let is_open = |line_column_1: LineColumn, line_column_2: LineColumn| {
+            if line_column_1.line == line_column_2.line {
+                let left = if line_column_1.column < line_column_2.column {
+                    line_column_1
+                } else {
+                    line_column_2
+                };
+                !wall(left, Self::WALL_RIGHT)
+            } else {
+                let top = if line_column_1.line < line_column_2.line {
+                    line_column_1
+                } else {
+                    line_column_2
+                };
+                !wall(top, Self::WALL_BOTTOM)
+            }
+        };
 
-        // Struct pour les positions ligne/colonne
-        let struct_line_column = LineColumn::new(line_column.0, line_column.1);
+        // Remplissage de chaque zone depuis sa première case dans l'ordre de lecture
+        let vec_line_column: Vec<LineColumn> = grid.cells().map(|(lc, _)| *lc).collect();
+        let mut hashmap_zone_of: HashMap<LineColumn, usize> = HashMap::new();
+        let mut count_zones = 0;
+        for first_line_column in vec_line_column {
+            if hashmap_zone_of.contains_key(&first_line_column) {
+                continue;
+            }
+            let index = count_zones;
+            count_zones += 1;
+            hashmap_zone_of.insert(first_line_column, index);
+            let mut stack = vec![first_line_column];
+            while let Some(line_column) = stack.pop() {
+                for delta in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                    let neighbor = line_column + LineColumn::new(delta.0, delta.1);
+                    if grid.get_cell(neighbor).is_some()
+                        && !hashmap_zone_of.contains_key(&neighbor)
+                        && is_open(line_column, neighbor)
+                    {
+                        hashmap_zone_of.insert(neighbor, index);
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
 
-        // Grille vierge ne connaît pas la zone ni la case
-        assert!(grid.get_zone(c_zone).is_none());
-        assert!(grid.get_cell(struct_line_column).is_none());
+        grid.hashmap_zones.clear();
+        let mut vec_zone_of: Vec<(LineColumn, usize)> = hashmap_zone_of.into_iter().collect();
+        vec_zone_of.sort_by_key(|(line_column, _)| (line_column.line, line_column.column));
+        for (line_column, index) in vec_zone_of {
+            let c_zone = grid.zone_id(&Self::canonical_zone_name(index))?;
+            let content = match grid.hashmap_cells[&line_column].content {
+                CellContent::Number(n) => Some(n),
+                CellContent::Undefined | CellContent::PossibleNumbers(_) => None,
+            };
+            grid.add_cell((line_column.line, line_column.column), c_zone, content);
+        }
+        Ok(grid)
+    }
 
-        // Ajoute la case qui contient la valeur dans la zone
-        grid.add_cell(line_column, c_zone, Some(content_number));
+    /// Texte de la grille dans le format lu par `Grid::from_str` : une ligne de texte par ligne
+    /// de la grille et, pour chaque case, le nom de sa zone suivi de son chiffre s'il est connu
+    ///
+    /// Les chiffres possibles d'une case en cours de résolution ne sont pas conservés (seule la
+    /// zone de la case est écrite) et une case absente de la grille est ignorée (les cases
+    /// suivantes de la ligne sont alors décalées). Les chiffres placés pendant la résolution sont
+    /// écrits comme les chiffres connus au départ (voir `cleared`).
+    #[must_use]
+    pub fn to_puzzle_string(&self) -> String {
+        self.to_puzzle_string_with_marker(EmptyCellMarker::None)
+    }
 
-        // Vérifie les dimensions de la grille en cours de construction
-        // grid.min_line_column reste à (0,0)
-        assert_eq!(grid.max_line_column.line, line_column.0);
-        assert_eq!(grid.max_line_column.column, line_column.1);
+    /// Texte de la grille comme `to_puzzle_string` mais avec la marque `marker` après le nom de
+    /// la zone d'une case sans chiffre (par exemple "b0" ou "b_" pour les logiciels qui écrivent
+    /// ainsi les cases vides)
+    ///
+    /// Ce texte est lu par `Grid::from_str_with_options` avec l'option
+    /// `ParseOptions::empty_cell_markers` (ou par `Grid::from_str` sans marque)
+    #[must_use]
+    pub fn to_puzzle_string_with_marker(&self, marker: EmptyCellMarker) -> String {
+        let width = self.max_zone_name_len();
+        let mut res = String::new();
+        for line in self.min_line_column.line..=self.max_line_column.line {
+            let vec_str_cells: Vec<String> = (self.min_line_column.column
+                ..=self.max_line_column.column)
+                .filter_map(|column| self.get_cell(LineColumn::new(line, column)))
+                .map(|cell| {
+                    let zone = self.zone_name(cell.c_zone);
+                    let str_cell = match cell.content {
+                        CellContent::Number(n) => format!("{zone}{n}"),
+                        CellContent::Undefined | CellContent::PossibleNumbers(_) => {
+                            format!("{zone}{}", marker.as_str())
+                        }
+                    };
+                    format!("{str_cell:w$}", w = width + 1)
+                })
+                .collect();
+            // Cases alignées en colonnes (nom de la zone et chiffre ou espace)
+            res.push_str(vec_str_cells.join(" ").trim_end());
+            res.push('\n');
+        }
+        res
+    }
 
-        // Vérifie que la zone est maintenant connue
-        assert!(grid.hashmap_zones.contains_key(&c_zone));
-        assert!(grid.get_zone(c_zone).is_some());
+    /// Grille tournée d'un quart de tour dans le sens des aiguilles d'une montre
+    ///
+    /// Comme pour les autres transformations géométriques, les zones et le contenu des cases sont
+    /// conservés et le coin supérieur gauche de la nouvelle grille reste `min_line_column`
+    #[must_use]
+    pub fn rotated_90(&self) -> Grid {
+        let (min, max) = (self.min_line_column, self.max_line_column);
+        self.map_line_columns(|line_column| {
+            LineColumn::new(
+                min.line + line_column.column - min.column,
+                min.column + max.line - line_column.line,
+            )
+        })
+    }
 
-        // Vérifie que la case placée est maintenant connue
-        assert!(grid.hashmap_cells.contains_key(&struct_line_column));
-        assert!(grid.get_cell(struct_line_column).is_some());
+    /// Grille retournée de gauche à droite (ordre des colonnes inversé)
+    #[must_use]
+    pub fn mirrored_horizontal(&self) -> Grid {
+        let (min, max) = (self.min_line_column, self.max_line_column);
+        self.map_line_columns(|line_column| {
+            LineColumn::new(
+                line_column.line,
+                min.column + max.column - line_column.column,
+            )
+        })
+    }
 
-        // Vérifie que la case est bien référencée dans la zone
-        let zone = grid.get_zone(c_zone).unwrap();
-        assert_eq!(zone.c_zone, c_zone);
-        assert!(zone.set_line_column.contains(&struct_line_column));
+    /// Grille retournée de haut en bas (ordre des lignes inversé)
+    #[must_use]
+    pub fn mirrored_vertical(&self) -> Grid {
+        let (min, max) = (self.min_line_column, self.max_line_column);
+        self.map_line_columns(|line_column| {
+            LineColumn::new(min.line + max.line - line_column.line, line_column.column)
+        })
+    }
 
-        // Vérifie que la case est correctement définie
-        let cell = grid.get_cell(struct_line_column).unwrap();
-        assert_eq!(cell.c_zone, c_zone);
-        assert_eq!(cell.line_column, struct_line_column);
-        assert_eq!(cell.content, CellContent::Number(content_number));
+    /// Grille transposée (les lignes deviennent les colonnes)
+    #[must_use]
+    pub fn transposed(&self) -> Grid {
+        let min = self.min_line_column;
+        self.map_line_columns(|line_column| {
+            LineColumn::new(
+                min.line + line_column.column - min.column,
+                min.column + line_column.line - min.line,
+            )
+        })
     }
 
-    #[test]
-    fn test_parse_grid_ok() {
-        let result_grid = Grid::from_str(
-            "
-        # Exemple de grille ok
-        a1 b  b2
-        b4 b  b
-        c  c  c2
-        ",
-        );
+    /// Grille dans une forme canonique : les cases sont déplacées pour que la première ligne et
+    /// la première colonne occupées soient numérotées 0 et les zones sont renommées 'a', 'b',
+    /// 'c', etc. dans l'ordre de lecture de leur première case (de gauche à droite puis de haut
+    /// en bas)
+    ///
+    /// Deux grilles de même structure et de même contenu ont ainsi la même forme canonique, quels
+    /// que soient les noms de leurs zones ou leur position d'origine. Au-delà de 26 zones, les
+    /// zones sont nommées par plusieurs lettres ('aa', 'ab', etc.)
+    ///
+    /// # Panics
+    /// Cette fonction panics si la grille a trop de zones pour les nommer (voir `zone_id`)
+    #[must_use]
+    pub fn normalized(&self) -> Grid {
+        let min_line = self
+            .hashmap_cells
+            .keys()
+            .map(|lc| lc.line)
+            .min()
+            .unwrap_or(0);
+        let min_column = self
+            .hashmap_cells
+            .keys()
+            .map(|lc| lc.column)
+            .min()
+            .unwrap_or(0);
 
-        assert!(result_grid.is_ok());
+        let mut grid = Grid::default();
+        let mut hashmap_names: HashMap<char, char> = HashMap::new();
+        for (line_column, cell) in self.cells() {
+            let c_zone = match hashmap_names.get(&cell.c_zone) {
+                Some(c_zone) => *c_zone,
+                None => {
+                    let name = Self::canonical_zone_name(hashmap_names.len());
+                    let c_zone = grid.zone_id(&name).expect("Trop de zones dans la grille");
+                    hashmap_names.insert(cell.c_zone, c_zone);
+                    c_zone
+                }
+            };
+            let line_column =
+                LineColumn::new(line_column.line - min_line, line_column.column - min_column);
+            grid.copy_cell(line_column, c_zone, cell);
+        }
+        grid
     }
 
-    #[test]
-    fn test_parse_grid_nok() {
-        let result_grid = Grid::from_str(
-            "
-        # NOK car une case b22 avec syntaxe incorrecte (line=1, column=1)
-        a1 b  b2
-        b4 b22 b
-        c  c  c2
-        ",
-        );
+    /// Empreinte de la grille pour détecter les doublons dans une collection de grilles
+    ///
+    /// L'empreinte est calculée d'après la forme canonique de la grille (voir `normalized`) : deux
+    /// grilles de même structure et avec les mêmes chiffres ont la même empreinte, quels que soient
+    /// les noms de leurs zones ou leur position. Les chiffres possibles d'une case en cours de
+    /// résolution ne sont pas pris en compte.
+    ///
+    /// Cette empreinte est stable (identique d'une exécution ou d'une version du programme à
+    /// l'autre) et peut donc être conservée
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        let grid = self.normalized();
 
-        assert!(result_grid.is_err());
-        if let Err(ParseGridError(line, column)) = result_grid {
-            assert_eq!(line, 1);
-            assert_eq!(column, 1);
-        } else {
-            panic!("ParseGridError non détectée");
+        // Position, zone et chiffre de chaque case (une grille peut avoir des trous)
+        let mut bytes = Vec::new();
+        for (_, cell) in grid.cells() {
+            bytes.extend_from_slice(&cell.line_column.line.to_le_bytes());
+            bytes.extend_from_slice(&cell.line_column.column.to_le_bytes());
+            bytes.extend_from_slice(grid.zone_name(cell.c_zone).as_bytes());
+            bytes.push(match cell.content {
+                CellContent::Number(n) => n,
+                CellContent::Undefined | CellContent::PossibleNumbers(_) => 0,
+            });
         }
+
+        // FNV-1a 64 bits
+        bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        })
     }
 
-    #[test]
-    fn test_parse_grid_nok_2() {
-        let result_grid = Grid::from_str(
-            "
-        # NOK car une case bz avec syntaxe incorrecte (line=1, column=1)
-        a1 b  b2
-        b4 bz b
-        c  c  c2
-        ",
-        );
+    /// Empreinte de la grille (voir `fingerprint`) identique pour toutes les grilles obtenues par
+    /// rotation ou par symétrie de cette grille
+    #[must_use]
+    pub fn fingerprint_up_to_symmetry(&self) -> u64 {
+        let mut fingerprint = u64::MAX;
+        for mut grid in [self.clone(), self.transposed()] {
+            for _ in 0..4 {
+                fingerprint = fingerprint.min(grid.fingerprint());
+                grid = grid.rotated_90();
+            }
+        }
+        fingerprint
+    }
 
-        assert!(result_grid.is_err());
-        if let Err(ParseGridError(line, column)) = result_grid {
-            assert_eq!(line, 1);
-            assert_eq!(column, 1);
-        } else {
-            panic!("ParseGridError non détectée");
+    /// Nom canonique de la zone d'index `index` : 'a' à 'z' puis 'aa', 'ab', etc.
+    pub(crate) fn canonical_zone_name(index: usize) -> String {
+        let mut name = Vec::new();
+        let mut index = index + 1;
+        while index > 0 {
+            index -= 1;
+            name.push(char::from(b'a' + u8::try_from(index % 26).unwrap()));
+            index /= 26;
         }
+        name.iter().rev().collect()
     }
 
-    #[test]
-    fn test_parse_grid_nok_3() {
-        let result_grid = Grid::from_str(
-            "
-        # NOK car la case (line=1, column=1) ne peut pas contenir 0
-        a1 b  b2
-        b4 b0 b
-        c  c  c2
-        ",
-        );
+    /// Déplace toutes les cases de la grille de `dl` lignes et `dc` colonnes
+    ///
+    /// Les zones et les numéros de ligne/colonne min et max sont déplacés de la même manière
+    pub fn translate(&mut self, dl: i32, dc: i32) {
+        let delta = LineColumn::new(dl, dc);
+        self.hashmap_cells = std::mem::take(&mut self.hashmap_cells)
+            .into_values()
+            .map(|mut cell| {
+                cell.line_column = cell.line_column + delta;
+                (cell.line_column, cell)
+            })
+            .collect();
+        for zone in self.hashmap_zones.values_mut() {
+            zone.set_line_column = zone
+                .set_line_column
+                .iter()
+                .map(|line_column| *line_column + delta)
+                .collect();
+        }
+        self.min_line_column = self.min_line_column + delta;
+        self.max_line_column = self.max_line_column + delta;
+    }
 
-        assert!(result_grid.is_err());
-        if let Err(ParseGridError(line, column)) = result_grid {
-            assert_eq!(line, 1);
-            assert_eq!(column, 1);
-        } else {
-            panic!("ParseGridError non détectée");
+    /// Déplace toutes les cases de la grille pour que la première ligne et la première colonne
+    /// occupées soient numérotées 0 (voir `translate`)
+    pub fn normalize_origin(&mut self) {
+        if let Some((min, _)) = self.bounds() {
+            self.translate(-min.line, -min.column);
         }
+        self.update_min_max_line_column();
+    }
+
+    /// Ajoute dans la grille toutes les cases d'une autre grille, déplacées de `offset`
+    ///
+    /// Une zone de l'autre grille dont le nom est déjà utilisé par une zone de la grille est
+    /// renommée avec le premier nom libre parmi 'a', 'b', ..., 'z', 'aa', 'ab', etc. Une case de
+    /// l'autre grille remplace la case de la grille à la même position.
+    ///
+    /// Retourne la correspondance entre les zones de l'autre grille et leur zone dans la grille
+    ///
+    /// # Panics
+    /// Cette fonction panics si les deux grilles ont ensemble trop de zones désignées par
+    /// plusieurs lettres (voir `zone_id`)
+    pub fn overlay(&mut self, other: &Grid, offset: LineColumn) -> HashMap<char, char> {
+        let mut set_names: HashSet<String> = self
+            .hashmap_zones
+            .keys()
+            .map(|c_zone| self.zone_name(*c_zone))
+            .collect();
+
+        let mut hashmap_zones = HashMap::new();
+        let mut index = 0;
+        for (other_c_zone, _) in other.zones() {
+            let mut name = other.zone_name(*other_c_zone);
+            while set_names.contains(&name) {
+                name = Self::canonical_zone_name(index);
+                index += 1;
+            }
+            let c_zone = self.zone_id(&name).expect("Trop de zones dans la grille");
+            set_names.insert(name);
+            hashmap_zones.insert(*other_c_zone, c_zone);
+        }
+
+        for (line_column, cell) in other.cells() {
+            let line_column = *line_column + offset;
+            let c_zone = hashmap_zones[&cell.c_zone];
+            self.copy_cell(line_column, c_zone, cell);
+        }
+
+        hashmap_zones
+    }
+
+    /// Extrait les cases du rectangle de coins `min` (en haut à gauche) et `max` (en bas à
+    /// droite) inclus dans une nouvelle grille (les cases conservent leurs coordonnées)
+    ///
+    /// # Errors
+    /// Une erreur est retournée si une zone de la grille a des cases à la fois dans et hors du
+    /// rectangle (voir `crop_clipped` pour conserver la partie de cette zone dans le rectangle)
+    pub fn crop(&self, min: LineColumn, max: LineColumn) -> Result<Grid, CropGridError> {
+        let is_inside = |line_column: &LineColumn| {
+            (min.line..=max.line).contains(&line_column.line)
+                && (min.column..=max.column).contains(&line_column.column)
+        };
+        for (c_zone, zone) in self.zones() {
+            let count_inside = zone
+                .set_line_column
+                .iter()
+                .filter(|lc| is_inside(lc))
+                .count();
+            if count_inside > 0 && count_inside < zone.size() {
+                return Err(CropGridError(*c_zone, self.zone_name(*c_zone)));
+            }
+        }
+        Ok(self.crop_clipped(min, max))
+    }
+
+    /// Extrait les cases du rectangle de coins `min` (en haut à gauche) et `max` (en bas à
+    /// droite) inclus dans une nouvelle grille (les cases conservent leurs coordonnées)
+    ///
+    /// Une zone coupée par le rectangle est réduite à ses cases dans le rectangle : elle peut
+    /// alors ne plus être d'un seul tenant ou contenir des chiffres trop grands pour sa taille
+    #[must_use]
+    pub fn crop_clipped(&self, min: LineColumn, max: LineColumn) -> Grid {
+        let mut grid = Grid {
+            zone_names: self.zone_names.clone(),
+            ..Grid::default()
+        };
+        for (line_column, cell) in self.cells() {
+            if (min.line..=max.line).contains(&line_column.line)
+                && (min.column..=max.column).contains(&line_column.column)
+            {
+                grid.copy_cell(*line_column, cell.c_zone, cell);
+            }
+        }
+        grid
+    }
+
+    /// Ajoute une case avec le contenu d'une case d'une autre grille (chiffre connu au départ ou
+    /// non)
+    fn copy_cell(&mut self, line_column: LineColumn, c_zone: char, cell: &Cell) {
+        self.add_cell((line_column.line, line_column.column), c_zone, None);
+        let new_cell = self.get_mut_cell(line_column).unwrap();
+        new_cell.content = cell.content.clone();
+        new_cell.is_given = cell.is_given;
+    }
+
+    /// Nouvelle grille avec les mêmes cases (zone et contenu) déplacées selon `f`
+    fn map_line_columns(&self, f: impl Fn(LineColumn) -> LineColumn) -> Grid {
+        let mut grid = Grid {
+            zone_names: self.zone_names.clone(),
+            ..Grid::default()
+        };
+        for cell in self.hashmap_cells.values() {
+            let line_column = f(cell.line_column);
+            grid.copy_cell(line_column, cell.c_zone, cell);
+        }
+        grid
+    }
+
+    /// Zones voisines de chaque zone de la grille (qui ont au moins une case voisine par un côté
+    /// ou par un coin d'une case de la zone)
+    ///
+    /// Toutes les zones de la grille sont présentes, éventuellement sans voisine
+    #[must_use]
+    pub fn zone_adjacency(&self) -> BTreeMap<char, BTreeSet<char>> {
+        let mut adjacency: BTreeMap<char, BTreeSet<char>> = self
+            .hashmap_zones
+            .keys()
+            .map(|c_zone| (*c_zone, BTreeSet::new()))
+            .collect();
+        for cell in self.hashmap_cells.values() {
+            for neighbor in NeighboringLineColumns::new(
+                cell.line_column,
+                self.min_line_column,
+                self.max_line_column,
+            ) {
+                if let Some(neighbor_cell) = self.get_cell(neighbor) {
+                    if neighbor_cell.c_zone != cell.c_zone {
+                        adjacency
+                            .entry(cell.c_zone)
+                            .or_default()
+                            .insert(neighbor_cell.c_zone);
+                    }
+                }
+            }
+        }
+        adjacency
+    }
+
+    /// Retourne true si toutes les cases d'une zone sont reliées entre elles par des cases
+    /// voisines de la zone (horizontalement ou verticalement)
+    /// Une zone inconnue est considérée d'un seul tenant
+    #[must_use]
+    pub fn is_zone_contiguous(&self, c_zone: char) -> bool {
+        let Some(zone) = self.hashmap_zones.get(&c_zone) else {
+            return true;
+        };
+        let Some(first_line_column) = zone.set_line_column.iter().next() else {
+            return true;
+        };
+
+        // Remplissage depuis une case de la zone
+        let mut visited = HashSet::from([*first_line_column]);
+        let mut stack = vec![*first_line_column];
+        while let Some(line_column) = stack.pop() {
+            for delta in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let neighbor = line_column + LineColumn::new(delta.0, delta.1);
+                if zone.set_line_column.contains(&neighbor) && visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        visited.len() == zone.set_line_column.len()
+    }
+
+    /// Caractère qui représente une zone de la grille d'après son nom
+    ///
+    /// Une zone est habituellement désignée par une seule lettre ('a', 'b', etc.) qui est aussi
+    /// le caractère qui la représente. Pour les grandes grilles, une zone peut être désignée par
+    /// plusieurs lettres ('aa', 'ab', etc.) : un caractère Unicode à usage privé lui est alors
+    /// attribué (voir `zone_name` pour retrouver son nom)
+    ///
+    /// # Errors
+    /// `ParseGridError::TooManyZones` est retourné si tous les caractères à usage privé sont
+    /// déjà attribués à d'autres zones de la grille désignées par plusieurs lettres
+    ///
+    /// # Panics
+    /// Cette fonction panics si le nom de la zone est vide
+    pub fn zone_id(&mut self, name: &str) -> Result<char, ParseGridError> {
+        let mut chars = name.chars();
+        let c_first = chars.next().expect("Nom de zone vide");
+        if chars.next().is_none() {
+            return Ok(c_first);
+        }
+
+        if let Some((c_zone, _)) = self
+            .zone_names
+            .iter()
+            .find(|(_, zone_name)| *zone_name == name)
+        {
+            return Ok(*c_zone);
+        }
+        let c_zone = multi_char_zone_id(self.zone_names.len())
+            .ok_or_else(|| ParseGridError::TooManyZones(name.to_string()))?;
+        self.zone_names.insert(c_zone, name.to_string());
+        Ok(c_zone)
+    }
+
+    /// Indique si `name` peut être le nom d'une zone dans le texte d'une grille : une ou
+    /// plusieurs lettres, minuscules ou majuscules, accentuées ou non et de n'importe quel
+    /// alphabet ('a', 'B', 'é', 'Ω', 'ab', etc.)
+    ///
+    /// Les majuscules et les minuscules désignent des zones différentes ('a' et 'A' par exemple).
+    /// Une lettre accentuée doit être écrite avec un seul caractère Unicode ('é' et non pas 'e'
+    /// suivi d'un accent).
+    #[must_use]
+    pub fn is_valid_zone_name(name: &str) -> bool {
+        !name.is_empty() && name.chars().all(char::is_alphabetic)
+    }
+
+    /// Nom d'une zone de la grille d'après le caractère qui la représente (voir `zone_id`)
+    #[must_use]
+    pub fn zone_name(&self, c_zone: char) -> String {
+        match self.zone_names.get(&c_zone) {
+            Some(name) => name.clone(),
+            None => c_zone.to_string(),
+        }
+    }
+
+    /// Longueur du plus long nom de zone de la grille
+    fn max_zone_name_len(&self) -> usize {
+        self.zone_names
+            .values()
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// Retourne true si toutes les cases de la grille contiennent un chiffre
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.hashmap_cells
+            .values()
+            .all(|cell| cell.content.is_number())
+    }
+
+    /// Proportion (de 0.0 à 1.0) des cases de la grille qui contiennent un chiffre
+    /// Une grille sans case est considérée complète
+    #[must_use]
+    pub fn completion(&self) -> f64 {
+        if self.hashmap_cells.is_empty() {
+            return 1.0;
+        }
+        let count_numbers = self
+            .hashmap_cells
+            .values()
+            .filter(|cell| cell.content.is_number())
+            .count();
+        f64::from(u32::try_from(count_numbers).unwrap_or(u32::MAX))
+            / f64::from(u32::try_from(self.hashmap_cells.len()).unwrap_or(u32::MAX))
+    }
+
+    /// Coins supérieur gauche et inférieur droit du plus petit rectangle qui contient toutes les
+    /// cases de la grille (None si la grille n'a aucune case)
+    ///
+    /// Contrairement à `min_line_column` (initialisé à (0, 0)), le coin supérieur gauche est celui
+    /// de la première ligne et de la première colonne réellement occupées
+    #[must_use]
+    pub fn bounds(&self) -> Option<(LineColumn, LineColumn)> {
+        let mut line_columns = self.hashmap_cells.keys();
+        let first_line_column = *line_columns.next()?;
+        let (mut min, mut max) = (first_line_column, first_line_column);
+        for line_column in line_columns {
+            min.min(*line_column);
+            max.max(*line_column);
+        }
+        Some((min, max))
+    }
+
+    /// Nombre de colonnes de la grille (colonnes sans case comprises, 0 pour une grille vide)
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.bounds().map_or(0, |(min, max)| {
+            usize::try_from(max.column - min.column + 1).unwrap()
+        })
+    }
+
+    /// Nombre de lignes de la grille (lignes sans case comprises, 0 pour une grille vide)
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.bounds().map_or(0, |(min, max)| {
+            usize::try_from(max.line - min.line + 1).unwrap()
+        })
+    }
+
+    /// Nombre de cases de la grille (inférieur à `width() * height()` pour une grille irrégulière)
+    #[must_use]
+    pub fn cell_count(&self) -> usize {
+        self.hashmap_cells.len()
+    }
+
+    /// Itérateur sur les cases de la grille, triées par ligne puis par colonne
+    pub fn cells(&self) -> impl Iterator<Item = (&LineColumn, &Cell)> {
+        let mut vec_cell: Vec<(&LineColumn, &Cell)> = self.hashmap_cells.iter().collect();
+        vec_cell.sort_by_key(|(line_column, _)| (line_column.line, line_column.column));
+        vec_cell.into_iter()
+    }
+
+    /// Itérateur sur les zones de la grille, triées selon le caractère qui les représente
+    pub fn zones(&self) -> impl Iterator<Item = (&char, &Zone)> {
+        let mut vec_zone: Vec<(&char, &Zone)> = self.hashmap_zones.iter().collect();
+        vec_zone.sort_by_key(|(c_zone, _)| **c_zone);
+        vec_zone.into_iter()
+    }
+
+    /// Accesseur (privé) à une zone de la grille (créée si elle n'existe pas)
+    #[must_use]
+    fn get_or_create_zone(&mut self, c_zone: char) -> &mut Zone {
+        self.hashmap_zones.entry(c_zone).or_default()
+    }
+
+    /// Accesseur (public) à une zone de la grille (None) si elle n'existe pas
+    #[must_use]
+    pub fn get_zone(&mut self, c_zone: char) -> Option<&mut Zone> {
+        self.hashmap_zones.get_mut(&c_zone)
+    }
+
+    /// Accesseur (privé) à une case de la grille (créée si elle n'existe pas)
+    #[must_use]
+    fn get_or_create_cell(&mut self, line_column: LineColumn) -> &mut Cell {
+        self.hashmap_cells.entry(line_column).or_default()
+    }
+
+    /// Accesseur (public) à une case non mutable de la grille (None) si elle n'existe pas
+    #[must_use]
+    pub fn get_cell(&self, line_column: LineColumn) -> Option<&Cell> {
+        self.hashmap_cells.get(&line_column)
+    }
+
+    /// Accesseur (public) à une case mutable de la grille (None) si elle n'existe pas
+    #[must_use]
+    pub fn get_mut_cell(&mut self, line_column: LineColumn) -> Option<&mut Cell> {
+        self.hashmap_cells.get_mut(&line_column)
+    }
+}
+
+/// Représentation sérialisée d'une grille : liste de ses cases triées par ligne puis colonne
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GridCells {
+    cells: Vec<Cell>,
+
+    // Noms des zones désignées par plusieurs lettres
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    zone_names: HashMap<char, String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Grid> for GridCells {
+    fn from(grid: Grid) -> Self {
+        let mut cells: Vec<Cell> = grid.hashmap_cells.into_values().collect();
+        cells.sort_by_key(|cell| (cell.line_column.line, cell.line_column.column));
+        GridCells {
+            cells,
+            zone_names: grid.zone_names,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<GridCells> for Grid {
+    fn from(grid_cells: GridCells) -> Self {
+        let mut grid = Grid {
+            zone_names: grid_cells.zone_names,
+            ..Grid::default()
+        };
+        for cell in grid_cells.cells {
+            let line_column = cell.line_column;
+            grid.copy_cell(line_column, cell.c_zone, &cell);
+        }
+        grid
+    }
+}
+
+/// Erreur rencontrée lors du parsing d'une grille (avec `FromStr` ou l'un des autres formats
+/// de texte d'une grille)
+///
+/// Les lignes et les colonnes sont numérotées à partir de 0 comme les cases de la grille. Avec
+/// `FromStr`, l'erreur sur une case indique aussi la ligne du texte d'origine (`SourceLine`),
+/// numérotée comme dans un éditeur de texte.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// Le texte d'une case n'a pas le format attendu (nom de zone qui n'est pas en lettres,
+    /// caractère inconnu, etc.)
+    InvalidCellToken {
+        token: String,
+        line: i32,
+        column: i32,
+        source: Option<SourceLine>,
+    },
+
+    /// Le chiffre d'une case n'est pas entre 1 et 9
+    DigitOutOfRange {
+        token: String,
+        line: i32,
+        column: i32,
+        source: Option<SourceLine>,
+    },
+
+    /// Une case d'un format en deux parties (zones et chiffres) n'a pas de correspondance dans
+    /// l'autre partie, ou une ligne de cases est incomplète
+    ShapeMismatch {
+        token: String,
+        line: i32,
+        column: i32,
+        source: Option<SourceLine>,
+    },
+
+    /// Une ligne de cases n'a pas la longueur (`width`) de la première ligne (`expected`) alors
+    /// que les options de lecture l'interdisent (voir `RaggedRows`)
+    RaggedRow {
+        line: i32,
+        width: usize,
+        expected: usize,
+        source: Option<SourceLine>,
+    },
+
+    /// Une partie obligatoire du texte (une section par exemple) est absente
+    MissingSection(String),
+
+    /// Une ligne du texte n'est pas à sa place ou n'a pas le format attendu
+    InvalidLine(String),
+
+    /// Les dimensions de la grille sont incorrectes ou incompatibles avec le nombre de cases
+    InvalidDimensions(String),
+
+    /// Le texte ne contient aucune case
+    EmptyGrid,
+
+    /// La grille a trop de zones désignées par plusieurs lettres pour ajouter la zone de ce nom
+    /// (voir `Grid::zone_id`)
+    TooManyZones(String),
+}
+
+/// Ligne du texte d'origine qui contient une case incorrecte
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLine {
+    /// Numéro de la ligne dans le texte, lignes vides et commentaires compris (la première
+    /// ligne du texte est la ligne 1)
+    pub number: usize,
+
+    /// Texte complet de la ligne
+    pub text: String,
+
+    /// Position dans la ligne (en caractères à partir de 0) du texte de la case incorrecte
+    pub offset: usize,
+}
+
+impl ParseGridError {
+    /// Erreur sur le texte d'une case (ligne, colonne et texte de la case incorrecte)
+    pub(crate) fn invalid_token(line: i32, column: i32, token: &str) -> Self {
+        ParseGridError::InvalidCellToken {
+            token: token.to_string(),
+            line,
+            column,
+            source: None,
+        }
+    }
+
+    /// Erreur sur le chiffre d'une case (ligne, colonne et texte de la case incorrecte)
+    pub(crate) fn digit_out_of_range(line: i32, column: i32, token: &str) -> Self {
+        ParseGridError::DigitOutOfRange {
+            token: token.to_string(),
+            line,
+            column,
+            source: None,
+        }
+    }
+
+    /// Erreur sur une case sans correspondance (ligne, colonne et texte de la case)
+    pub(crate) fn shape_mismatch(line: i32, column: i32, token: &str) -> Self {
+        ParseGridError::ShapeMismatch {
+            token: token.to_string(),
+            line,
+            column,
+            source: None,
+        }
+    }
+
+    /// Chiffre (1..=9) du texte `token` d'une case en ligne `line` et colonne `column`
+    pub(crate) fn parse_digit(line: i32, column: i32, token: &str) -> Result<u8, Self> {
+        match token.parse::<u8>() {
+            Ok(n) if (1..=9).contains(&n) => Ok(n),
+            Ok(_) => Err(Self::digit_out_of_range(line, column, token)),
+            Err(_) => Err(Self::invalid_token(line, column, token)),
+        }
+    }
+
+    /// Ajoute à l'erreur sur une case la ligne `text` (de numéro `number`) du texte d'origine,
+    /// au format de `Grid::from_str`, qui contient cette case
+    pub(crate) fn with_source_line(mut self, number: usize, text: &str) -> Self {
+        let column = usize::try_from(self.column()).unwrap_or_default();
+        if let ParseGridError::InvalidCellToken { source, .. }
+        | ParseGridError::DigitOutOfRange { source, .. }
+        | ParseGridError::ShapeMismatch { source, .. }
+        | ParseGridError::RaggedRow { source, .. } = &mut self
+        {
+            // Position de la case : deux caractères par case au format compact ou parmi les cases
+            // séparées par des espaces (après la dernière case pour une case manquante)
+            let trimmed = text.trim_start();
+            let cells = text.split('#').next().unwrap_or_default().trim_end();
+            let offset = if Grid::is_compact_line(cells_text(text)) {
+                text.chars().count() - trimmed.chars().count() + 2 * column
+            } else {
+                cells
+                    .char_indices()
+                    .zip(std::iter::once(' ').chain(cells.chars()))
+                    .filter(|((_, c), previous)| !c.is_whitespace() && previous.is_whitespace())
+                    .nth(column)
+                    .map_or(cells.chars().count() + 1, |((index, _), _)| {
+                        text[..index].chars().count()
+                    })
+            };
+            *source = Some(SourceLine {
+                number,
+                text: text.to_string(),
+                offset,
+            });
+        }
+        self
+    }
+
+    /// Décale de `count` lignes le numéro de la ligne du texte d'origine (lorsque le texte
+    /// analysé n'est qu'une partie du texte d'origine)
+    pub(crate) fn offset_source_line(mut self, count: usize) -> Self {
+        if let ParseGridError::InvalidCellToken {
+            source: Some(source),
+            ..
+        }
+        | ParseGridError::DigitOutOfRange {
+            source: Some(source),
+            ..
+        }
+        | ParseGridError::ShapeMismatch {
+            source: Some(source),
+            ..
+        }
+        | ParseGridError::RaggedRow {
+            source: Some(source),
+            ..
+        } = &mut self
+        {
+            source.number += count;
+        }
+        self
+    }
+
+    /// Ligne de la case incorrecte (0 si l'erreur ne concerne pas une case)
+    #[must_use]
+    pub fn line(&self) -> i32 {
+        match self {
+            ParseGridError::InvalidCellToken { line, .. }
+            | ParseGridError::DigitOutOfRange { line, .. }
+            | ParseGridError::ShapeMismatch { line, .. }
+            | ParseGridError::RaggedRow { line, .. } => *line,
+            _ => 0,
+        }
+    }
+
+    /// Colonne de la case incorrecte ou de la première case manquante (ou en trop) d'une ligne de
+    /// longueur différente (0 si l'erreur ne concerne pas une case)
+    #[must_use]
+    pub fn column(&self) -> i32 {
+        match self {
+            ParseGridError::InvalidCellToken { column, .. }
+            | ParseGridError::DigitOutOfRange { column, .. }
+            | ParseGridError::ShapeMismatch { column, .. } => *column,
+            ParseGridError::RaggedRow {
+                width, expected, ..
+            } => i32::try_from(*width.min(expected)).unwrap(),
+            _ => 0,
+        }
+    }
+
+    /// Texte incorrect : texte de la case, nom de la partie absente, texte de la ligne ou des
+    /// dimensions (vide pour une grille sans case ou une ligne de longueur différente)
+    #[must_use]
+    pub fn token(&self) -> &str {
+        match self {
+            ParseGridError::InvalidCellToken { token, .. }
+            | ParseGridError::DigitOutOfRange { token, .. }
+            | ParseGridError::ShapeMismatch { token, .. }
+            | ParseGridError::MissingSection(token)
+            | ParseGridError::InvalidLine(token)
+            | ParseGridError::InvalidDimensions(token)
+            | ParseGridError::TooManyZones(token) => token,
+            ParseGridError::RaggedRow { .. } | ParseGridError::EmptyGrid => "",
+        }
+    }
+
+    /// Ligne du texte d'origine qui contient la case incorrecte (si elle est connue)
+    #[must_use]
+    pub fn source_line(&self) -> Option<&SourceLine> {
+        match self {
+            ParseGridError::InvalidCellToken { source, .. }
+            | ParseGridError::DigitOutOfRange { source, .. }
+            | ParseGridError::ShapeMismatch { source, .. }
+            | ParseGridError::RaggedRow { source, .. } => source.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Position de la case incorrecte, numérotée à partir de 1 : ligne et colonne dans le texte
+        // d'origine si elles sont connues, sinon ligne et rang de la case parmi les cases
+        let position = match self.source_line() {
+            Some(source) => format!("Ligne {}, colonne {}", source.number, source.offset + 1),
+            None => format!("Ligne {}, case {}", self.line() + 1, self.column() + 1),
+        };
+        match self {
+            ParseGridError::InvalidCellToken { token, .. } => write!(
+                f,
+                "{position} : case '{token}' incorrecte (une case est le nom de sa zone en \
+                 lettres, suivi éventuellement de son chiffre)"
+            )?,
+            ParseGridError::DigitOutOfRange { token, .. } => write!(
+                f,
+                "{position} : le chiffre de la case '{token}' doit être entre 1 et 9"
+            )?,
+            ParseGridError::ShapeMismatch { token, .. } => write!(
+                f,
+                "{position} : la case '{token}' n'a pas de correspondance (chaque ligne doit \
+                 avoir le même nombre de cases dans chaque partie de la grille)"
+            )?,
+            ParseGridError::RaggedRow {
+                width, expected, ..
+            } => write!(
+                f,
+                "{position} : la ligne a {width} case(s) au lieu de {expected} (comme la première \
+                 ligne de la grille)"
+            )?,
+            ParseGridError::MissingSection(name) => {
+                write!(f, "La partie '{name}' est absente du texte de la grille")?;
+            }
+            ParseGridError::InvalidLine(text) => write!(f, "Ligne '{text}' inattendue")?,
+            ParseGridError::InvalidDimensions(text) => write!(
+                f,
+                "Les dimensions '{text}' sont incorrectes ou ne correspondent pas au nombre de \
+                 cases"
+            )?,
+            ParseGridError::EmptyGrid => write!(f, "La grille ne contient aucune case")?,
+            ParseGridError::TooManyZones(name) => write!(
+                f,
+                "La zone '{name}' ne peut pas être ajoutée : la grille a trop de zones désignées \
+                 par plusieurs lettres"
+            )?,
+        }
+
+        // Ligne du texte d'origine avec la case incorrecte soulignée (les tabulations de la ligne
+        // sont reprises avant le soulignement pour qu'il reste aligné sur la case)
+        if let Some(source) = self.source_line() {
+            let width = self.token().chars().count().max(1);
+            let padding: String = source
+                .text
+                .chars()
+                .take(source.offset)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            write!(
+                f,
+                "\n{}\n{padding}{}",
+                source.text.trim_end(),
+                "^".repeat(width)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// Erreur rencontrée lors de l'extraction d'une partie de grille avec `Grid::crop`
+/// (zone coupée par le rectangle extrait)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CropGridError(char, String);
+
+impl CropGridError {
+    /// Zone coupée par le rectangle extrait
+    #[must_use]
+    pub fn zone(&self) -> char {
+        self.0
+    }
+
+    /// Nom de la zone coupée par le rectangle extrait (voir `Grid::zone_name`)
+    #[must_use]
+    pub fn zone_name(&self) -> &str {
+        &self.1
+    }
+}
+
+impl fmt::Display for CropGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "La zone '{}' est coupée par l'extraction", self.1)
+    }
+}
+
+impl Error for CropGridError {}
+
+/// Caractère à usage privé attribué à la zone désignée par plusieurs lettres d'index `index`
+/// dans une grille (None si tous les caractères sont attribués)
+fn multi_char_zone_id(index: usize) -> Option<char> {
+    let mut index = u32::try_from(index).ok()?;
+    for (first, last) in MULTI_CHAR_ZONE_IDS {
+        if index <= last - first {
+            return char::from_u32(first + index);
+        }
+        index -= last - first + 1;
+    }
+    None
+}
+
+/// Texte d'une grille sans l'éventuel BOM UTF-8 du début et avec des fins de ligne '\n' (au
+/// lieu des fins de ligne "\r\n" de Windows ou '\r' des anciens Mac)
+pub(crate) fn normalized_text(s: &str) -> String {
+    let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+    s.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Texte des cases d'une ligne au format de `Grid::from_str` : sans les espaces autour des cases
+/// ni le commentaire en fin de ligne (après un '#')
+pub(crate) fn cells_text(str_line: &str) -> &str {
+    match str_line.split_once('#') {
+        Some((cells, _)) => cells.trim(),
+        None => str_line.trim(),
+    }
+}
+
+/// Lecture ligne par ligne d'une grille au format de `Grid::from_str` (pour lire une grille
+/// sans disposer de tout son texte)
+pub(crate) struct GridParser<'a> {
+    grid: Grid,
+    options: &'a ParseOptions,
+
+    // Numéro de la dernière ligne de cases lue (-1 initialement)
+    line: i32,
+
+    // Longueur de la première ligne de cases
+    option_expected: Option<usize>,
+}
+
+impl<'a> GridParser<'a> {
+    pub(crate) fn new(options: &'a ParseOptions) -> Self {
+        GridParser {
+            grid: Grid::default(),
+            options,
+            line: -1,
+            option_expected: None,
+        }
+    }
+
+    /// Lecture de la ligne `source_line` (de numéro `number` dans le texte d'origine, sans fin de
+    /// ligne) : la ligne est ignorée si elle ne contient aucune case
+    pub(crate) fn parse_source_line(
+        &mut self,
+        number: usize,
+        source_line: &str,
+    ) -> Result<(), ParseGridError> {
+        let str_line = cells_text(source_line);
+        if str_line.is_empty() {
+            return Ok(());
+        }
+        self.line += 1;
+        self.grid
+            .parse_line(self.line, str_line, self.options)
+            .map_err(|e| e.with_source_line(number, source_line))?;
+        self.check_width(number, source_line)
+    }
+
+    /// Lecture de la ligne `source_line` comme `parse_source_line` mais en continuant après une
+    /// case incorrecte : les cases correctes sont ajoutées à la grille et les erreurs à
+    /// `vec_errors`
+    pub(crate) fn parse_source_line_lenient(
+        &mut self,
+        number: usize,
+        source_line: &str,
+        vec_errors: &mut Vec<ParseGridError>,
+    ) {
+        let str_line = cells_text(source_line);
+        if str_line.is_empty() {
+            return;
+        }
+        self.line += 1;
+        let line = self.line;
+        for (column, str_cell) in Grid::split_cells(str_line) {
+            let result = Grid::parse_cell(line, column, str_cell, self.options)
+                .and_then(|(name, content)| Ok((self.grid.zone_id(&name)?, content)));
+            match result {
+                Ok((c_zone, content)) => self.grid.add_cell((line, column), c_zone, content),
+                Err(e) => vec_errors.push(e.with_source_line(number, source_line)),
+            }
+        }
+        if let Err(e) = self.check_width(number, source_line) {
+            vec_errors.push(e);
+        }
+    }
+
+    /// Vérification de la longueur de la dernière ligne de cases lue (`source_line`, de numéro
+    /// `number` dans le texte d'origine) selon les options de lecture
+    fn check_width(&mut self, number: usize, source_line: &str) -> Result<(), ParseGridError> {
+        let width = Grid::split_cells(cells_text(source_line)).len();
+        let expected = *self.option_expected.get_or_insert(width);
+        let is_allowed = match self.options.ragged_rows {
+            RaggedRows::Irregular => true,
+            RaggedRows::Reject => width == expected,
+        };
+        if !is_allowed {
+            let e = ParseGridError::RaggedRow {
+                line: self.line,
+                width,
+                expected,
+                source: None,
+            };
+            return Err(e.with_source_line(number, source_line));
+        }
+        Ok(())
+    }
+
+    /// Grille lue (erreur si aucune case n'a été lue)
+    pub(crate) fn finish(self) -> Result<Grid, ParseGridError> {
+        if self.grid.hashmap_cells.is_empty() {
+            return Err(ParseGridError::EmptyGrid);
+        }
+        Ok(self.grid)
+    }
+
+    /// Grille lue par `parse_source_line_lenient` et liste des erreurs `vec_errors`, complétée
+    /// par une erreur si le texte ne contient aucune case
+    pub(crate) fn finish_lenient(
+        self,
+        mut vec_errors: Vec<ParseGridError>,
+    ) -> (Grid, Vec<ParseGridError>) {
+        if self.grid.hashmap_cells.is_empty() && vec_errors.is_empty() {
+            vec_errors.push(ParseGridError::EmptyGrid);
+        }
+        (self.grid, vec_errors)
+    }
+}
+
+impl FromStr for Grid {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_options(s, &ParseOptions::default())
+    }
+}
+
+impl Grid {
+    /// Construction d'une grille au format de `Grid::from_str` avec des options de lecture (voir
+    /// `ParseOptions`)
+    ///
+    /// # Errors
+    /// Une erreur est retournée si une case est incorrecte, si la longueur d'une ligne n'est pas
+    /// permise par les options (`ParseGridError::RaggedRow`) ou si le texte ne contient aucune case
+    pub fn from_str_with_options(s: &str, options: &ParseOptions) -> Result<Grid, ParseGridError> {
+        let mut parser = GridParser::new(options);
+        for (index, source_line) in normalized_text(s).lines().enumerate() {
+            parser.parse_source_line(index + 1, source_line)?;
+        }
+        parser.finish()
+    }
+
+    /// Ajoute une ligne (à partir de la colonne 0) dans la grille tectonic avec le texte d'une
+    /// ligne au format de `Grid::from_str` (par exemple "a1 b  b2")
+    ///
+    /// * `line` le numéro de ligne (la 1ere ligne du haut est la ligne 0)
+    /// * `str_line` le texte des cases de la ligne
+    ///
+    /// # Errors
+    /// Une erreur est retournée (ligne, colonne et texte de la case incorrecte) si une case n'a
+    /// pas le bon format : aucune case de la ligne n'est alors ajoutée
+    pub fn add_line_str(&mut self, line: i32, str_line: &str) -> Result<(), ParseGridError> {
+        self.parse_line(line, cells_text(str_line), &ParseOptions::default())
+    }
+
+    /// Construction d'une grille au format de `Grid::from_str` qui continue après une case
+    /// incorrecte (un éditeur peut ainsi signaler toutes les erreurs du texte en une seule fois)
+    ///
+    /// Retourne la grille des cases correctes et la liste de toutes les erreurs rencontrées
+    /// (vide si le texte est correct). Une case incorrecte est absente de la grille mais compte
+    /// dans la numérotation des colonnes de sa ligne.
+    #[must_use]
+    pub fn from_str_lenient(s: &str) -> (Grid, Vec<ParseGridError>) {
+        Self::from_str_lenient_with_options(s, &ParseOptions::default())
+    }
+
+    /// Construction d'une grille comme `Grid::from_str_lenient` avec des options de lecture (voir
+    /// `ParseOptions`) : une ligne de longueur interdite par les options est une erreur de la
+    /// liste mais ses cases correctes sont dans la grille
+    #[must_use]
+    pub fn from_str_lenient_with_options(
+        s: &str,
+        options: &ParseOptions,
+    ) -> (Grid, Vec<ParseGridError>) {
+        let mut parser = GridParser::new(options);
+        let mut vec_errors = Vec::new();
+        for (index, source_line) in normalized_text(s).lines().enumerate() {
+            parser.parse_source_line_lenient(index + 1, source_line, &mut vec_errors);
+        }
+        parser.finish_lenient(vec_errors)
+    }
+
+    /// Ajoute les cases d'une ligne de texte au format de `Grid::from_str` (à partir de la
+    /// colonne 0)
+    /// Aucune case n'est ajoutée si une case de la ligne est incorrecte
+    pub(crate) fn parse_line(
+        &mut self,
+        line: i32,
+        str_line: &str,
+        options: &ParseOptions,
+    ) -> Result<(), ParseGridError> {
+        // Cases de la ligne (colonne, zone, contenu)
+        let mut vec_cells: Vec<(i32, char, Option<u8>)> = Vec::new();
+        for (column, str_cell) in Self::split_cells(str_line) {
+            let (name, content) = Self::parse_cell(line, column, str_cell, options)?;
+            vec_cells.push((column, self.zone_id(&name)?, content));
+        }
+
+        for (column, c_zone, content) in vec_cells {
+            self.add_cell((line, column), c_zone, content);
+        }
+
+        Ok(())
+    }
+
+    /// Texte de chaque case d'une ligne au format de `Grid::from_str` avec sa colonne
+    ///
+    /// Une ligne sans espace où chaque case est une lettre suivie d'un chiffre, d'un point ou d'un
+    /// '_' (par exemple "a1b.b2") est au format compact : le texte de chaque case est alors la lettre de
+    /// la zone suivie du chiffre (ou seulement la lettre si le chiffre n'est pas connu)
+    fn split_cells(str_line: &str) -> Vec<(i32, &str)> {
+        let vec_str_cells: Vec<&str> = if Self::is_compact_line(str_line) {
+            let mut vec_str_cells = Vec::new();
+            let mut rest = str_line;
+            // Le 2e caractère de chaque case est un chiffre ou un point (un seul octet)
+            while let Some((index_digit, _)) = rest.char_indices().nth(1) {
+                let (str_cell, next) = rest.split_at(index_digit + 1);
+                vec_str_cells.push(str_cell.strip_suffix('.').unwrap_or(str_cell));
+                rest = next;
+            }
+            vec_str_cells
+        } else {
+            // Cases séparées par des espaces ou des tabulations
+            str_line.split_whitespace().collect()
+        };
+        vec_str_cells
+            .into_iter()
+            .enumerate()
+            .map(|(column, str_cell)| (i32::try_from(column).unwrap(), str_cell))
+            .collect()
+    }
+
+    /// Indique si une ligne est au format compact (voir `split_cells`)
+    fn is_compact_line(str_line: &str) -> bool {
+        let vec_char: Vec<char> = str_line.chars().collect();
+        !vec_char.is_empty()
+            && vec_char.len().is_multiple_of(2)
+            && vec_char.chunks(2).all(|pair| {
+                pair[0].is_alphabetic()
+                    && (pair[1].is_ascii_digit() || pair[1] == '.' || pair[1] == '_')
+            })
+    }
+
+    /// Nom de la zone et chiffre (s'il est donné) du texte d'une case au format de
+    /// `Grid::from_str`
+    fn parse_cell(
+        line: i32,
+        column: i32,
+        str_cell: &str,
+        options: &ParseOptions,
+    ) -> Result<(String, Option<u8>), ParseGridError> {
+        // Case vide marquée par un '0' ou un '_' après le nom de sa zone
+        if options.empty_cell_markers {
+            if let Some(name) = str_cell.strip_suffix(EmptyCellMarker::MARKERS) {
+                if Self::is_valid_zone_name(name) {
+                    return Ok((name.to_string(), None));
+                }
+            }
+        }
+
+        let vec_char: Vec<char> = str_cell.chars().collect();
+
+        // Nom de la zone (une seule lettre ou plusieurs lettres) suivi éventuellement d'un
+        // chiffre (1..=9)
+        let (vec_char_zone, option_n) = match vec_char.split_last() {
+            Some((c_last, vec_char_zone)) if !vec_char_zone.is_empty() => {
+                match c_last.to_digit(10) {
+                    Some(n) => (vec_char_zone, Some(n)),
+                    None => (&vec_char[..], None),
+                }
+            }
+            _ => (&vec_char[..], None),
+        };
+        let name: String = vec_char_zone.iter().collect();
+        if !Self::is_valid_zone_name(&name) {
+            // Définition incorrecte d'une case
+            return Err(ParseGridError::invalid_token(line, column, str_cell));
+        }
+        let content = match option_n {
+            None => None,
+            Some(n) => {
+                let n = u8::try_from(n).unwrap();
+                if !(1..=9).contains(&n) {
+                    return Err(ParseGridError::digit_out_of_range(line, column, str_cell));
+                }
+                Some(n)
+            }
+        };
+        Ok((name, content))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::solver::Solver;
+    use crate::solver_config::SolverConfig;
+
+    #[test]
+    fn test_grid_add_cell() {
+        // Grille vierge
+        let mut grid = Grid::default();
+
+        // Ligne/Colonne/Zone/Number de la case placée
+        let line_column = (1, 2);
+        let c_zone = 'a';
+        let content_number = 1;
+
+        // Struct pour les positions ligne/colonne
+        let struct_line_column = LineColumn::new(line_column.0, line_column.1);
+
+        // Grille vierge ne connaît pas la zone ni la case
+        assert!(grid.get_zone(c_zone).is_none());
+        assert!(grid.get_cell(struct_line_column).is_none());
+
+        // Ajoute la case qui contient la valeur dans la zone
+        grid.add_cell(line_column, c_zone, Some(content_number));
+
+        // Vérifie les dimensions de la grille en cours de construction
+        // grid.min_line_column reste à (0,0)
+        assert_eq!(grid.max_line_column.line, line_column.0);
+        assert_eq!(grid.max_line_column.column, line_column.1);
+
+        // Vérifie que la zone est maintenant connue
+        assert!(grid.hashmap_zones.contains_key(&c_zone));
+        assert!(grid.get_zone(c_zone).is_some());
+
+        // Vérifie que la case placée est maintenant connue
+        assert!(grid.hashmap_cells.contains_key(&struct_line_column));
+        assert!(grid.get_cell(struct_line_column).is_some());
+
+        // Vérifie que la case est bien référencée dans la zone
+        let zone = grid.get_zone(c_zone).unwrap();
+        assert_eq!(zone.c_zone, c_zone);
+        assert!(zone.set_line_column.contains(&struct_line_column));
+
+        // Vérifie que la case est correctement définie
+        let cell = grid.get_cell(struct_line_column).unwrap();
+        assert_eq!(cell.c_zone, c_zone);
+        assert_eq!(cell.line_column, struct_line_column);
+        assert_eq!(cell.content, CellContent::Number(content_number));
+    }
+
+    #[test]
+    fn test_grid_edit_cells() {
+        let mut grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        // Modification du contenu des cases
+        assert!(grid.set_number(LineColumn::new(0, 1), 5));
+        assert!(!grid.set_number(LineColumn::new(5, 5), 1));
+        assert_eq!(grid.clear_number(LineColumn::new(0, 1)), Some(5));
+        assert_eq!(grid.clear_number(LineColumn::new(0, 1)), None);
+        assert_eq!(grid.clear_number(LineColumn::new(1, 0)), Some(4));
+        assert_eq!(
+            grid.get_cell(LineColumn::new(1, 0)).unwrap().content,
+            CellContent::Undefined
+        );
+
+        // Une zone sans case disparaît
+        let cell = grid.remove_cell(LineColumn::new(0, 0)).unwrap();
+        assert_eq!(cell.content, CellContent::Number(1));
+        assert!(!grid.hashmap_zones.contains_key(&'a'));
+        assert!(grid.remove_cell(LineColumn::new(0, 0)).is_none());
+
+        // Les dimensions de la grille diminuent
+        for column in 0..3 {
+            let _ = grid.remove_cell(LineColumn::new(2, column));
+        }
+        assert!(!grid.hashmap_zones.contains_key(&'c'));
+        assert_eq!(grid.max_line_column, LineColumn::new(1, 2));
+        assert_eq!(grid.min_line_column, LineColumn::new(0, 0));
+
+        // Les dimensions diminuent aussi en haut et à gauche
+        let mut shrunk = grid.clone();
+        for column in 0..3 {
+            let _ = shrunk.remove_cell(LineColumn::new(0, column));
+        }
+        let _ = shrunk.remove_cell(LineColumn::new(1, 0));
+        assert_eq!(shrunk.min_line_column, LineColumn::new(1, 1));
+        assert_eq!(shrunk.max_line_column, LineColumn::new(1, 2));
+        assert_eq!((shrunk.width(), shrunk.height()), (2, 1));
+
+        // Une case redéfinie change de zone
+        grid.add_cell((1, 2), 'd', Some(1));
+        assert_eq!(grid.hashmap_zones[&'b'].set_line_column.len(), 4);
+        assert!(grid.hashmap_zones[&'d']
+            .set_line_column
+            .contains(&LineColumn::new(1, 2)));
+    }
+
+    #[test]
+    fn test_geometric_transformations() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        ",
+        )
+        .unwrap();
+
+        assert_eq!(grid.rotated_90().to_puzzle_string(), "b4 a1\nb  b\nb  b2\n");
+        assert_eq!(
+            grid.mirrored_horizontal().to_puzzle_string(),
+            "b2 b  a1\nb  b  b4\n"
+        );
+        assert_eq!(
+            grid.mirrored_vertical().to_puzzle_string(),
+            "b4 b  b\na1 b  b2\n"
+        );
+        assert_eq!(grid.transposed().to_puzzle_string(), "a1 b4\nb  b\nb2 b\n");
+
+        // Quatre quarts de tour ramènent à la grille d'origine
+        let rotated = grid.rotated_90().rotated_90().rotated_90().rotated_90();
+        assert_eq!(rotated.to_string(), grid.to_string());
+        assert_eq!(rotated.hashmap_zones[&'b'].set_line_column.len(), 5);
+    }
+
+    #[test]
+    fn test_normalized() {
+        let mut grid = Grid::default();
+        grid.add_line(2, vec![('x', Some(1)), ('z', None), ('z', Some(2))]);
+        grid.add_line(3, vec![('z', Some(4)), ('z', None), ('z', None)]);
+        grid.add_line(4, vec![('y', None), ('y', None), ('y', Some(2))]);
+
+        let normalized = grid.normalized();
+        assert_eq!(
+            normalized.to_puzzle_string(),
+            "a1 b  b2\nb4 b  b\nc  c  c2\n"
+        );
+        assert_eq!(normalized.min_line_column, LineColumn::new(0, 0));
+        assert_eq!(normalized.max_line_column, LineColumn::new(2, 2));
+
+        // Même forme canonique pour une grille de même structure
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(
+            grid.normalized().to_puzzle_string(),
+            normalized.to_puzzle_string()
+        );
+
+        assert_eq!(Grid::canonical_zone_name(0), "a");
+        assert_eq!(Grid::canonical_zone_name(25), "z");
+        assert_eq!(Grid::canonical_zone_name(26), "aa");
+        assert_eq!(Grid::canonical_zone_name(27), "ab");
+        assert_eq!(Grid::canonical_zone_name(52), "ba");
+    }
+
+    #[test]
+    fn test_grid_eq() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(grid, grid.clone());
+
+        // Les noms des zones ne comptent pas
+        assert_eq!(grid, Grid::from_str("x1 a  a2\na4 a  a\nb  b  b2").unwrap());
+
+        // Contenu, répartition dans les zones ou positions différentes
+        assert_ne!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c3").unwrap());
+        assert_ne!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  b2").unwrap());
+        assert_ne!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nb  c  c2").unwrap());
+        assert_ne!(grid, Grid::from_str("a1 a  a2\na4 a  a\nc  c  c2").unwrap());
+        assert_ne!(grid, grid.transposed());
+        assert_eq!(grid.transposed().transposed(), grid);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let fingerprint = grid.fingerprint();
+        assert_eq!(fingerprint, grid.normalized().fingerprint());
+        assert_eq!(
+            fingerprint,
+            Grid::from_str("x1 a  a2\na4 a  a\nb  b  b2")
+                .unwrap()
+                .fingerprint()
+        );
+        assert_ne!(
+            fingerprint,
+            Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c3")
+                .unwrap()
+                .fingerprint()
+        );
+
+        // Les grilles obtenues par rotation ou symétrie sont des doublons en option
+        let mirrored = grid.mirrored_horizontal();
+        assert_ne!(mirrored.fingerprint(), fingerprint);
+        let fingerprint = grid.fingerprint_up_to_symmetry();
+        assert_eq!(mirrored.fingerprint_up_to_symmetry(), fingerprint);
+        assert_eq!(grid.rotated_90().fingerprint_up_to_symmetry(), fingerprint);
+        assert_eq!(
+            grid.mirrored_vertical().fingerprint_up_to_symmetry(),
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn test_cells_zones_iterators() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+
+        let vec_line_column: Vec<LineColumn> =
+            grid.cells().map(|(line_column, _)| *line_column).collect();
+        assert_eq!(vec_line_column.len(), 9);
+        assert_eq!(vec_line_column[0], LineColumn::new(0, 0));
+        assert_eq!(vec_line_column[1], LineColumn::new(0, 1));
+        assert_eq!(vec_line_column[3], LineColumn::new(1, 0));
+        assert_eq!(vec_line_column[8], LineColumn::new(2, 2));
+        assert!(grid
+            .cells()
+            .all(|(line_column, cell)| *line_column == cell.line_column));
+
+        let vec_zone: Vec<(char, usize)> = grid
+            .zones()
+            .map(|(c_zone, zone)| (*c_zone, zone.set_line_column.len()))
+            .collect();
+        assert_eq!(vec_zone, vec![('a', 1), ('b', 5), ('c', 3)]);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let grid = Grid::default();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!((grid.width(), grid.height(), grid.cell_count()), (0, 0, 0));
+
+        // Grille irrégulière qui ne commence pas en (0, 0)
+        let mut grid = Grid::default();
+        grid.add_line(2, vec![('a', Some(1)), ('b', None), ('b', Some(2))]);
+        grid.add_line(3, vec![('b', None)]);
+        assert_eq!(
+            grid.bounds(),
+            Some((LineColumn::new(2, 0), LineColumn::new(3, 2)))
+        );
+        assert_eq!((grid.width(), grid.height(), grid.cell_count()), (3, 2, 4));
+    }
+
+    #[test]
+    fn test_zone_api() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+
+        let zone = &grid.hashmap_zones[&'b'];
+        assert_eq!(zone.size(), 5);
+        assert!(zone.contains(LineColumn::new(1, 0)));
+        assert!(!zone.contains(LineColumn::new(0, 0)));
+        assert_eq!(zone.cells().next(), Some(LineColumn::new(0, 1)));
+        assert_eq!(zone.cells().count(), 5);
+        assert_eq!(zone.missing_digits(&grid), vec![1, 3, 5]);
+
+        assert_eq!(
+            grid.hashmap_zones[&'a'].missing_digits(&grid),
+            Vec::<u8>::new()
+        );
+        assert_eq!(grid.hashmap_zones[&'c'].missing_digits(&grid), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_zone_adjacency() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        d  e  e
+        ",
+        )
+        .unwrap();
+
+        let adjacency = grid.zone_adjacency();
+        assert_eq!(adjacency.len(), 5);
+        assert_eq!(adjacency[&'a'], BTreeSet::from(['b']));
+        assert_eq!(adjacency[&'b'], BTreeSet::from(['a', 'c']));
+        assert_eq!(adjacency[&'c'], BTreeSet::from(['b', 'd', 'e']));
+        assert_eq!(adjacency[&'d'], BTreeSet::from(['c', 'e']));
+        assert_eq!(adjacency[&'e'], BTreeSet::from(['c', 'd']));
+
+        // Zones voisines par un coin seulement
+        let grid = Grid::from_str("a b\nb a\n").unwrap();
+        assert_eq!(grid.zone_adjacency()[&'a'], BTreeSet::from(['b']));
+    }
+
+    #[test]
+    fn test_crop() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2 d
+        b4 b  b  d
+        c  c  c2 d3
+        ",
+        )
+        .unwrap();
+
+        // Extraction de zones entières
+        let cropped = grid
+            .crop(LineColumn::new(0, 3), LineColumn::new(5, 5))
+            .unwrap();
+        assert_eq!(cropped.cell_count(), 3);
+        assert_eq!(cropped.hashmap_zones.len(), 1);
+        assert_eq!(
+            cropped.get_cell(LineColumn::new(2, 3)).unwrap().content,
+            CellContent::Number(3)
+        );
+
+        // Zones coupées par l'extraction
+        let error = grid
+            .crop(LineColumn::new(0, 0), LineColumn::new(1, 1))
+            .unwrap_err();
+        assert_eq!(error.zone(), 'b');
+        let cropped = grid.crop_clipped(LineColumn::new(0, 0), LineColumn::new(1, 1));
+        assert_eq!(cropped.to_puzzle_string(), "a1 b\nb4 b\n");
+        assert_eq!(cropped.hashmap_zones[&'b'].size(), 3);
+
+        // Zone désignée par plusieurs lettres coupée par l'extraction
+        let grid = Grid::from_str("aa1 ab  ab2\nab4 ab  ab").unwrap();
+        let error = grid
+            .crop(LineColumn::new(0, 0), LineColumn::new(0, 1))
+            .unwrap_err();
+        assert_eq!(error.zone_name(), "ab");
+        assert_eq!(
+            error.to_string(),
+            "La zone 'ab' est coupée par l'extraction"
+        );
+    }
+
+    #[test]
+    fn test_translate() {
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let grid_origin = grid.clone();
+
+        grid.translate(-3, 2);
+        assert_eq!(grid.min_line_column, LineColumn::new(-3, 2));
+        assert_eq!(grid.max_line_column, LineColumn::new(-1, 4));
+        assert_eq!(
+            grid.get_cell(LineColumn::new(-3, 2)).unwrap().content,
+            CellContent::Number(1)
+        );
+        assert!(grid.hashmap_zones[&'a'].contains(LineColumn::new(-3, 2)));
+        assert_eq!(grid.to_puzzle_string(), grid_origin.to_puzzle_string());
+
+        grid.normalize_origin();
+        assert_eq!(grid, grid_origin);
+        assert_eq!(grid.min_line_column, LineColumn::new(0, 0));
+        assert_eq!(grid.max_line_column, LineColumn::new(2, 2));
+
+        // Grille construite avec un décalage
+        let mut grid = Grid::default();
+        grid.add_line(4, vec![('a', Some(1)), ('b', None)]);
+        grid.normalize_origin();
+        assert_eq!(
+            grid.bounds(),
+            Some((LineColumn::new(0, 0), LineColumn::new(0, 1)))
+        );
+        assert_eq!(grid.max_line_column, LineColumn::new(0, 1));
+    }
+
+    #[test]
+    fn test_overlay() {
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let tile = Grid::from_str("a1 b  b2\nb4 b  b\nc  d  d2").unwrap();
+
+        let hashmap_zones = grid.overlay(&tile, LineColumn::new(0, 3));
+        assert_eq!(hashmap_zones[&'a'], 'd');
+        assert_eq!(hashmap_zones[&'b'], 'e');
+        assert_eq!(hashmap_zones[&'c'], 'f');
+        assert_eq!(hashmap_zones[&'d'], 'g');
+        assert_eq!(
+            grid.to_puzzle_string(),
+            "a1 b  b2 d1 e  e2\nb4 b  b  e4 e  e\nc  c  c2 f  g  g2\n"
+        );
+        assert_eq!(grid.hashmap_zones.len(), 7);
+        assert_eq!(grid.max_line_column, LineColumn::new(2, 5));
+
+        // Les cases de l'autre grille remplacent celles de la grille
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let _ = grid.overlay(&Grid::from_str("x2").unwrap(), LineColumn::new(2, 2));
+        assert_eq!(grid.hashmap_zones[&'c'].size(), 2);
+        assert_eq!(
+            grid.get_cell(LineColumn::new(2, 2)).unwrap().content,
+            CellContent::Number(2)
+        );
+    }
+
+    #[test]
+    fn test_is_given() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let vec_given: Vec<LineColumn> = grid
+            .cells()
+            .filter(|(_, cell)| cell.is_given)
+            .map(|(line_column, _)| *line_column)
+            .collect();
+        assert_eq!(
+            vec_given,
+            vec![
+                LineColumn::new(0, 0),
+                LineColumn::new(0, 2),
+                LineColumn::new(1, 0),
+                LineColumn::new(2, 2)
+            ]
+        );
+
+        // Les chiffres connus au départ sont conservés par les transformations
+        assert!(
+            grid.rotated_90()
+                .cells()
+                .filter(|(_, c)| c.is_given)
+                .count()
+                == 4
+        );
+        assert!(
+            grid.normalized()
+                .get_cell(LineColumn::new(0, 0))
+                .unwrap()
+                .is_given
+        );
+
+        // Chiffres placés pendant la résolution
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        let mut solved_grid = solver.to_grid();
+        let cell = solved_grid.get_cell(LineColumn::new(0, 1)).unwrap();
+        assert!(matches!(cell.content, CellContent::Number(_)));
+        assert!(!cell.is_given);
+        assert!(
+            solved_grid
+                .get_cell(LineColumn::new(0, 0))
+                .unwrap()
+                .is_given
+        );
+
+        assert_eq!(solved_grid.cleared(), grid);
+        assert!(solved_grid.is_complete());
+        solved_grid.reset_to_givens();
+        assert_eq!(solved_grid, grid);
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert!(!grid.is_complete());
+        assert!((grid.completion() - 4.0 / 9.0).abs() < f64::EPSILON);
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        let solved_grid = solver.to_grid();
+        assert!(solved_grid.is_complete());
+        assert!((solved_grid.completion() - 1.0).abs() < f64::EPSILON);
+
+        assert!(Grid::default().is_complete());
+    }
+
+    #[test]
+    fn test_from_matrices() {
+        let grid = Grid::from_matrices(
+            &[&['a', 'b', 'b'], &['b', 'b', 'b'], &['c', 'c', 'c']],
+            &[
+                &[Some(1), None, Some(2)],
+                &[Some(4), None, None],
+                &[None, None, Some(2)],
+            ],
+        )
+        .unwrap();
+        assert_eq!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap());
+        assert!(grid.get_cell(LineColumn::new(0, 0)).unwrap().is_given);
+
+        // Matrices de dimensions différentes
+        let error = Grid::from_matrices(&[&['a', 'b'], &['b']], &[&[Some(1), None], &[None, None]])
+            .unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+        let error = Grid::from_matrices(&[&['a', 'b']], &[&[Some(1), None], &[None]]).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 0, ""));
+        let error = Grid::from_matrices(&[&['a', 'b']], &[&[Some(1), Some(0)]]).unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (0, 1, "b0"));
+    }
+
+    #[test]
+    fn test_add_line_str() {
+        let mut grid = Grid::default();
+        grid.add_line_str(0, "a1 b  b2").unwrap();
+        grid.add_line_str(1, "  b4 b  b  ").unwrap();
+
+        // Une ligne incorrecte n'ajoute aucune case
+        let error = grid.add_line_str(2, "c  c? c2").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (2, 1, "c?"));
+        assert_eq!(grid.cell_count(), 6);
+        assert!(!grid.hashmap_zones.contains_key(&'c'));
+
+        grid.add_line_str(2, "c  c  c2").unwrap();
+        assert_eq!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap());
+    }
+
+    #[test]
+    fn test_from_walls() {
+        const R: u8 = Grid::WALL_RIGHT;
+        const B: u8 = Grid::WALL_BOTTOM;
+        let grid = Grid::from_walls(
+            &[
+                &[Some(1), None, Some(2)],
+                &[Some(4), None, None],
+                &[None, None, Some(2)],
+            ],
+            &[&[R | B, 0, 0], &[B, B, B], &[0, 0, 0]],
+        )
+        .unwrap();
+        assert_eq!(grid.to_puzzle_string(), "a1 b  b2\nb4 b  b\nc  c  c2\n");
+
+        // Matrices de dimensions différentes
+        assert!(Grid::from_walls(&[&[Some(1), None]], &[&[0]]).is_err());
+    }
+
+    #[test]
+    fn test_puzzle_from_solution() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        let solved_grid = solver.to_grid();
+
+        let keep: HashSet<LineColumn> = grid
+            .cells()
+            .filter(|(_, cell)| cell.is_given)
+            .map(|(line_column, _)| *line_column)
+            .collect();
+        assert_eq!(solved_grid.puzzle_from_solution(&keep), grid);
+
+        // Tirage reproductible des cases conservées
+        let puzzle = solved_grid.puzzle_from_solution_random(3, 1);
+        assert_eq!(puzzle.cells().filter(|(_, c)| c.is_given).count(), 3);
+        assert_eq!(
+            puzzle
+                .hashmap_cells
+                .values()
+                .filter(|c| c.content.is_number())
+                .count(),
+            3
+        );
+        assert_eq!(puzzle, solved_grid.puzzle_from_solution_random(3, 1));
+        for (line_column, cell) in puzzle.cells() {
+            if cell.is_given {
+                assert_eq!(cell.content, solved_grid.hashmap_cells[line_column].content);
+            }
+        }
+        assert!(solved_grid.puzzle_from_solution_random(20, 1).is_complete());
+    }
+
+    #[test]
+    fn test_cleared_after_export() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        let solved_grid = solver.to_grid();
+        assert_eq!(solved_grid.cleared(), grid);
+
+        // Le texte de la grille relu ne distingue plus les chiffres placés pendant la résolution
+        let reloaded = Grid::from_str(&solved_grid.to_puzzle_string()).unwrap();
+        assert_eq!(reloaded.cleared(), solved_grid);
+        let reloaded = Grid::from_csv(&solved_grid.to_csv()).unwrap();
+        assert_eq!(reloaded.cleared(), solved_grid);
+
+        // La sérialisation des cases conserve les chiffres connus au départ
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&solved_grid).unwrap();
+            let reloaded: Grid = serde_json::from_str(&json).unwrap();
+            assert_eq!(reloaded.cleared(), grid);
+        }
+
+        // Comme l'encodage binaire de la grille avec sa solution
+        #[cfg(feature = "bincode")]
+        {
+            let bytes = grid.to_binary_with_solution(&solved_grid).unwrap();
+            let (_, solution) = Grid::from_binary_with_solution(&bytes).unwrap();
+            assert_eq!(solution.unwrap().cleared(), grid);
+        }
+    }
+
+    #[test]
+    fn test_shuffled_digits() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+
+        // Permutations incompatibles avec la taille des zones ou avec les cases voisines
+        assert!(grid
+            .relabeled_digits(&[2, 1, 3, 4, 5, 6, 7, 8, 9])
+            .is_none());
+        assert!(grid
+            .relabeled_digits(&[1, 1, 3, 4, 5, 6, 7, 8, 9])
+            .is_none());
+        let relabeled = grid.relabeled_digits(&[1, 3, 2, 5, 4, 6, 7, 8, 9]).unwrap();
+        assert_eq!(
+            relabeled.to_puzzle_string(),
+            "a1 b  b3\nb5 b  b\nc  c  c3\n"
+        );
+
+        // Les variantes ont une solution comme la grille d'origine
+        for seed in 0..10 {
+            let variant = grid.shuffled_digits(seed);
+            assert_eq!(variant, grid.shuffled_digits(seed));
+            assert!(variant.validate().is_empty());
+            let mut solver = Solver::new(&variant);
+            assert!(solver.solve(&SolverConfig::default()).is_solved());
+        }
+        assert!((0..10).any(|seed| grid.shuffled_digits(seed) != grid));
+    }
+
+    #[test]
+    fn test_to_puzzle_string() {
+        let str_grid = "a1 b  b2\nb4 b  b\nc  c  c2\n";
+        let grid = Grid::from_str(str_grid).unwrap();
+        assert_eq!(grid.to_puzzle_string(), str_grid);
+
+        // Le texte produit est relu à l'identique
+        let grid = Grid::from_str(
+            "
+        # Jeu Le Routard no 13 - page 38 (niveau rouge)
+        a  a5 a  b  b
+        c  a  a  b3 b
+        d  e  e  e  f
+        d5 d  e2 e  g
+        d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+        let puzzle_string = grid.to_puzzle_string();
+        let grid_reloaded = Grid::from_str(&puzzle_string).unwrap();
+        assert_eq!(grid_reloaded.to_puzzle_string(), puzzle_string);
+        assert_eq!(grid_reloaded.to_string(), grid.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_grid_serde() {
+        let mut grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        grid.get_mut_cell(LineColumn::new(0, 1)).unwrap().content =
+            CellContent::PossibleNumbers(Simple09Set::new(&[3, 5]));
+
+        let json = serde_json::to_string(&grid).unwrap();
+        assert!(json.starts_with(
+            r#"{"cells":[{"c_zone":"a","line_column":{"line":0,"column":0},"content":{"Number":1},"is_given":true}"#
+        ));
+        assert!(json.contains(r#"{"PossibleNumbers":[3,5]}"#));
+
+        let grid_reloaded: Grid = serde_json::from_str(&json).unwrap();
+        assert_eq!(grid_reloaded.to_string(), grid.to_string());
+        assert_eq!(grid_reloaded.hashmap_zones.len(), 3);
+        assert_eq!(grid_reloaded.hashmap_zones[&'b'].set_line_column.len(), 5);
+        assert_eq!(serde_json::to_string(&grid_reloaded).unwrap(), json);
+    }
+
+    #[test]
+    fn test_is_zone_contiguous() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  a
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        assert!(grid.is_zone_contiguous('b'));
+        assert!(grid.is_zone_contiguous('c'));
+        assert!(!grid.is_zone_contiguous('a'));
+        assert!(grid.is_zone_contiguous('z'));
+    }
+
+    #[test]
+    fn test_parse_grid_ok() {
+        let result_grid = Grid::from_str(
+            "
+        # Exemple de grille ok
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        );
+
+        assert!(result_grid.is_ok());
+    }
+
+    #[test]
+    fn test_parse_grid_nok() {
+        let result_grid = Grid::from_str(
+            "
+        # NOK car une case b22 avec syntaxe incorrecte (line=1, column=1)
+        a1 b  b2
+        b4 b22 b
+        c  c  c2
+        ",
+        );
+
+        assert!(result_grid.is_err());
+        if let Err(ParseGridError::InvalidCellToken {
+            token,
+            line,
+            column,
+            ..
+        }) = result_grid
+        {
+            assert_eq!(token, "b22");
+            assert_eq!(line, 1);
+            assert_eq!(column, 1);
+        } else {
+            panic!("ParseGridError non détectée");
+        }
+    }
+
+    #[test]
+    fn test_parse_grid_nok_2() {
+        let result_grid = Grid::from_str(
+            "
+        # NOK car une case b? avec syntaxe incorrecte (line=1, column=1)
+        a1 b  b2
+        b4 b? b
+        c  c  c2
+        ",
+        );
+
+        assert!(result_grid.is_err());
+        if let Err(ParseGridError::InvalidCellToken { line, column, .. }) = result_grid {
+            assert_eq!(line, 1);
+            assert_eq!(column, 1);
+        } else {
+            panic!("ParseGridError non détectée");
+        }
+    }
+
+    #[test]
+    fn test_parse_multi_char_zones() {
+        let grid = Grid::from_str(
+            "
+        # Zones désignées par plusieurs lettres
+        aa1 ab  ab2
+        ab4 ab  ab
+        b   b   b2
+        ",
+        )
+        .unwrap();
+
+        assert_eq!(grid.hashmap_zones.len(), 3);
+        let cell = grid.get_cell(LineColumn::new(0, 0)).unwrap();
+        assert_eq!(grid.zone_name(cell.c_zone), "aa");
+        assert_eq!(cell.content, CellContent::Number(1));
+        let cell = grid.get_cell(LineColumn::new(1, 0)).unwrap();
+        assert_eq!(grid.zone_name(cell.c_zone), "ab");
+        assert_eq!(grid.hashmap_zones[&cell.c_zone].set_line_column.len(), 5);
+        assert_eq!(grid.get_cell(LineColumn::new(2, 0)).unwrap().c_zone, 'b');
+
+        // Le texte produit est relu à l'identique
+        let puzzle_string = grid.to_puzzle_string();
+        assert_eq!(puzzle_string, "aa1 ab  ab2\nab4 ab  ab\nb   b   b2\n");
+        let grid_reloaded = Grid::from_str(&puzzle_string).unwrap();
+        assert_eq!(grid_reloaded.to_string(), grid.to_string());
+        assert!(grid.to_string().contains("aa1 ab  ab2"));
+
+        // Un nom de zone ne contient que des lettres
+        assert!(Grid::from_str("a1 b2c").is_err());
+    }
+
+    #[test]
+    fn test_multi_char_zone_ids() {
+        assert_eq!(multi_char_zone_id(0), Some('\u{e000}'));
+        assert_eq!(multi_char_zone_id(6399), Some('\u{f8ff}'));
+        assert_eq!(multi_char_zone_id(6400), Some('\u{f0000}'));
+        assert_eq!(multi_char_zone_id(6400 + 65534), Some('\u{100000}'));
+        assert_eq!(multi_char_zone_id(6400 + 2 * 65534 - 1), Some('\u{10fffd}'));
+        assert_eq!(multi_char_zone_id(6400 + 2 * 65534), None);
+
+        // Erreur lorsque tous les caractères sont attribués
+        let mut grid = Grid::default();
+        for index in 0..6400 + 2 * 65534 {
+            let c_zone = multi_char_zone_id(index).unwrap();
+            grid.zone_names.insert(c_zone, format!("z{index}"));
+        }
+        assert_eq!(
+            grid.zone_id("aa"),
+            Err(ParseGridError::TooManyZones("aa".to_string()))
+        );
+        assert_eq!(grid.zone_id("a"), Ok('a'));
+    }
+
+    #[test]
+    fn test_parse_grid_nok_3() {
+        let result_grid = Grid::from_str(
+            "
+        # NOK car la case (line=1, column=1) ne peut pas contenir 0
+        a1 b  b2
+        b4 b0 b
+        c  c  c2
+        ",
+        );
+
+        assert!(result_grid.is_err());
+        if let Err(ParseGridError::DigitOutOfRange { line, column, .. }) = result_grid {
+            assert_eq!(line, 1);
+            assert_eq!(column, 1);
+        } else {
+            panic!("ParseGridError non détectée");
+        }
+    }
+
+    #[test]
+    fn test_from_str_lenient() {
+        let (grid, vec_errors) = Grid::from_str_lenient(
+            "
+        # Deux cases incorrectes
+        a1 b  b2
+        b4 b? b
+        c  c0 c2
+        ",
+        );
+        let vec_positions: Vec<(i32, i32, &str, usize)> = vec_errors
+            .iter()
+            .map(|e| {
+                let number = e.source_line().unwrap().number;
+                (e.line(), e.column(), e.token(), number)
+            })
+            .collect();
+        assert_eq!(vec_positions, vec![(1, 1, "b?", 4), (2, 1, "c0", 5)]);
+        assert!(matches!(
+            vec_errors[1],
+            ParseGridError::DigitOutOfRange { .. }
+        ));
+        assert_eq!(grid.cell_count(), 7);
+        assert!(grid.get_cell(LineColumn::new(1, 1)).is_none());
+        assert!(grid.get_cell(LineColumn::new(1, 2)).is_some());
+
+        // Texte correct
+        let s = "a1 b  b2\nb4 b  b\nc  c  c2";
+        let (grid, vec_errors) = Grid::from_str_lenient(s);
+        assert!(vec_errors.is_empty());
+        assert_eq!(grid, Grid::from_str(s).unwrap());
+
+        let (_, vec_errors) = Grid::from_str_lenient("# Vide");
+        assert_eq!(vec_errors, vec![ParseGridError::EmptyGrid]);
+
+        // Options de lecture : marques des cases sans chiffre et lignes de longueurs différentes
+        let options = ParseOptions::new()
+            .ragged_rows(RaggedRows::Reject)
+            .empty_cell_markers(true);
+        let (grid, vec_errors) =
+            Grid::from_str_lenient_with_options("a1 b_ b2\nb4 b?\nc  c0 c2", &options);
+        assert_eq!(grid.cell_count(), 7);
+        assert_eq!(vec_errors.len(), 2);
+        assert_eq!(vec_errors[0].token(), "b?");
+        assert!(matches!(
+            vec_errors[1],
+            ParseGridError::RaggedRow {
+                line: 1,
+                width: 2,
+                expected: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_compact_lines() {
+        let grid = Grid::from_str(
+            "
+        # Format compact : deux caractères par case
+        a1b.b2
+        b4b.b.
+        c.c.c2
+        ",
+        )
+        .unwrap();
+        assert_eq!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap());
+
+        // Les deux formats peuvent être mélangés ligne par ligne
+        let grid = Grid::from_str("a1 b  b2\nb4b.b.\nc  c  c2").unwrap();
+        assert_eq!(grid.cell_count(), 9);
+
+        // Une zone de plusieurs lettres n'est pas une ligne au format compact
+        assert!(!Grid::is_compact_line("ab"));
+        assert!(Grid::is_compact_line("a."));
+        assert!(!Grid::is_compact_line("a1b"));
+
+        let error = Grid::from_str("a1b.\n  b.b0\n").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 1, "b0"));
+        assert_eq!(error.source_line().unwrap().offset, 4);
+    }
+
+    #[test]
+    fn test_parse_unicode_zones() {
+        let grid = Grid::from_str(
+            "
+        # Zones en majuscules et en lettres accentuées
+        A1 é  é2
+        é4 é  é
+        Ω  Ω  a2
+        ",
+        )
+        .unwrap();
+        assert_eq!(grid.hashmap_zones.len(), 4);
+        let cell = grid.get_cell(LineColumn::new(0, 0)).unwrap();
+        assert_eq!(grid.zone_name(cell.c_zone), "A");
+        assert_eq!(grid.hashmap_zones[&'é'].set_line_column.len(), 5);
+
+        // Le texte produit est relu à l'identique
+        let puzzle_string = grid.to_puzzle_string();
+        assert_eq!(puzzle_string, "A1 é  é2\né4 é  é\nΩ  Ω  a2\n");
+        assert_eq!(Grid::from_str(&puzzle_string).unwrap(), grid);
+
+        // 'a' et 'A' sont deux zones différentes
+        let grid = Grid::from_str("a  A2\nÀa a1").unwrap();
+        assert_eq!(grid.hashmap_zones.len(), 3);
+        assert_eq!(
+            grid.zone_name(grid.get_cell(LineColumn::new(1, 0)).unwrap().c_zone),
+            "Àa"
+        );
+
+        // Une zone est désignée uniquement par des lettres
+        assert!(Grid::from_str("a1 ?").is_err());
+        assert!(Grid::from_str("*1").is_err());
+        assert!(!Grid::is_valid_zone_name(""));
+        assert!(!Grid::is_valid_zone_name("e\u{301}"));
+    }
+
+    #[test]
+    fn test_parse_whitespace() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+
+        // BOM, fins de ligne Windows, tabulations et commentaires en fin de ligne
+        let s = "\u{feff}# Grille\r\na1\tb \t b2  # ligne 0\r\n\r\nb4 b b#1\r\nc c c2\r\n";
+        assert_eq!(Grid::from_str(s).unwrap(), grid);
+        let (grid_lenient, vec_errors) = Grid::from_str_lenient(s);
+        assert!(vec_errors.is_empty());
+        assert_eq!(grid_lenient, grid);
+
+        // Fins de ligne des anciens Mac
+        assert_eq!(Grid::from_str("a1 b b2\rb4 b b\rc c c2").unwrap(), grid);
+
+        // La ligne de l'erreur est numérotée dans le texte d'origine
+        let error = Grid::from_str("\u{feff}a1\r\n\r\nb\tb0 # commentaire\r\n").unwrap_err();
+        let source = error.source_line().unwrap();
+        assert_eq!((source.number, source.offset), (3, 2));
+
+        let mut grid = Grid::default();
+        grid.add_line_str(0, "a1 b2 # commentaire").unwrap();
+        assert_eq!(grid.cell_count(), 2);
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        let s = "a1 b  b2\nb4 b\nc  c  c2 c";
+        let grid = Grid::from_str(s).unwrap();
+        assert_eq!(grid.cell_count(), 9);
+
+        // Grille irrégulière (comme `Grid::from_str`)
+        let options = ParseOptions::new().ragged_rows(RaggedRows::Irregular);
+        assert_eq!(Grid::from_str_with_options(s, &options).unwrap(), grid);
+
+        // Lignes trop courtes complétées par des trous
+        let grid = Grid::from_str_with_options("a1 b  b2\nb4 b", &options).unwrap();
+        assert_eq!(grid.cell_count(), 5);
+        assert_eq!(grid.width(), 3);
+
+        // Toutes les lignes doivent avoir la même longueur
+        let options = ParseOptions::new().ragged_rows(RaggedRows::Reject);
+        let error = Grid::from_str_with_options(s, &options).unwrap_err();
+        assert!(matches!(
+            error,
+            ParseGridError::RaggedRow {
+                line: 1,
+                width: 2,
+                expected: 3,
+                ..
+            }
+        ));
+        let source = error.source_line().unwrap();
+        assert_eq!((source.number, source.offset), (2, 5));
+        assert!(error
+            .to_string()
+            .starts_with("Ligne 2, colonne 6 : la ligne a 2 case(s)"));
+        let s = "a1 b  b2\nb4 b  b\nc  c  c2";
+        assert!(Grid::from_str_with_options(s, &options).is_ok());
+    }
+
+    #[test]
+    fn test_parse_empty_cell_markers() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+
+        let options = ParseOptions::new().empty_cell_markers(true);
+        let s = "a1 b0 b2\nb4 b_ b\nc0 c_ c2";
+        assert_eq!(Grid::from_str_with_options(s, &options).unwrap(), grid);
+        let s = "a1b0b2\nb4b_b.\nc0c_c2";
+        assert_eq!(Grid::from_str_with_options(s, &options).unwrap(), grid);
+
+        // Sans l'option, les marques sont des erreurs
+        assert!(matches!(
+            Grid::from_str("a1 b0"),
+            Err(ParseGridError::DigitOutOfRange { column: 1, .. })
+        ));
+        assert!(matches!(
+            Grid::from_str("a1 b_"),
+            Err(ParseGridError::InvalidCellToken { column: 1, .. })
+        ));
+        // Une marque seule n'est pas une case
+        assert!(Grid::from_str_with_options("a1 _", &options).is_err());
+
+        for marker in [
+            EmptyCellMarker::None,
+            EmptyCellMarker::Zero,
+            EmptyCellMarker::Underscore,
+        ] {
+            let text = grid.to_puzzle_string_with_marker(marker);
+            assert_eq!(Grid::from_str_with_options(&text, &options).unwrap(), grid);
+        }
+        assert_eq!(
+            grid.to_puzzle_string_with_marker(EmptyCellMarker::Zero),
+            "a1 b0 b2\nb4 b0 b0\nc0 c0 c2\n"
+        );
+        assert_eq!(
+            grid.to_puzzle_string_with_marker(EmptyCellMarker::None),
+            grid.to_puzzle_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_error() {
+        assert_eq!(
+            Grid::from_str("# Aucune case\n\n"),
+            Err(ParseGridError::EmptyGrid)
+        );
+
+        let error = ParseGridError::invalid_token(0, 1, "b?");
+        assert_eq!(
+            error.to_string(),
+            "Ligne 1, case 2 : case 'b?' incorrecte (une case est le nom de sa zone en \
+             lettres, suivi éventuellement de son chiffre)"
+        );
+
+        // Numéro de la ligne dans le texte (avec les commentaires et les lignes vides) et ligne
+        // soulignée
+        let error = Grid::from_str("# Commentaire\n\na1 b\n  b4  b22\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+        assert_eq!(
+            error.source_line(),
+            Some(&SourceLine {
+                number: 4,
+                text: "  b4  b22".to_string(),
+                offset: 6,
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "Ligne 4, colonne 7 : case 'b22' incorrecte (une case est le nom de sa zone en \
+             lettres, suivi éventuellement de son chiffre)\n  b4  b22\n      ^^^"
+        );
+
+        let error = Grid::from_str("a1\nb0").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Ligne 2, colonne 1 : le chiffre de la case 'b0' doit être entre 1 et 9\nb0\n^^"
+        );
+
+        // Tabulations reprises avant le soulignement
+        let error = Grid::from_str("a1\n\tb1\tb0").unwrap_err();
+        assert!(error.to_string().ends_with("\n\tb1\tb0\n\t  \t^^"));
+
+        let error = Grid::from_matrices(&[&['a', 'b']], &[&[None]]).unwrap_err();
+        assert!(matches!(
+            error,
+            ParseGridError::ShapeMismatch { column: 1, .. }
+        ));
     }
 }
@@ -0,0 +1,165 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::grid::{Grid, ParseGridError};
+use crate::solver::{Solver, SolvingError};
+
+/// Erreur rencontrée lors de la construction d'une grille par `GridBuilder::build`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridBuildError {
+    /// Une ligne de la grille n'a pas le format de `Grid::from_str`
+    Parse(ParseGridError),
+
+    /// La grille ne contient aucune case
+    Empty,
+
+    /// La structure de la grille est incorrecte (taille d'une zone, chiffre incompatible avec la
+    /// taille de sa zone, zone qui n'est pas d'un seul tenant, etc.)
+    Invalid(SolvingError),
+}
+
+impl fmt::Display for GridBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridBuildError::Parse(e) => write!(f, "{e}"),
+            GridBuildError::Empty => write!(f, "La grille ne contient aucune case"),
+            GridBuildError::Invalid(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for GridBuildError {}
+
+impl From<ParseGridError> for GridBuildError {
+    fn from(e: ParseGridError) -> Self {
+        GridBuildError::Parse(e)
+    }
+}
+
+impl From<SolvingError> for GridBuildError {
+    fn from(e: SolvingError) -> Self {
+        GridBuildError::Invalid(e)
+    }
+}
+
+/// Construction d'une grille ligne par ligne : la syntaxe de chaque ligne est vérifiée lors de
+/// son ajout et la structure de la grille lors de l'appel à `build`
+///
+/// Chaque ligne a le format d'une ligne de `Grid::from_str` :
+///
+/// ```rust
+/// use tectonic::{GridBuildError, GridBuilder};
+///
+/// # fn main() -> Result<(), GridBuildError> {
+/// let grid = GridBuilder::new()
+///     .row("a1 b  b2")?
+///     .row("b4 b  b")?
+///     .row("c  c  c2")?
+///     .build()?;
+/// assert_eq!(grid.hashmap_zones.len(), 3);
+///
+/// // La zone 'a' est coupée en deux
+/// assert!(GridBuilder::new().row("a b a")?.build().is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GridBuilder {
+    /// Grille en cours de construction
+    grid: Grid,
+
+    /// Nombre de lignes ajoutées
+    nb_rows: i32,
+}
+
+impl GridBuilder {
+    /// Construction d'une grille vide
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ajoute une ligne en bas de la grille
+    /// # Errors
+    /// Une erreur est retournée si la ligne n'a pas le format d'une ligne de `Grid::from_str`
+    pub fn row(mut self, str_line: &str) -> Result<Self, GridBuildError> {
+        self.grid.add_line_str(self.nb_rows, str_line)?;
+        self.nb_rows = self.nb_rows.saturating_add(1);
+        Ok(self)
+    }
+
+    /// Construit la grille et vérifie sa structure : taille des zones, chiffres compatibles avec
+    /// la taille de leur zone et zones d'un seul tenant
+    /// # Errors
+    /// Une erreur est retournée pour la première incohérence trouvée
+    pub fn build(self) -> Result<Grid, GridBuildError> {
+        if self.grid.hashmap_cells.is_empty() {
+            return Err(GridBuildError::Empty);
+        }
+
+        // Taille des zones, chiffres des cases et zones d'un seul tenant
+        let _ = Solver::try_new(&self.grid)?;
+
+        Ok(self.grid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::line_column::LineColumn;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_build() {
+        let grid = GridBuilder::new()
+            .row("a1 b  b2")
+            .and_then(|builder| builder.row("b4 b  b"))
+            .and_then(|builder| builder.row("c  c  c2"))
+            .and_then(GridBuilder::build)
+            .unwrap();
+
+        let grid_from_str = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(grid, grid_from_str);
+    }
+
+    #[test]
+    fn test_build_errors() {
+        assert_eq!(
+            GridBuilder::new().build().unwrap_err(),
+            GridBuildError::Empty
+        );
+
+        match GridBuilder::new()
+            .row("a1 b")
+            .and_then(|builder| builder.row("b b22"))
+        {
+            Err(GridBuildError::Parse(e)) => {
+                assert_eq!((e.line(), e.column(), e.token()), (1, 1, "b22"));
+            }
+            result => panic!("Erreur de syntaxe non détectée : {result:?}"),
+        }
+
+        assert_eq!(
+            GridBuilder::new()
+                .row("a1 b  a")
+                .and_then(|builder| builder.row("b  b  b"))
+                .and_then(GridBuilder::build)
+                .unwrap_err(),
+            GridBuildError::Invalid(SolvingError::ZoneNotContiguous("a".to_string()))
+        );
+
+        assert_eq!(
+            GridBuilder::new()
+                .row("a1 b  b3")
+                .and_then(GridBuilder::build)
+                .unwrap_err(),
+            GridBuildError::Invalid(SolvingError::ZoneWithUnexpectedNumber(
+                "b".to_string(),
+                LineColumn::new(0, 2),
+                3
+            ))
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+
+/// Différence sur une case entre deux grilles (voir `Grid::diff`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CellDiff {
+    /// Case de la grille qui n'existe pas dans l'autre grille
+    Missing(LineColumn),
+
+    /// Case de l'autre grille qui n'existe pas dans la grille
+    Added(LineColumn),
+
+    /// Case qui n'est pas dans la zone correspondant à sa zone dans l'autre grille (case, nom de
+    /// la zone de la case dans la grille, nom de la zone de la case dans l'autre grille)
+    DifferentZone(LineColumn, String, String),
+
+    /// Case avec un contenu différent (case, contenu dans la grille, contenu dans l'autre grille)
+    DifferentContent(LineColumn, CellContent, CellContent),
+}
+
+impl CellDiff {
+    /// Case concernée par cette différence
+    #[must_use]
+    pub fn line_column(&self) -> LineColumn {
+        match self {
+            CellDiff::Missing(line_column)
+            | CellDiff::Added(line_column)
+            | CellDiff::DifferentZone(line_column, ..)
+            | CellDiff::DifferentContent(line_column, ..) => *line_column,
+        }
+    }
+}
+
+impl fmt::Display for CellDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellDiff::Missing(line_column) => write!(f, "Case {line_column} absente"),
+            CellDiff::Added(line_column) => write!(f, "Case {line_column} en plus"),
+            CellDiff::DifferentZone(line_column, zone_name, other_zone_name) => {
+                write!(
+                    f,
+                    "Case {line_column} dans la zone '{zone_name}' au lieu de '{other_zone_name}'"
+                )
+            }
+            CellDiff::DifferentContent(line_column, content, other_content) => {
+                write!(
+                    f,
+                    "Case {line_column} avec {} au lieu de {}",
+                    content_to_string(content),
+                    content_to_string(other_content)
+                )
+            }
+        }
+    }
+}
+
+/// Texte du contenu d'une case pour l'affichage d'une différence
+fn content_to_string(content: &CellContent) -> String {
+    match content {
+        CellContent::Undefined => "aucun chiffre".to_string(),
+        CellContent::Number(n) => format!("le chiffre {n}"),
+        CellContent::PossibleNumbers(simple_09_set) => format!("les chiffres {simple_09_set}"),
+    }
+}
+
+impl Grid {
+    /// Différences entre cette grille et une autre grille, triées par ligne puis colonne
+    ///
+    /// Comme pour l'égalité des grilles, les noms des zones ne comptent pas : chaque zone d'une
+    /// grille correspond à la zone de l'autre grille avec laquelle elle a le plus de cases en
+    /// commun, et une case est dans une zone différente si sa zone dans une grille ne correspond
+    /// pas à sa zone dans l'autre grille. Seules les cases déplacées d'une zone à une autre sont
+    /// donc signalées (et non toutes les cases des zones modifiées). Une case peut avoir à la fois
+    /// une zone et un contenu différents.
+    ///
+    /// Retourne une liste vide si les deux grilles sont égales
+    #[must_use]
+    pub fn diff(&self, other: &Grid) -> Vec<CellDiff> {
+        let set_line_column: BTreeSet<(i32, i32)> = self
+            .hashmap_cells
+            .keys()
+            .chain(other.hashmap_cells.keys())
+            .map(|line_column| (line_column.line, line_column.column))
+            .collect();
+
+        // Correspondance entre les zones des deux grilles, selon leurs cases communes
+        let mut vec_pairs = Vec::new();
+        for (line, column) in &set_line_column {
+            let line_column = LineColumn::new(*line, *column);
+            if let (Some(cell), Some(other_cell)) =
+                (self.get_cell(line_column), other.get_cell(line_column))
+            {
+                vec_pairs.push((cell.c_zone, other_cell.c_zone));
+            }
+        }
+        let zone_of = zone_correspondence(&vec_pairs);
+        let vec_reversed: Vec<(char, char)> = vec_pairs.iter().map(|(a, b)| (*b, *a)).collect();
+        let other_zone_of = zone_correspondence(&vec_reversed);
+
+        let mut vec_diff = Vec::new();
+        for (line, column) in set_line_column {
+            let line_column = LineColumn::new(line, column);
+            match (self.get_cell(line_column), other.get_cell(line_column)) {
+                (Some(_), None) => vec_diff.push(CellDiff::Missing(line_column)),
+                (None, Some(_)) => vec_diff.push(CellDiff::Added(line_column)),
+                (Some(cell), Some(other_cell)) => {
+                    if zone_of[&cell.c_zone] != other_cell.c_zone
+                        || other_zone_of[&other_cell.c_zone] != cell.c_zone
+                    {
+                        vec_diff.push(CellDiff::DifferentZone(
+                            line_column,
+                            self.zone_name(cell.c_zone),
+                            other.zone_name(other_cell.c_zone),
+                        ));
+                    }
+                    if cell.content != other_cell.content {
+                        vec_diff.push(CellDiff::DifferentContent(
+                            line_column,
+                            cell.content.clone(),
+                            other_cell.content.clone(),
+                        ));
+                    }
+                }
+                (None, None) => (),
+            }
+        }
+        vec_diff
+    }
+}
+
+/// Zone correspondant à chaque zone d'une grille, à partir des couples (zone dans la grille, zone
+/// dans l'autre grille) des cases communes aux deux grilles dans l'ordre de lecture : la zone de
+/// l'autre grille avec le plus de cases communes (la première rencontrée en cas d'égalité)
+fn zone_correspondence(vec_pairs: &[(char, char)]) -> HashMap<char, char> {
+    let mut hashmap_counts: HashMap<(char, char), usize> = HashMap::new();
+    for pair in vec_pairs {
+        *hashmap_counts.entry(*pair).or_default() += 1;
+    }
+    let mut hashmap_best: HashMap<char, (char, usize)> = HashMap::new();
+    for pair @ (c_zone, other_c_zone) in vec_pairs {
+        let count = hashmap_counts[pair];
+        let best = hashmap_best
+            .entry(*c_zone)
+            .or_insert((*other_c_zone, count));
+        if count > best.1 {
+            *best = (*other_c_zone, count);
+        }
+    }
+    hashmap_best
+        .into_iter()
+        .map(|(c_zone, (other_c_zone, _))| (c_zone, other_c_zone))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::solver::Solver;
+    use crate::solver_config::SolverConfig;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_diff() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert!(grid.diff(&grid).is_empty());
+        assert!(grid
+            .diff(&Grid::from_str("x1 a  a2\na4 a  a\nb  b  b2").unwrap())
+            .is_empty());
+
+        let other = Grid::from_str("a1 b  b3\nb4 b  c\nc  c  c2\nc").unwrap();
+        assert_eq!(
+            grid.diff(&other),
+            vec![
+                CellDiff::DifferentContent(
+                    LineColumn::new(0, 2),
+                    CellContent::Number(2),
+                    CellContent::Number(3)
+                ),
+                CellDiff::DifferentZone(LineColumn::new(1, 2), "b".to_string(), "c".to_string()),
+                CellDiff::Added(LineColumn::new(3, 0)),
+            ]
+        );
+        assert_eq!(
+            other.diff(&grid).last(),
+            Some(&CellDiff::Missing(LineColumn::new(3, 0)))
+        );
+
+        // Deux zones réunies : seules les cases de la plus petite zone changent de zone
+        let other = Grid::from_str("b1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(
+            grid.diff(&other),
+            vec![CellDiff::DifferentZone(
+                LineColumn::new(0, 0),
+                "a".to_string(),
+                "b".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_diff_solved_grid() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+
+        // Chiffres placés par la résolution
+        let vec_diff = grid.diff(&solver.to_grid());
+        assert_eq!(vec_diff.len(), 5);
+        assert!(vec_diff.iter().all(|diff| matches!(
+            diff,
+            CellDiff::DifferentContent(_, CellContent::Undefined, CellContent::Number(_))
+        )));
+        assert_eq!(vec_diff[0].line_column(), LineColumn::new(0, 1));
+    }
+}
@@ -0,0 +1,537 @@
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+
+/// Caractères d'une intersection de bordures selon le trait de chacune des 4 branches (0 : aucun
+/// trait, 1 : trait fin, 2 : trait épais). Le caractère d'indice `27 * haut + 9 * droite + 3 * bas
+/// + gauche` est celui de l'intersection
+const JUNCTIONS: &str =
+    " ╴╸╷┐┑╻┒┓╶─╾┌┬┭┎┰┱╺╼━┍┮┯┏┲┳╵┘┙│┤┥╽┧┪└┴┵├┼┽┟╁╅┕┶┷┝┾┿┢╆╈╹┚┛╿┦┩┃┨┫┖┸┹┞╀╃┠╂╉┗┺┻┡╄╇┣╊╋";
+
+/// Traits horizontaux et verticaux selon leur épaisseur (aucun, fin ou épais)
+const HORIZONTALS: [char; 3] = [' ', '─', '━'];
+const VERTICALS: [char; 3] = [' ', '│', '┃'];
+
+/// Marque ajoutée au contenu d'une case mise en évidence
+const HIGHLIGHT_MARK: char = '*';
+
+/// Bordures des cases dans l'affichage d'une grille
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// Aucune bordure : les cases sont séparées par des espaces
+    #[default]
+    None,
+
+    /// Caractères Unicode de dessin de cadres : traits fins entre les cases d'une même zone et
+    /// traits épais entre deux zones et autour de la grille
+    Box,
+
+    /// Motifs braille pour une imprimante braille ou une plage braille (voir `Grid::to_braille`) :
+    /// seuls les chiffres des cases et les bordures entre les zones sont dessinés
+    Braille,
+}
+
+/// Affichage des chiffres possibles d'une case en cours de résolution
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CandidatesStyle {
+    /// Chiffres possibles non affichés
+    Hidden,
+
+    /// Chiffres possibles sur une ligne (par exemple `[1, 5]`)
+    #[default]
+    Inline,
+
+    /// Chaque chiffre possible à sa place dans un carré de 3x3 (comme les annotations d'un
+    /// sudoku)
+    PencilMarks,
+}
+
+/// Options de l'affichage d'une grille sous forme de texte
+///
+/// `GridFormatter::default()` est l'affichage de `{grid}` (coordonnées, noms des zones et chiffres
+/// possibles sur une ligne) et `GridFormatter::boxed()` celui de `{grid:#}`. Les options se
+/// construisent par chaînage :
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use tectonic::{BorderStyle, CandidatesStyle, Grid, GridFormatter, LineColumn};
+///
+/// let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+/// let formatter = GridFormatter::new()
+///     .border_style(BorderStyle::Box)
+///     .candidates(CandidatesStyle::PencilMarks)
+///     .highlight([LineColumn::new(0, 0)]);
+/// println!("{}", formatter.format(&grid));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GridFormatter {
+    /// Numéros des lignes et des colonnes autour de la grille
+    pub(crate) coordinates: bool,
+
+    /// Nom de la zone de chaque case
+    pub(crate) zone_letters: bool,
+
+    /// Affichage des chiffres possibles
+    pub(crate) candidates: CandidatesStyle,
+
+    /// Bordures des cases
+    pub(crate) border_style: BorderStyle,
+
+    /// Cases mises en évidence (contenu suivi de `*`)
+    pub(crate) highlights: Vec<LineColumn>,
+}
+
+impl Default for GridFormatter {
+    fn default() -> Self {
+        GridFormatter {
+            coordinates: true,
+            zone_letters: true,
+            candidates: CandidatesStyle::Inline,
+            border_style: BorderStyle::None,
+            highlights: Vec::new(),
+        }
+    }
+}
+
+impl GridFormatter {
+    /// Options par défaut : affichage de `{grid}`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options de l'affichage de `{grid:#}` : bordures des zones dessinées, sans coordonnées ni
+    /// noms des zones
+    #[must_use]
+    pub fn boxed() -> Self {
+        Self::default()
+            .border_style(BorderStyle::Box)
+            .coordinates(false)
+            .zone_letters(false)
+    }
+
+    /// Numéros des lignes et des colonnes autour de la grille
+    #[must_use]
+    pub fn coordinates(mut self, coordinates: bool) -> Self {
+        self.coordinates = coordinates;
+        self
+    }
+
+    /// Nom de la zone de chaque case avant son contenu
+    #[must_use]
+    pub fn zone_letters(mut self, zone_letters: bool) -> Self {
+        self.zone_letters = zone_letters;
+        self
+    }
+
+    /// Affichage des chiffres possibles des cases en cours de résolution
+    #[must_use]
+    pub fn candidates(mut self, candidates: CandidatesStyle) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    /// Bordures des cases
+    #[must_use]
+    pub fn border_style(mut self, border_style: BorderStyle) -> Self {
+        self.border_style = border_style;
+        self
+    }
+
+    /// Cases mises en évidence (leur contenu est suivi de `*`)
+    #[must_use]
+    pub fn highlight(mut self, highlights: impl IntoIterator<Item = LineColumn>) -> Self {
+        self.highlights = highlights.into_iter().collect();
+        self
+    }
+
+    /// Texte de la grille avec ces options
+    #[must_use]
+    pub fn format(&self, grid: &Grid) -> String {
+        match self.border_style {
+            BorderStyle::None => self.format_plain(grid),
+            BorderStyle::Box => self.format_box(grid),
+            BorderStyle::Braille => grid.to_braille(),
+        }
+    }
+
+    /// Nombre de lignes de texte d'une case
+    fn cell_height(&self) -> usize {
+        if self.candidates == CandidatesStyle::PencilMarks {
+            3
+        } else {
+            1
+        }
+    }
+
+    /// Lignes de texte du contenu d'une case (éventuellement absente)
+    fn cell_rows(&self, grid: &Grid, line_column: LineColumn) -> Vec<String> {
+        let height = self.cell_height();
+        let mut rows = vec![String::new(); height];
+        let Some(cell) = grid.get_cell(line_column) else {
+            return rows;
+        };
+
+        let mut label = if self.zone_letters {
+            grid.zone_name(cell.c_zone)
+        } else {
+            String::new()
+        };
+        match (&cell.content, self.candidates) {
+            (CellContent::Number(n), _) => label.push_str(&n.to_string()),
+            (CellContent::PossibleNumbers(simple_09_set), CandidatesStyle::PencilMarks) => {
+                for (index, row) in rows.iter_mut().enumerate() {
+                    *row = (1..=3)
+                        .map(|n| {
+                            let n = u8::try_from(3 * index).unwrap() + n;
+                            if simple_09_set.contains(n) {
+                                n.to_string()
+                            } else {
+                                " ".to_string()
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                }
+                return rows;
+            }
+            (CellContent::PossibleNumbers(simple_09_set), CandidatesStyle::Inline) => {
+                label.push_str(&simple_09_set.to_string());
+            }
+            (CellContent::PossibleNumbers(_), CandidatesStyle::Hidden)
+            | (CellContent::Undefined, _) => (),
+        }
+        if self.highlights.contains(&line_column) {
+            label.push(HIGHLIGHT_MARK);
+        }
+        rows[height / 2] = label;
+        rows
+    }
+
+    /// Affichage sans bordure, les cases étant alignées en colonnes
+    fn format_plain(&self, grid: &Grid) -> String {
+        let (min, max) = (grid.min_line_column, grid.max_line_column);
+        let height = self.cell_height();
+        let contents: Vec<Vec<Vec<String>>> = (min.line..=max.line)
+            .map(|line| {
+                (min.column..=max.column)
+                    .map(|column| self.cell_rows(grid, LineColumn::new(line, column)))
+                    .collect()
+            })
+            .collect();
+
+        // Largeur d'une colonne : contenu le plus long (ou numéro de colonne le plus long) suivi
+        // d'un espace
+        let mut width = contents
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        if self.coordinates {
+            width = width.max(number_width(min.column, max.column));
+        }
+        width += 1;
+        let line_width = number_width(min.line, max.line);
+
+        let mut res = String::new();
+        if self.coordinates {
+            // La première ligne contient les numéros de colonnes
+            res.push_str(&" ".repeat(line_width + 1));
+            for column in min.column..=max.column {
+                res.push_str(&format!("{column:<width$}"));
+            }
+            end_line(&mut res);
+        }
+        for (line, line_contents) in (min.line..=max.line).zip(&contents) {
+            for index in 0..height {
+                if self.coordinates {
+                    // Numéro de ligne à gauche
+                    if index == height / 2 {
+                        res.push_str(&format!("{line:>line_width$} "));
+                    } else {
+                        res.push_str(&" ".repeat(line_width + 1));
+                    }
+                }
+                for rows in line_contents {
+                    res.push_str(&format!("{:width$}", rows[index]));
+                }
+                end_line(&mut res);
+            }
+        }
+        res
+    }
+
+    /// Affichage avec les caractères Unicode de dessin de cadres
+    fn format_box(&self, grid: &Grid) -> String {
+        let Some((min, max)) = grid.bounds() else {
+            return String::new();
+        };
+        let zone_of = |line: i32, column: i32| {
+            grid.get_cell(LineColumn::new(line, column))
+                .map(|cell| cell.c_zone)
+        };
+
+        // Épaisseur du trait entre deux cases (éventuellement absentes)
+        let weight = |zone_1: Option<char>, zone_2: Option<char>| match (zone_1, zone_2) {
+            (None, None) => 0,
+            _ if zone_1 == zone_2 => 1,
+            _ => 2,
+        };
+        // Traits au-dessus et à gauche d'une case
+        let top = |line: i32, column: i32| weight(zone_of(line - 1, column), zone_of(line, column));
+        let left =
+            |line: i32, column: i32| weight(zone_of(line, column - 1), zone_of(line, column));
+
+        let height = self.cell_height();
+        let width = grid
+            .cells()
+            .flat_map(|(line_column, _)| self.cell_rows(grid, *line_column))
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or_default()
+            .max(1)
+            .max(if self.coordinates {
+                number_width(min.column, max.column)
+            } else {
+                0
+            })
+            + 2;
+        let line_width = number_width(min.line, max.line);
+        let margin = if self.coordinates {
+            " ".repeat(line_width + 1)
+        } else {
+            String::new()
+        };
+
+        let mut res = String::new();
+        if self.coordinates {
+            // La première ligne contient les numéros de colonnes
+            res.push_str(&margin);
+            for column in min.column..=max.column {
+                res.push_str(&format!(" {column:^width$}"));
+            }
+            end_line(&mut res);
+        }
+        for line in min.line..=max.line + 1 {
+            // Bordures au-dessus de la ligne
+            res.push_str(&margin);
+            for column in min.column..=max.column + 1 {
+                let index = 27 * left(line - 1, column)
+                    + 9 * top(line, column)
+                    + 3 * left(line, column)
+                    + top(line, column - 1);
+                res.push(JUNCTIONS.chars().nth(index).unwrap());
+                if column <= max.column {
+                    let horizontal = HORIZONTALS[top(line, column)];
+                    res.extend(std::iter::repeat_n(horizontal, width));
+                }
+            }
+            end_line(&mut res);
+
+            // Contenu des cases de la ligne
+            if line <= max.line {
+                let contents: Vec<Vec<String>> = (min.column..=max.column)
+                    .map(|column| self.cell_rows(grid, LineColumn::new(line, column)))
+                    .collect();
+                for index in 0..height {
+                    if self.coordinates && index == height / 2 {
+                        res.push_str(&format!("{line:>line_width$} "));
+                    } else {
+                        res.push_str(&margin);
+                    }
+                    for column in min.column..=max.column + 1 {
+                        res.push(VERTICALS[left(line, column)]);
+                        if column <= max.column {
+                            let rows = &contents[usize::try_from(column - min.column).unwrap()];
+                            res.push_str(&format!("{:^width$}", rows[index]));
+                        }
+                    }
+                    end_line(&mut res);
+                }
+            }
+        }
+        res
+    }
+}
+
+impl Grid {
+    /// Affichage de la grille comme `{grid:#}` mais avec les chiffres possibles de chaque case en
+    /// cours de résolution à leur place dans un carré de 3x3 (comme les annotations d'un sudoku),
+    /// pour voir pourquoi une résolution est bloquée
+    #[must_use]
+    pub fn to_pencil_marks(&self) -> String {
+        GridFormatter::boxed()
+            .candidates(CandidatesStyle::PencilMarks)
+            .format(self)
+    }
+}
+
+/// Nombre de caractères du plus long des numéros de `min` à `max` (au moins 2)
+fn number_width(min: i32, max: i32) -> usize {
+    min.to_string().len().max(max.to_string().len()).max(2)
+}
+
+/// Termine une ligne de l'affichage sans les espaces à droite des cases absentes de la grille
+fn end_line(res: &mut String) {
+    res.truncate(res.trim_end_matches(' ').len());
+    res.push('\n');
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::simple_09_set::Simple09Set;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_box_drawing() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(
+            format!("{grid:#}"),
+            "\
+┏━━━┳━━━┯━━━┓
+┃ 1 ┃   │ 2 ┃
+┣━━━╃───┼───┨
+┃ 4 │   │   ┃
+┣━━━┿━━━┿━━━┫
+┃   │   │ 2 ┃
+┗━━━┷━━━┷━━━┛
+"
+        );
+
+        // Cases absentes de la grille
+        let grid = Grid::from_str("a1 b1\nc1").unwrap();
+        assert_eq!(
+            format!("{grid:#}"),
+            "\
+┏━━━┳━━━┓
+┃ 1 ┃ 1 ┃
+┣━━━╋━━━┛
+┃ 1 ┃
+┗━━━┛
+"
+        );
+        assert_eq!(format!("{:#}", Grid::default()), "");
+    }
+
+    #[test]
+    fn test_to_pencil_marks() {
+        let mut grid = Grid::from_str("a1 b2\nb  b").unwrap();
+        grid.get_mut_cell(LineColumn::new(1, 0)).unwrap().content =
+            CellContent::PossibleNumbers(Simple09Set::new(&[1, 3, 4]));
+        assert_eq!(
+            grid.to_pencil_marks(),
+            "\
+┏━━━━━━━┳━━━━━━━┓
+┃       ┃       ┃
+┃   1   ┃   2   ┃
+┃       ┃       ┃
+┣━━━━━━━╃───────┨
+┃ 1   3 │       ┃
+┃ 4     │       ┃
+┃       │       ┃
+┗━━━━━━━┷━━━━━━━┛
+"
+        );
+    }
+
+    #[test]
+    fn test_format_options() {
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert_eq!(GridFormatter::new().format(&grid), grid.to_string());
+        assert_eq!(
+            GridFormatter::new().format(&grid),
+            "   0  1  2\n 0 a1 b  b2\n 1 b4 b  b\n 2 c  c  c2\n"
+        );
+        assert_eq!(
+            GridFormatter::new()
+                .border_style(BorderStyle::Braille)
+                .format(&grid),
+            grid.to_braille()
+        );
+        assert_eq!(
+            GridFormatter::new()
+                .coordinates(false)
+                .zone_letters(false)
+                .highlight([LineColumn::new(0, 2)])
+                .format(&grid),
+            "1     2*\n4\n      2\n"
+        );
+
+        // Chiffres possibles
+        grid.get_mut_cell(LineColumn::new(0, 1)).unwrap().content =
+            CellContent::PossibleNumbers(Simple09Set::new(&[3, 5]));
+        let formatter = GridFormatter::new().coordinates(false);
+        assert!(formatter.format(&grid).starts_with("a1      b[3, 5] b2\n"));
+        assert!(formatter
+            .clone()
+            .candidates(CandidatesStyle::Hidden)
+            .format(&grid)
+            .starts_with("a1 b  b2\n"));
+
+        // Bordures avec les coordonnées et les noms des zones
+        let formatter = GridFormatter::boxed()
+            .coordinates(true)
+            .zone_letters(true)
+            .candidates(CandidatesStyle::Hidden);
+        assert_eq!(
+            formatter.format(&grid),
+            "     0    1    2
+   ┏━━━━┳━━━━┯━━━━┓
+ 0 ┃ a1 ┃ b  │ b2 ┃
+   ┣━━━━╃────┼────┨
+ 1 ┃ b4 │ b  │ b  ┃
+   ┣━━━━┿━━━━┿━━━━┫
+ 2 ┃ c  │ c  │ c2 ┃
+   ┗━━━━┷━━━━┷━━━━┛
+"
+        );
+    }
+
+    #[test]
+    fn test_format_alignment() {
+        // Numéros de lignes et de colonnes négatifs ou à plusieurs chiffres
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        grid.translate(-1, 98);
+        assert_eq!(
+            GridFormatter::new().format(&grid),
+            "   98  99  100\n-1 a1  b   b2\n 0 b4  b   b\n 1 c   c   c2\n"
+        );
+        assert_eq!(
+            GridFormatter::boxed().coordinates(true).format(&grid),
+            "     98    99    100
+   ┏━━━━━┳━━━━━┯━━━━━┓
+-1 ┃  1  ┃     │  2  ┃
+   ┣━━━━━╃─────┼─────┨
+ 0 ┃  4  │     │     ┃
+   ┣━━━━━┿━━━━━┿━━━━━┫
+ 1 ┃     │     │  2  ┃
+   ┗━━━━━┷━━━━━┷━━━━━┛
+"
+        );
+
+        // Numéros de lignes à 3 chiffres
+        let mut grid = Grid::from_str("a1\nb1").unwrap();
+        grid.translate(99, 10);
+        assert_eq!(
+            GridFormatter::boxed().coordinates(true).format(&grid),
+            "      10
+    ┏━━━━┓
+ 99 ┃ 1  ┃
+    ┣━━━━┫
+100 ┃ 1  ┃
+    ┗━━━━┛
+"
+        );
+
+        // Grille de plus de 10 colonnes
+        let grid = Grid::from_str(&"a1".repeat(12)).unwrap();
+        let text = GridFormatter::new().format(&grid);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0].find("11"), lines[1].rfind("a1"));
+        assert_eq!(lines[0].find("10"), Some(33));
+    }
+}
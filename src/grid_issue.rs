@@ -0,0 +1,407 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::neighboring_line_columns::NeighboringLineColumns;
+
+/// Problème de structure d'une grille détecté par `Grid::validate`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridIssue {
+    /// Zone avec trop de cases (nom de la zone, nombre de cases de la zone)
+    ZoneTooLong(String, usize),
+
+    /// Case avec un chiffre hors de 1..=taille de la zone (nom de la zone, case, chiffre)
+    ClueOutOfRange(String, LineColumn, u8),
+
+    /// Deux cases d'une même zone avec le même chiffre (nom de la zone, première case, seconde
+    /// case, chiffre)
+    /// Les 2 cases sont triées par ligne puis colonne
+    ZoneWithSameNumber(String, LineColumn, LineColumn, u8),
+
+    /// Deux cases voisines avec le même chiffre (case, case voisine, chiffre)
+    /// Les 2 cases sont triées par ligne puis colonne
+    NeighboringWithSameNumber(LineColumn, LineColumn, u8),
+
+    /// Zone dont les cases ne sont pas toutes reliées entre elles horizontalement ou
+    /// verticalement (nom de la zone)
+    ZoneNotContiguous(String),
+
+    /// Position sans case entièrement entourée par des cases de la grille (position)
+    Hole(LineColumn),
+}
+
+impl fmt::Display for GridIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridIssue::ZoneTooLong(zone_name, zone_len) => {
+                write!(
+                    f,
+                    "La zone '{zone_name}' est trop grande ({zone_len} cases)"
+                )
+            }
+            GridIssue::ClueOutOfRange(zone_name, line_column, n) => {
+                write!(
+                    f,
+                    "Le chiffre '{n}' en {line_column} n'est pas possible dans la zone '{zone_name}'"
+                )
+            }
+            GridIssue::ZoneWithSameNumber(zone_name, line_column_1, line_column_2, n) => {
+                write!(
+                    f,
+                    "Le chiffre '{n}' apparaît plusieurs fois dans la zone '{zone_name}' (cases {line_column_1} et {line_column_2})"
+                )
+            }
+            GridIssue::NeighboringWithSameNumber(line_column_1, line_column_2, n) => {
+                write!(
+                    f,
+                    "Le chiffre {n} apparaît dans les cases voisines {line_column_1} et {line_column_2}"
+                )
+            }
+            GridIssue::ZoneNotContiguous(zone_name) => {
+                write!(f, "La zone '{zone_name}' n'est pas d'un seul tenant")
+            }
+            GridIssue::Hole(line_column) => {
+                write!(f, "Aucune case en {line_column} au milieu de la grille")
+            }
+        }
+    }
+}
+
+/// Définition suspecte d'une grille détectée par `Grid::lints` : la grille est correcte mais
+/// contient probablement une erreur de saisie
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridLint {
+    /// Zone d'une seule case dont le chiffre 1 n'est pas indiqué (nom de la zone, case)
+    SingleCellZoneWithoutClue(String, LineColumn),
+
+    /// Ligne avec beaucoup moins de cases que la ligne la plus longue (ligne, nombre de cases de
+    /// la ligne, nombre de cases de la ligne la plus longue)
+    ShortLine(i32, usize, usize),
+
+    /// Case isolée loin des autres cases de sa zone : aucune case voisine n'est dans la même zone
+    /// (nom de la zone, case)
+    IsolatedZoneCell(String, LineColumn),
+}
+
+impl fmt::Display for GridLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridLint::SingleCellZoneWithoutClue(zone_name, line_column) => {
+                write!(
+                    f,
+                    "La zone '{zone_name}' n'a qu'une case {line_column} sans le chiffre 1"
+                )
+            }
+            GridLint::ShortLine(line, line_len, max_line_len) => {
+                write!(
+                    f,
+                    "La ligne {line} n'a que {line_len} cases (au lieu de {max_line_len})"
+                )
+            }
+            GridLint::IsolatedZoneCell(zone_name, line_column) => {
+                write!(
+                    f,
+                    "La case {line_column} est isolée des autres cases de la zone '{zone_name}'"
+                )
+            }
+        }
+    }
+}
+
+impl Grid {
+    /// Liste des définitions suspectes de la grille qui sont probablement des erreurs de saisie
+    /// (liste vide si rien n'est suspect) : zone d'une seule case sans chiffre, ligne beaucoup plus
+    /// courte que les autres (moins de la moitié des cases de la ligne la plus longue) et case
+    /// isolée loin des autres cases de sa zone
+    ///
+    /// Ces avertissements ne bloquent pas la résolution (voir `validate` pour les problèmes qui la
+    /// rendent impossible)
+    #[must_use]
+    pub fn lints(&self) -> Vec<GridLint> {
+        let mut vec_lint = Vec::new();
+
+        for (c_zone, zone) in self.zones() {
+            if zone.size() == 1 {
+                let line_column = zone.cells().next().unwrap();
+                let content = self.get_cell(line_column).map(|cell| &cell.content);
+                if !content.is_some_and(CellContent::is_number) {
+                    vec_lint.push(GridLint::SingleCellZoneWithoutClue(
+                        self.zone_name(*c_zone),
+                        line_column,
+                    ));
+                }
+            }
+        }
+
+        let mut hashmap_line_len: HashMap<i32, usize> = HashMap::new();
+        for line_column in self.hashmap_cells.keys() {
+            *hashmap_line_len.entry(line_column.line).or_default() += 1;
+        }
+        let max_line_len = hashmap_line_len.values().copied().max().unwrap_or(0);
+        let mut vec_line: Vec<(i32, usize)> = hashmap_line_len.into_iter().collect();
+        vec_line.sort_unstable();
+        for (line, line_len) in vec_line {
+            if line_len * 2 < max_line_len {
+                vec_lint.push(GridLint::ShortLine(line, line_len, max_line_len));
+            }
+        }
+
+        for (c_zone, zone) in self.zones() {
+            if zone.size() < 2 {
+                continue;
+            }
+            for line_column in zone.cells() {
+                let mut neighbors = NeighboringLineColumns::new(
+                    line_column,
+                    self.min_line_column,
+                    self.max_line_column,
+                );
+                if !neighbors.any(|neighbor| zone.contains(neighbor)) {
+                    vec_lint.push(GridLint::IsolatedZoneCell(
+                        self.zone_name(*c_zone),
+                        line_column,
+                    ));
+                }
+            }
+        }
+
+        vec_lint
+    }
+
+    /// Liste de tous les problèmes de structure de la grille (liste vide si la grille est
+    /// correcte) : zones trop grandes, chiffres impossibles dans leur zone, chiffres répétés dans
+    /// une zone ou dans des cases voisines, zones qui ne sont pas d'un seul tenant et trous au
+    /// milieu de la grille
+    ///
+    /// Contrairement aux vérifications faites par le solver qui s'arrêtent à la première erreur,
+    /// tous les problèmes sont retournés, regroupés par type dans l'ordre des variantes de
+    /// `GridIssue` puis triés par zone et par case
+    #[must_use]
+    pub fn validate(&self) -> Vec<GridIssue> {
+        let mut vec_issue = Vec::new();
+        let mut vec_out_of_range = Vec::new();
+        let mut vec_same_number = Vec::new();
+        let mut vec_not_contiguous = Vec::new();
+
+        for (c_zone, zone) in self.zones() {
+            let name = self.zone_name(*c_zone);
+            let zone_len = zone.size();
+            if zone_len > 9 {
+                vec_issue.push(GridIssue::ZoneTooLong(name.clone(), zone_len));
+            }
+            if !self.is_zone_contiguous(*c_zone) {
+                vec_not_contiguous.push(GridIssue::ZoneNotContiguous(name.clone()));
+            }
+
+            let mut hashmap_numbers: HashMap<u8, LineColumn> = HashMap::new();
+            for line_column in zone.cells() {
+                let Some(CellContent::Number(n)) = self.get_cell(line_column).map(|c| &c.content)
+                else {
+                    continue;
+                };
+                if *n == 0 || usize::from(*n) > zone_len.min(9) {
+                    vec_out_of_range.push(GridIssue::ClueOutOfRange(name.clone(), line_column, *n));
+                }
+                match hashmap_numbers.get(n) {
+                    Some(first_line_column) => vec_same_number.push(GridIssue::ZoneWithSameNumber(
+                        name.clone(),
+                        *first_line_column,
+                        line_column,
+                        *n,
+                    )),
+                    None => {
+                        hashmap_numbers.insert(*n, line_column);
+                    }
+                }
+            }
+        }
+
+        vec_issue.extend(vec_out_of_range);
+        vec_issue.extend(vec_same_number);
+        vec_issue.extend(self.neighboring_same_number_issues());
+        vec_issue.extend(vec_not_contiguous);
+        vec_issue.extend(self.hole_issues());
+        vec_issue
+    }
+
+    /// Cases voisines avec le même chiffre (chaque paire de cases n'est signalée qu'une fois)
+    fn neighboring_same_number_issues(&self) -> Vec<GridIssue> {
+        let mut vec_issue = Vec::new();
+        for (line_column, cell) in self.cells() {
+            let CellContent::Number(n) = cell.content else {
+                continue;
+            };
+            let mut vec_neighbor: Vec<LineColumn> = NeighboringLineColumns::new(
+                *line_column,
+                self.min_line_column,
+                self.max_line_column,
+            )
+            .filter(|neighbor| {
+                (neighbor.line, neighbor.column) > (line_column.line, line_column.column)
+            })
+            .filter(|neighbor| {
+                self.get_cell(*neighbor)
+                    .is_some_and(|cell| cell.content == CellContent::Number(n))
+            })
+            .collect();
+            vec_neighbor.sort_by_key(|neighbor| (neighbor.line, neighbor.column));
+            for neighbor in vec_neighbor {
+                vec_issue.push(GridIssue::NeighboringWithSameNumber(
+                    *line_column,
+                    neighbor,
+                    n,
+                ));
+            }
+        }
+        vec_issue
+    }
+
+    /// Positions sans case qui ne sont pas reliées au bord de la grille par d'autres positions
+    /// sans case : une grille irrégulière (en forme de L par exemple) n'a pas de trou
+    fn hole_issues(&self) -> Vec<GridIssue> {
+        let Some((min, max)) = self.bounds() else {
+            return Vec::new();
+        };
+
+        // Positions sans case
+        let mut set_empty: HashSet<LineColumn> = HashSet::new();
+        for line in min.line..=max.line {
+            for column in min.column..=max.column {
+                let line_column = LineColumn::new(line, column);
+                if self.get_cell(line_column).is_none() {
+                    set_empty.insert(line_column);
+                }
+            }
+        }
+
+        // Remplissage depuis les positions sans case du bord de la grille
+        let mut stack: Vec<LineColumn> = set_empty
+            .iter()
+            .filter(|lc| {
+                lc.line == min.line
+                    || lc.line == max.line
+                    || lc.column == min.column
+                    || lc.column == max.column
+            })
+            .copied()
+            .collect();
+        let mut visited: HashSet<LineColumn> = stack.iter().copied().collect();
+        while let Some(line_column) = stack.pop() {
+            for delta in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let neighbor = line_column + LineColumn::new(delta.0, delta.1);
+                if set_empty.contains(&neighbor) && visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        let mut vec_hole: Vec<LineColumn> = set_empty.difference(&visited).copied().collect();
+        vec_hole.sort_by_key(|line_column| (line_column.line, line_column.column));
+        vec_hole.into_iter().map(GridIssue::Hole).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_validate_ok() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        assert!(grid.validate().is_empty());
+
+        // Une grille irrégulière n'a pas de trou
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c\n").unwrap();
+        assert!(grid.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_all_issues() {
+        let mut grid = Grid::from_str(
+            "
+        a1 b  b2
+        b3 b2 a
+        c  c  c6
+        c  c  c3
+        ",
+        )
+        .unwrap();
+        let _ = grid.remove_cell(LineColumn::new(2, 1));
+
+        assert_eq!(
+            grid.validate(),
+            vec![
+                GridIssue::ClueOutOfRange("c".to_string(), LineColumn::new(2, 2), 6),
+                GridIssue::ZoneWithSameNumber(
+                    "b".to_string(),
+                    LineColumn::new(0, 2),
+                    LineColumn::new(1, 1),
+                    2
+                ),
+                GridIssue::NeighboringWithSameNumber(
+                    LineColumn::new(0, 2),
+                    LineColumn::new(1, 1),
+                    2
+                ),
+                GridIssue::ZoneNotContiguous("a".to_string()),
+                GridIssue::Hole(LineColumn::new(2, 1)),
+            ]
+        );
+
+        let mut grid = Grid::default();
+        grid.add_line(0, vec![('a', None); 10]);
+        assert_eq!(
+            grid.validate(),
+            vec![GridIssue::ZoneTooLong("a".to_string(), 10)]
+        );
+
+        // Zone désignée par plusieurs lettres
+        let grid = Grid::from_str("aa1 ab  aa").unwrap();
+        assert_eq!(
+            grid.validate()[0].to_string(),
+            "La zone 'aa' n'est pas d'un seul tenant"
+        );
+    }
+
+    #[test]
+    fn test_lints() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        assert!(grid.lints().is_empty());
+
+        let grid = Grid::from_str(
+            "
+        a  b  b2  c  c
+        b4 b  b   c  c
+        d  d  d2  d  b
+        e
+        ",
+        )
+        .unwrap();
+        assert_eq!(
+            grid.lints(),
+            vec![
+                GridLint::SingleCellZoneWithoutClue("a".to_string(), LineColumn::new(0, 0)),
+                GridLint::SingleCellZoneWithoutClue("e".to_string(), LineColumn::new(3, 0)),
+                GridLint::ShortLine(3, 1, 5),
+                GridLint::IsolatedZoneCell("b".to_string(), LineColumn::new(2, 4)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::Write;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::zone_border::CellSide;
+
+/// Feuille de style de la table d'une grille : bordures fines entre les cases, classes `t`, `r`,
+/// `b` et `l` pour les bordures épaisses en haut, à droite, en bas et à gauche d'une case
+const STYLE: &str = "\
+table.tectonic{border-collapse:collapse;font-family:sans-serif}\
+table.tectonic td{width:2.5em;height:2.5em;padding:0;text-align:center;vertical-align:middle;\
+font-size:1.5em;border:1px solid #999999}\
+table.tectonic td.t{border-top-width:3px;border-top-color:#000000}\
+table.tectonic td.r{border-right-width:3px;border-right-color:#000000}\
+table.tectonic td.b{border-bottom-width:3px;border-bottom-color:#000000}\
+table.tectonic td.l{border-left-width:3px;border-left-color:#000000}\
+table.tectonic td.hole{border:none}\
+table.tectonic td.given{font-weight:bold}\
+table.tectonic td.placed{color:#2f5fb3}\
+table.tectonic .candidates{display:grid;grid-template-columns:repeat(3,1fr);font-size:0.4em;\
+color:#777777}";
+
+/// Options de l'export HTML d'une grille avec `Grid::to_html`
+///
+/// Les options se construisent par chaînage :
+///
+/// ```rust
+/// use tectonic::HtmlOptions;
+///
+/// let options = HtmlOptions::new().candidates(true).style(false);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// Affichage des chiffres possibles des cases en cours de résolution
+    pub(crate) candidates: bool,
+
+    /// Feuille de style incluse avant la table
+    pub(crate) style: bool,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        HtmlOptions {
+            candidates: false,
+            style: true,
+        }
+    }
+}
+
+impl HtmlOptions {
+    /// Options par défaut : feuille de style incluse, sans les chiffres possibles
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Affichage des chiffres possibles d'une case en cours de résolution (en petit, chaque
+    /// chiffre à sa place dans un carré de 3x3)
+    #[must_use]
+    pub fn candidates(mut self, candidates: bool) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    /// Feuille de style `<style>` incluse avant la table (sans elle, la page qui contient la
+    /// grille doit définir le style des classes de la table `tectonic`)
+    #[must_use]
+    pub fn style(mut self, style: bool) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Grid {
+    /// Table HTML autonome de la grille (pour un blog, une lettre d'information, etc.) : une case
+    /// par cellule `<td>`, avec les classes des bordures épaisses entre deux zones et autour de la
+    /// grille, ainsi que les classes `given` (chiffre connu au départ), `placed` (chiffre placé
+    /// pendant la résolution) et `hole` (case absente de la grille)
+    #[must_use]
+    pub fn to_html(&self, options: &HtmlOptions) -> String {
+        let mut res = String::new();
+        if options.style {
+            writeln!(res, "<style>{STYLE}</style>").unwrap();
+        }
+        res.push_str("<table class=\"tectonic\">\n");
+
+        if let Some((min, max)) = self.bounds() {
+            for line in min.line..=max.line {
+                res.push_str("<tr>");
+                for column in min.column..=max.column {
+                    let line_column = LineColumn::new(line, column);
+                    let Some(cell) = self.get_cell(line_column) else {
+                        res.push_str("<td class=\"hole\"></td>");
+                        continue;
+                    };
+
+                    // Bordures épaisses si la case voisine est dans une autre zone (ou absente)
+                    let mut classes: Vec<&str> = [
+                        ("t", CellSide::Top),
+                        ("r", CellSide::Right),
+                        ("b", CellSide::Bottom),
+                        ("l", CellSide::Left),
+                    ]
+                    .into_iter()
+                    .filter(|(_, side)| self.is_zone_border(line_column, *side))
+                    .map(|(class, _)| class)
+                    .collect();
+
+                    let content = match &cell.content {
+                        CellContent::Number(n) => {
+                            classes.push(if cell.is_given { "given" } else { "placed" });
+                            n.to_string()
+                        }
+                        CellContent::PossibleNumbers(simple_09_set) if options.candidates => {
+                            let numbers = simple_09_set.as_vec_u8();
+                            let spans: String = (1..=9)
+                                .map(|n| {
+                                    if numbers.contains(&n) {
+                                        format!("<span>{n}</span>")
+                                    } else {
+                                        "<span></span>".to_string()
+                                    }
+                                })
+                                .collect();
+                            format!("<div class=\"candidates\">{spans}</div>")
+                        }
+                        CellContent::Undefined | CellContent::PossibleNumbers(_) => String::new(),
+                    };
+                    write!(res, "<td class=\"{}\">{content}</td>", classes.join(" ")).unwrap();
+                }
+                res.push_str("</tr>\n");
+            }
+        }
+
+        res.push_str("</table>\n");
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::simple_09_set::Simple09Set;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_html() {
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let html = grid.to_html(&HtmlOptions::default());
+        assert!(html.starts_with("<style>"));
+        assert!(html.ends_with("</table>\n"));
+        assert_eq!(html.matches("<tr>").count(), 3);
+        assert_eq!(html.matches("<td ").count(), 9);
+        assert!(html.contains("<tr><td class=\"t r b l given\">1</td><td class=\"t l\"></td>"));
+        assert_eq!(html.matches("given").count(), 4 + 1);
+
+        // Chiffre placé et chiffres possibles
+        grid.get_mut_cell(LineColumn::new(0, 1)).unwrap().content = CellContent::Number(3);
+        grid.get_mut_cell(LineColumn::new(1, 1)).unwrap().content =
+            CellContent::PossibleNumbers(Simple09Set::new(&[1, 5]));
+        let html = grid.to_html(&HtmlOptions::new().style(false));
+        assert!(html.starts_with("<table "));
+        assert!(html.contains("<td class=\"t l placed\">3</td>"));
+        assert!(!html.contains("candidates"));
+        let html = grid.to_html(&HtmlOptions::new().style(false).candidates(true));
+        assert!(html.contains(
+            "<div class=\"candidates\"><span>1</span><span></span><span></span><span></span>\
+             <span>5</span><span></span><span></span><span></span><span></span></div>"
+        ));
+
+        // Case absente de la grille
+        let grid = Grid::from_str("a1 b1\nc1").unwrap();
+        let html = grid.to_html(&HtmlOptions::new().style(false));
+        assert!(html.contains("<td class=\"hole\"></td></tr>"));
+    }
+}
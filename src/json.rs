@@ -0,0 +1,389 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::puzzle::{Puzzle, PuzzleMetadata};
+
+/// Nom du format JSON d'échange d'une grille (champ `format`)
+const FORMAT_NAME: &str = "tectonic-puzzle";
+
+/// Version du format JSON d'échange d'une grille écrite par `Puzzle::to_json` (champ `version`)
+pub const PUZZLE_JSON_VERSION: u32 = 1;
+
+/// Schéma JSON (draft 2020-12) du format JSON d'échange d'une grille
+pub const PUZZLE_JSON_SCHEMA: &str = include_str!("../schema/puzzle-v1.schema.json");
+
+/// Erreur rencontrée lors de la lecture d'une grille au format JSON d'échange
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PuzzleJsonError {
+    /// Le texte n'est pas du JSON ou n'a pas la structure du format (message de l'analyseur)
+    Syntax(String),
+
+    /// La version du format n'est pas connue (version plus récente que celle de la crate par
+    /// exemple)
+    UnsupportedVersion(u32),
+
+    /// Le contenu de la grille est incohérent (case hors de la grille, case dans deux zones,
+    /// etc.)
+    InvalidPuzzle(String),
+}
+
+impl fmt::Display for PuzzleJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzleJsonError::Syntax(message) => write!(f, "JSON incorrect : {message}"),
+            PuzzleJsonError::UnsupportedVersion(version) => write!(
+                f,
+                "Version {version} du format inconnue (version {PUZZLE_JSON_VERSION} attendue)"
+            ),
+            PuzzleJsonError::InvalidPuzzle(message) => write!(f, "Grille incorrecte : {message}"),
+        }
+    }
+}
+
+impl Error for PuzzleJsonError {}
+
+/// Document JSON d'échange d'une grille (voir `PUZZLE_JSON_SCHEMA`)
+#[derive(Serialize, Deserialize)]
+struct PuzzleDocument {
+    format: String,
+    version: u32,
+    width: usize,
+    height: usize,
+    zones: Vec<ZoneDocument>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    givens: Vec<GivenDocument>,
+    #[serde(default, skip_serializing_if = "MetadataDocument::is_empty")]
+    metadata: MetadataDocument,
+}
+
+/// Zone d'une grille : nom et position [ligne, colonne] de chaque case
+#[derive(Serialize, Deserialize)]
+struct ZoneDocument {
+    name: String,
+    cells: Vec<[usize; 2]>,
+}
+
+/// Chiffre connu d'une case
+#[derive(Serialize, Deserialize)]
+struct GivenDocument {
+    cell: [usize; 2],
+    value: u8,
+}
+
+/// Informations sur une grille (voir `PuzzleMetadata`)
+#[derive(Default, Serialize, Deserialize)]
+struct MetadataDocument {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    difficulty: Option<u8>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    extra: BTreeMap<String, String>,
+}
+
+impl MetadataDocument {
+    fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.author.is_none()
+            && self.source.is_none()
+            && self.difficulty.is_none()
+            && self.extra.is_empty()
+    }
+}
+
+impl Puzzle {
+    /// Texte JSON d'échange de la grille et de ses informations (voir `PUZZLE_JSON_SCHEMA`)
+    ///
+    /// ```json
+    /// {
+    ///   "format": "tectonic-puzzle",
+    ///   "version": 1,
+    ///   "width": 2,
+    ///   "height": 1,
+    ///   "zones": [{ "name": "a", "cells": [[0, 0], [0, 1]] }],
+    ///   "givens": [{ "cell": [0, 0], "value": 1 }],
+    ///   "metadata": { "title": "Exemple" }
+    /// }
+    /// ```
+    ///
+    /// Le texte est canonique : la grille est d'abord mise sous sa forme `Grid::normalized` puis
+    /// les zones, les cases et les chiffres sont écrits dans l'ordre de lecture. Tous les
+    /// chiffres connus des cases sont écrits (`givens`), les chiffres possibles d'une case en
+    /// cours de résolution ne sont pas conservés.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let grid = self.grid.normalized();
+        let mut vec_cells: Vec<(&LineColumn, char, &CellContent)> = grid
+            .cells()
+            .map(|(line_column, cell)| (line_column, cell.c_zone, &cell.content))
+            .collect();
+        vec_cells.sort_by_key(|(line_column, _, _)| (line_column.line, line_column.column));
+
+        let mut zones: Vec<ZoneDocument> = Vec::new();
+        let mut hashmap_zones: HashMap<char, usize> = HashMap::new();
+        let mut givens = Vec::new();
+        for (line_column, c_zone, content) in vec_cells {
+            // Positions à partir de (0, 0) dans une grille normalisée
+            let cell = [
+                usize::try_from(line_column.line).unwrap(),
+                usize::try_from(line_column.column).unwrap(),
+            ];
+            let index = *hashmap_zones.entry(c_zone).or_insert_with(|| {
+                zones.push(ZoneDocument {
+                    name: grid.zone_name(c_zone),
+                    cells: Vec::new(),
+                });
+                zones.len() - 1
+            });
+            zones[index].cells.push(cell);
+            if let CellContent::Number(value) = content {
+                givens.push(GivenDocument {
+                    cell,
+                    value: *value,
+                });
+            }
+        }
+
+        let metadata = &self.metadata;
+        let document = PuzzleDocument {
+            format: FORMAT_NAME.to_string(),
+            version: PUZZLE_JSON_VERSION,
+            width: grid.width(),
+            height: grid.height(),
+            zones,
+            givens,
+            metadata: MetadataDocument {
+                title: metadata.title.clone(),
+                author: metadata.author.clone(),
+                source: metadata.source.clone(),
+                difficulty: metadata.difficulty,
+                extra: metadata.extra.clone(),
+            },
+        };
+        serde_json::to_string_pretty(&document).unwrap()
+    }
+
+    /// Lecture d'une grille et de ses informations au format JSON d'échange (voir `to_json`)
+    ///
+    /// # Errors
+    /// Une erreur est retournée si le texte n'a pas la structure du format, si sa version n'est
+    /// pas connue ou si la grille est incohérente
+    pub fn from_json(s: &str) -> Result<Puzzle, PuzzleJsonError> {
+        let document: PuzzleDocument =
+            serde_json::from_str(s).map_err(|e| PuzzleJsonError::Syntax(e.to_string()))?;
+        let invalid = |message: String| Err(PuzzleJsonError::InvalidPuzzle(message));
+        if document.format != FORMAT_NAME {
+            return invalid(format!("format '{}' inconnu", document.format));
+        }
+        if document.version != PUZZLE_JSON_VERSION {
+            return Err(PuzzleJsonError::UnsupportedVersion(document.version));
+        }
+        if document.zones.is_empty() {
+            return invalid("aucune zone".to_string());
+        }
+        // Les positions des cases de la grille doivent être des `i32`
+        let max_size = usize::try_from(i32::MAX).unwrap();
+        if document.width > max_size || document.height > max_size {
+            return invalid(format!(
+                "dimensions {}x{} trop grandes",
+                document.width, document.height
+            ));
+        }
+
+        let mut grid = Grid::default();
+        let mut hashmap_givens: HashMap<[usize; 2], u8> = HashMap::new();
+        for given in &document.givens {
+            let [line, column] = given.cell;
+            if hashmap_givens.insert(given.cell, given.value).is_some() {
+                return invalid(format!(
+                    "plusieurs chiffres pour la case [{line}, {column}]"
+                ));
+            }
+        }
+        let mut set_cells: HashSet<[usize; 2]> = HashSet::new();
+        let mut set_names: HashSet<&str> = HashSet::new();
+        for zone in &document.zones {
+            if !Grid::is_valid_zone_name(&zone.name) || !set_names.insert(&zone.name) {
+                return invalid(format!("nom de zone '{}' incorrect ou répété", zone.name));
+            }
+            if zone.cells.is_empty() {
+                return invalid(format!("la zone '{}' n'a aucune case", zone.name));
+            }
+            let c_zone = match grid.zone_id(&zone.name) {
+                Ok(c_zone) => c_zone,
+                Err(e) => return invalid(e.to_string()),
+            };
+            for &zone_cell in &zone.cells {
+                let [line, column] = zone_cell;
+                if line >= document.height || column >= document.width {
+                    return invalid(format!("case [{line}, {column}] hors de la grille"));
+                }
+                if !set_cells.insert(zone_cell) {
+                    return invalid(format!("case [{line}, {column}] dans plusieurs zones"));
+                }
+                let (Ok(line), Ok(column)) = (i32::try_from(line), i32::try_from(column)) else {
+                    return invalid(format!("case [{line}, {column}] hors de la grille"));
+                };
+                let tuple_line_column = (line, column);
+                let content = hashmap_givens.get(&zone_cell).copied();
+                grid.add_cell(tuple_line_column, c_zone, content);
+            }
+        }
+        for given in &document.givens {
+            let [line, column] = given.cell;
+            if !set_cells.contains(&given.cell) {
+                return invalid(format!(
+                    "chiffre de la case [{line}, {column}] hors des zones"
+                ));
+            }
+            if !(1..=9).contains(&given.value) {
+                return invalid(format!(
+                    "chiffre {} de la case [{line}, {column}] hors de 1 à 9",
+                    given.value
+                ));
+            }
+        }
+
+        let metadata = document.metadata;
+        Ok(Puzzle {
+            grid,
+            metadata: PuzzleMetadata {
+                title: metadata.title,
+                author: metadata.author,
+                source: metadata.source,
+                difficulty: metadata.difficulty,
+                extra: metadata.extra,
+            },
+        })
+    }
+}
+
+impl Grid {
+    /// Texte JSON d'échange de la grille sans informations (voir `Puzzle::to_json`)
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        Puzzle {
+            grid: self.clone(),
+            metadata: PuzzleMetadata::default(),
+        }
+        .to_json()
+    }
+
+    /// Lecture d'une grille au format JSON d'échange (voir `Puzzle::from_json`), sans ses
+    /// informations
+    ///
+    /// # Errors
+    /// Une erreur est retournée si le texte n'a pas la structure du format, si sa version n'est
+    /// pas connue ou si la grille est incohérente
+    pub fn from_json(s: &str) -> Result<Grid, PuzzleJsonError> {
+        Puzzle::from_json(s).map(|puzzle| puzzle.grid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_json() {
+        let puzzle = Puzzle::from_str(
+            "
+        #! title: Exemple
+        #! difficulty: 2
+        #! date: 2024-01-01
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        let json = puzzle.to_json();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["format"], "tectonic-puzzle");
+        assert_eq!(value["version"], 1);
+        assert_eq!(
+            (value["width"].as_u64(), value["height"].as_u64()),
+            (Some(3), Some(3))
+        );
+        assert_eq!(value["zones"][0]["cells"], serde_json::json!([[0, 0]]));
+        assert_eq!(value["zones"][1]["name"], "b");
+        assert_eq!(value["zones"][1]["cells"].as_array().unwrap().len(), 5);
+        assert_eq!(
+            value["givens"][1],
+            serde_json::json!({"cell": [0, 2], "value": 2})
+        );
+        assert_eq!(value["metadata"]["title"], "Exemple");
+        assert_eq!(value["metadata"]["extra"]["date"], "2024-01-01");
+        assert!(value["metadata"].get("author").is_none());
+
+        assert_eq!(Puzzle::from_json(&json).unwrap(), puzzle);
+
+        // Texte canonique quels que soient les noms des zones et la position de la grille
+        let mut grid = Grid::from_str("z1 y  y2\ny4 y  y\nx  x  x2").unwrap();
+        grid.translate(2, 3);
+        assert_eq!(grid.to_json(), puzzle.grid.to_json());
+        assert!(!grid.to_json().contains("metadata"));
+        assert_eq!(Grid::from_json(&grid.to_json()).unwrap(), grid.normalized());
+
+        // Schéma du format
+        let schema: serde_json::Value = serde_json::from_str(PUZZLE_JSON_SCHEMA).unwrap();
+        assert_eq!(
+            schema["properties"]["version"]["const"],
+            PUZZLE_JSON_VERSION
+        );
+    }
+
+    #[test]
+    fn test_json_nok() {
+        let header = r#""format": "tectonic-puzzle", "width": 2, "height": 1"#;
+        let parse = |body: &str| Grid::from_json(&format!("{{{header}, {body}}}")).unwrap_err();
+
+        assert!(matches!(
+            Grid::from_json("{").unwrap_err(),
+            PuzzleJsonError::Syntax(_)
+        ));
+        assert_eq!(
+            parse(r#""version": 2, "zones": []"#),
+            PuzzleJsonError::UnsupportedVersion(2)
+        );
+        let zone = r#""version": 1, "zones": [{"name": "a", "cells": [[0, 0], [0, 1]]}]"#;
+        assert!(Grid::from_json(&format!("{{{header}, {zone}}}")).is_ok());
+        for body in [
+            r#""version": 1, "zones": []"#,
+            r#""version": 1, "zones": [{"name": "a", "cells": [[0, 2]]}]"#,
+            r#""version": 1, "zones": [{"name": "a1", "cells": [[0, 0]]}]"#,
+            r#""version": 1, "zones": [{"name": "a", "cells": [[0, 0]]}, {"name": "b", "cells": [[0, 0]]}]"#,
+            &format!(r#"{zone}, "givens": [{{"cell": [0, 0], "value": 0}}]"#),
+            r#""version": 1, "zones": [{"name": "a", "cells": [[0, 0]]}], "givens": [{"cell": [0, 1], "value": 1}]"#,
+            &format!(
+                r#"{zone}, "givens": [{{"cell": [0, 0], "value": 1}}, {{"cell": [0, 0], "value": 2}}]"#
+            ),
+        ] {
+            assert!(
+                matches!(parse(body), PuzzleJsonError::InvalidPuzzle(_)),
+                "{body}"
+            );
+        }
+
+        // Dimensions qui ne sont pas des positions de cases
+        assert!(matches!(
+            Grid::from_json(
+                r#"{"format": "tectonic-puzzle", "version": 1, "width": 1, "height": 10000000000,
+                "zones": [{"name": "a", "cells": [[3000000000, 0]]}]}"#
+            )
+            .unwrap_err(),
+            PuzzleJsonError::InvalidPuzzle(_)
+        ));
+    }
+}
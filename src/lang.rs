@@ -0,0 +1,330 @@
+use std::fmt;
+
+use crate::action_formatter::ActionFormatter;
+use crate::line_column::LineColumn;
+use crate::solver::{DifficultyLevel, SolvingAction, SolvingError, SolvingStrategy};
+
+/// Langue des messages affichés (actions de résolution, erreurs, stratégies, difficultés et
+/// positions des cases)
+///
+/// Le `Display` des types de la crate utilise le français. Pour une autre langue, il faut utiliser
+/// `display_in` :
+///
+/// ```rust
+/// use tectonic::{Lang, SolvingError};
+///
+/// let error = SolvingError::NoSolution;
+/// assert_eq!(error.to_string(), "La grille n'a aucune solution");
+/// assert_eq!(error.display_in(Lang::English).to_string(), "The grid has no solution");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Lang {
+    /// Français (langue par défaut)
+    #[default]
+    French,
+
+    /// Anglais
+    English,
+}
+
+/// Affichage d'une valeur dans une langue donnée (voir `display_in`)
+#[derive(Clone, Copy, Debug)]
+pub struct Localized<'a, T: ?Sized> {
+    value: &'a T,
+    lang: Lang,
+}
+
+impl<'a, T: ?Sized> Localized<'a, T> {
+    pub(crate) fn new(value: &'a T, lang: Lang) -> Self {
+        Localized { value, lang }
+    }
+}
+
+impl fmt::Display for Localized<'_, SolvingAction> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.lang.format_action(self.value))
+    }
+}
+
+impl fmt::Display for Localized<'_, SolvingError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lang {
+            Lang::French => write_error_fr(f, self.value),
+            Lang::English => write_error_en(f, self.value),
+        }
+    }
+}
+
+impl fmt::Display for Localized<'_, SolvingStrategy> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match (self.lang, self.value) {
+            (Lang::French, SolvingStrategy::SinglePossibleNumber) => {
+                "Seule possibilité pour une case"
+            }
+            (Lang::French, SolvingStrategy::NumbersInZone) => "Chiffres déjà placés dans la zone",
+            (Lang::French, SolvingStrategy::OnlyNumberInZone) => "Seule case possible dans la zone",
+            (Lang::French, SolvingStrategy::NumbersNeighboring) => "Chiffres des cases voisines",
+            (Lang::French, SolvingStrategy::DualValuesPair) => "Paire de valeurs voisines",
+            (Lang::French, SolvingStrategy::TryAndSee) => "Recherche par essai",
+            (Lang::English, SolvingStrategy::SinglePossibleNumber) => "Single candidate in a cell",
+            (Lang::English, SolvingStrategy::NumbersInZone) => "Numbers already placed in the zone",
+            (Lang::English, SolvingStrategy::OnlyNumberInZone) => "Only possible cell in the zone",
+            (Lang::English, SolvingStrategy::NumbersNeighboring) => "Numbers of neighboring cells",
+            (Lang::English, SolvingStrategy::DualValuesPair) => "Pair of neighboring values",
+            (Lang::English, SolvingStrategy::TryAndSee) => "Trial and error",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl fmt::Display for Localized<'_, LineColumn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let LineColumn { line, column } = self.value;
+        match self.lang {
+            Lang::French => write!(f, "(lin={line}, col={column})"),
+            Lang::English => write!(f, "(row={line}, col={column})"),
+        }
+    }
+}
+
+impl fmt::Display for Localized<'_, DifficultyLevel> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match (self.lang, self.value) {
+            (Lang::French, DifficultyLevel::Unknown) => "Difficulté inconnue",
+            (Lang::French, DifficultyLevel::Easy) => "Difficulté facile",
+            (Lang::French, DifficultyLevel::Medium) => "Difficulté moyenne",
+            (Lang::French, DifficultyLevel::Hard) => "Difficile",
+            (Lang::French, DifficultyLevel::VeryHard) => "Très difficile",
+            (Lang::French, DifficultyLevel::Expert) => "Expert",
+            (Lang::English, DifficultyLevel::Unknown) => "Unknown difficulty",
+            (Lang::English, DifficultyLevel::Easy) => "Easy",
+            (Lang::English, DifficultyLevel::Medium) => "Medium",
+            (Lang::English, DifficultyLevel::Hard) => "Hard",
+            (Lang::English, DifficultyLevel::VeryHard) => "Very hard",
+            (Lang::English, DifficultyLevel::Expert) => "Expert",
+        };
+        write!(f, "{text}")
+    }
+}
+
+/// Mots de la description d'une grille dans une langue (voir `Grid::describe_in`)
+pub(crate) struct DescriptionWords {
+    pub(crate) grid: &'static str,
+    pub(crate) empty_grid: &'static str,
+    pub(crate) line: &'static str,
+    pub(crate) lines: &'static str,
+    pub(crate) column: &'static str,
+    pub(crate) columns: &'static str,
+    pub(crate) cell: &'static str,
+    pub(crate) cells: &'static str,
+    pub(crate) zone: &'static str,
+    pub(crate) zones: &'static str,
+    pub(crate) and: &'static str,
+    pub(crate) colon: &'static str,
+    pub(crate) number: &'static str,
+    pub(crate) empty: &'static str,
+    pub(crate) missing: &'static str,
+}
+
+impl Lang {
+    /// Mots de la description d'une grille
+    pub(crate) fn description_words(self) -> DescriptionWords {
+        match self {
+            Lang::French => DescriptionWords {
+                grid: "Grille de",
+                empty_grid: "Grille vide.",
+                line: "ligne",
+                lines: "lignes",
+                column: "colonne",
+                columns: "colonnes",
+                cell: "case",
+                cells: "cases",
+                zone: "zone",
+                zones: "zones",
+                and: "et",
+                colon: " :",
+                number: "chiffre",
+                empty: "vide",
+                missing: "absente",
+            },
+            Lang::English => DescriptionWords {
+                grid: "Grid of",
+                empty_grid: "Empty grid.",
+                line: "row",
+                lines: "rows",
+                column: "column",
+                columns: "columns",
+                cell: "cell",
+                cells: "cells",
+                zone: "zone",
+                zones: "zones",
+                and: "and",
+                colon: ":",
+                number: "value",
+                empty: "empty",
+                missing: "missing",
+            },
+        }
+    }
+
+    /// Phrase de la description d'une grille pour la zone `name` : nombre de cases
+    /// (`nb_cells`) puis positions des cases (`positions`)
+    pub(crate) fn zone_description(self, name: &str, nb_cells: &str, positions: &str) -> String {
+        match self {
+            Lang::French => format!("Zone {name} : {nb_cells}, {positions}."),
+            Lang::English => format!("Zone {name} has {nb_cells}: {positions}."),
+        }
+    }
+}
+
+fn write_error_fr(f: &mut fmt::Formatter<'_>, error: &SolvingError) -> fmt::Result {
+    match error {
+        SolvingError::ZoneTooLong(zone_name, zone_len) => {
+            write!(
+                f,
+                "La zone '{zone_name}' est trop grande ({zone_len} cases)"
+            )
+        }
+        SolvingError::ZoneNotContiguous(zone_name) => {
+            write!(f, "La zone '{zone_name}' n'est pas d'un seul tenant")
+        }
+        SolvingError::ZoneWithUnexpectedNumber(zone_name, line_column, n) => {
+            write!(
+                f,
+                "Le chiffre '{n}' en {line_column} n'est pas possible dans la zone '{zone_name}'"
+            )
+        }
+        SolvingError::NeighboringWithSameNumber(line_column_1, line_column_2, n) => {
+            write!(
+                f,
+                "Le chiffre {n} apparaît dans les cases voisines {line_column_1} et {line_column_2}"
+            )
+        }
+        SolvingError::ZoneWithSameNumber(zone_name, line_column_1, line_column_2, n) => {
+            write!(
+                f,
+                "Le chiffre '{n}' apparaît plusieurs fois dans la zone '{zone_name}' (cases {line_column_1} et {line_column_2})"
+            )
+        }
+        SolvingError::NoPossibleNumber(line_column, zone_name) => {
+            write!(
+                f,
+                "Aucun chiffre possible dans la case {line_column} de la zone '{zone_name}'"
+            )
+        }
+        SolvingError::NoSolution => write!(f, "La grille n'a aucune solution"),
+        SolvingError::ActionNotApplicable(index) => {
+            write!(f, "L'action no {index} ne s'applique pas à la grille")
+        }
+        SolvingError::BadImplementation => write!(f, "Erreur inattendue (voir source code...)"),
+        SolvingError::WithGrid(error, grid) => {
+            write_error_fr(f, error)?;
+            write!(f, "\nÉtat de la grille :\n{grid}")
+        }
+    }
+}
+
+fn write_error_en(f: &mut fmt::Formatter<'_>, error: &SolvingError) -> fmt::Result {
+    let position = |line_column: &LineColumn| line_column.display_in(Lang::English).to_string();
+    match error {
+        SolvingError::ZoneTooLong(zone_name, zone_len) => {
+            write!(f, "Zone '{zone_name}' is too large ({zone_len} cells)")
+        }
+        SolvingError::ZoneNotContiguous(zone_name) => {
+            write!(f, "Zone '{zone_name}' is split into disconnected parts")
+        }
+        SolvingError::ZoneWithUnexpectedNumber(zone_name, line_column, n) => {
+            let line_column = position(line_column);
+            write!(
+                f,
+                "Number '{n}' at {line_column} is not possible in zone '{zone_name}'"
+            )
+        }
+        SolvingError::NeighboringWithSameNumber(line_column_1, line_column_2, n) => {
+            let (line_column_1, line_column_2) = (position(line_column_1), position(line_column_2));
+            write!(
+                f,
+                "Number {n} appears in the neighboring cells {line_column_1} and {line_column_2}"
+            )
+        }
+        SolvingError::ZoneWithSameNumber(zone_name, line_column_1, line_column_2, n) => {
+            let (line_column_1, line_column_2) = (position(line_column_1), position(line_column_2));
+            write!(
+                f,
+                "Number '{n}' appears several times in zone '{zone_name}' (cells {line_column_1} and {line_column_2})"
+            )
+        }
+        SolvingError::NoPossibleNumber(line_column, zone_name) => {
+            let line_column = position(line_column);
+            write!(
+                f,
+                "No possible number in cell {line_column} of zone '{zone_name}'"
+            )
+        }
+        SolvingError::NoSolution => write!(f, "The grid has no solution"),
+        SolvingError::ActionNotApplicable(index) => {
+            write!(f, "Action no {index} does not apply to the grid")
+        }
+        SolvingError::BadImplementation => write!(f, "Unexpected error (see source code...)"),
+        SolvingError::WithGrid(error, grid) => {
+            write_error_en(f, error)?;
+            write!(f, "\nGrid state:\n{grid}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::line_column::LineColumn;
+
+    #[test]
+    fn test_french_is_default_display() {
+        let action = SolvingAction::SinglePossibleNumber(LineColumn::new(1, 2), 3);
+        assert_eq!(
+            action.to_string(),
+            action.display_in(Lang::default()).to_string()
+        );
+
+        let error = SolvingError::NoPossibleNumber(LineColumn::new(1, 2), "a".to_string());
+        assert_eq!(
+            error.to_string(),
+            error.display_in(Lang::French).to_string()
+        );
+        assert_eq!(
+            error.display_in(Lang::English).to_string(),
+            "No possible number in cell (row=1, col=2) of zone 'a'"
+        );
+        assert_eq!(
+            LineColumn::new(1, 2).display_in(Lang::French).to_string(),
+            LineColumn::new(1, 2).to_string()
+        );
+    }
+
+    #[test]
+    fn test_english() {
+        let line_column = LineColumn::new(0, 1);
+        let action = SolvingAction::Batch(vec![
+            SolvingAction::SinglePossibleNumber(line_column, 3),
+            SolvingAction::Solved,
+        ]);
+        assert_eq!(
+            action.display_in(Lang::English).to_string(),
+            "[3] is the only possibility for cell (row=0, col=1)\nGrid solved"
+        );
+
+        assert_eq!(
+            SolvingStrategy::TryAndSee
+                .display_in(Lang::English)
+                .to_string(),
+            "Trial and error"
+        );
+        assert_eq!(
+            DifficultyLevel::VeryHard
+                .display_in(Lang::English)
+                .to_string(),
+            "Very hard"
+        );
+    }
+}
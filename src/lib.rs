@@ -13,19 +13,25 @@
 //! Lors de cette construction, une zone est repérée par une lettre ('a', 'b', etc.), et chaque case est repérée
 //! par une lettre (la zone qui contient cette case) et le chiffre qu'elle contient ou la zone seulement si
 //! le chiffre de la case n'est pas encore connu.
+//! Les lettres peuvent être des minuscules, des majuscules ou des lettres accentuées ('a', 'A', 'é', etc.) : 'a' et
+//! 'A' désignent alors deux zones différentes.
+//! Pour les grandes grilles, une zone peut aussi être repérée par plusieurs lettres ('aa', 'ab', etc.).
+//! Une ligne peut aussi être écrite au format compact, sans espace, avec deux caractères par case : la lettre
+//! de la zone suivie du chiffre de la case ou d'un point si le chiffre n'est pas connu (par exemple `a1b.b2`).
 //!
 //! La structure `Solver` permet de résoudre cette grille
 //!
 //! ```rust
 //! use std::str::FromStr;
-//! use tectonic::{Grid, Solver, SolvingOption};
+//! use tectonic::{Grid, Solver, SolverConfig};
 //!
 //! // Exemple de création d'une grille et de résolution
 //! // Les zones sont représentées par les lettres 'a', 'b' et 'c'
 //! // Les cases sont éventuellement renseignées avec un chiffre
 //! // Ici, la zone 'a' ne contient qu'une seule case en haut à gauche
 //! // Et cette case contient déjà le chiffre 1
-//! // Note : Une ligne qui commence par le caractère '#' est ignorée (commentaire)
+//! // Note : Le texte qui suit un caractère '#' est ignoré (commentaire)
+//! // Les cases sont séparées par des espaces ou des tabulations
 //! let grid = Grid::from_str(
 //!     "
 //! a1 b  b2
@@ -36,18 +42,110 @@
 //! .unwrap();
 //!
 //! println!("{grid}");
+//! // Affichage avec les bordures des zones dessinées avec des traits épais
+//! println!("{grid:#}");
 //!
 //! // Résolution de la grille
 //! let mut solver = Solver::new(&grid);
-//! let _ = solver.solve(&[SolvingOption::StepPrintAction]);
+//! let _ = solver.solve(&SolverConfig::new().print_actions(true));
 //! println!("{solver}");
 //! ```
+mod action_formatter;
+mod analysis;
+mod answer_key;
+mod ascii_art;
+mod backend;
+mod backtracking;
+#[cfg(feature = "bincode")]
+mod binary;
+mod braille;
+mod cell_state;
+mod collection;
+#[cfg(feature = "color")]
+mod color;
+mod csv;
+mod derivation;
+mod describe;
+mod dimacs;
+mod dlx;
+mod encoding;
+mod explain;
+#[cfg(feature = "formats")]
+pub mod formats;
 mod grid;
+mod grid_builder;
+mod grid_diff;
+mod grid_formatter;
+mod grid_issue;
+mod html;
+#[cfg(feature = "json")]
+mod json;
+mod lang;
 mod line_column;
+mod minizinc;
 mod neighboring_line_columns;
+mod observer;
+mod parse_options;
+#[cfg(feature = "pdf")]
+mod pdf;
+#[cfg(feature = "pdf")]
+mod pdf_book;
+#[cfg(feature = "image")]
+mod png;
+mod puzzle;
+#[cfg(feature = "qr")]
+mod qr;
+mod reader;
+mod rng;
+mod sections;
 mod simple_09_set;
 mod solver;
+mod solver_config;
+mod stuck_report;
+mod svg;
+mod symmetry;
+mod teach;
+mod tikz;
+mod walkthrough;
+mod zone_border;
 
-pub use grid::{Cell, CellContent, Grid, ParseGridError};
+pub use action_formatter::ActionFormatter;
+pub use analysis::LogicClassification;
+pub use backend::SolverBackend;
+pub use backtracking::BacktrackingSolver;
+#[cfg(feature = "bincode")]
+pub use binary::{BinaryGridError, BINARY_GRID_VERSION};
+pub use cell_state::{CellState, CellStatus};
+pub use collection::{CollectionError, PuzzleCollection, PuzzleReader};
+pub use derivation::Derivation;
+pub use dlx::DlxSolver;
+pub use explain::ExclusionReason;
+pub use grid::{Cell, CellContent, CropGridError, Grid, ParseGridError, SourceLine, Zone};
+pub use grid_builder::{GridBuildError, GridBuilder};
+pub use grid_diff::CellDiff;
+pub use grid_formatter::{BorderStyle, CandidatesStyle, GridFormatter};
+pub use grid_issue::{GridIssue, GridLint};
+pub use html::HtmlOptions;
+#[cfg(feature = "json")]
+pub use json::{PuzzleJsonError, PUZZLE_JSON_SCHEMA, PUZZLE_JSON_VERSION};
+pub use lang::{Lang, Localized};
 pub use line_column::LineColumn;
-pub use solver::{DifficultyLevel, Solver, SolvingAction, SolvingError, SolvingOption};
+pub use observer::{CellChange, CellObserver};
+pub use parse_options::{EmptyCellMarker, ParseOptions, RaggedRows};
+#[cfg(feature = "pdf")]
+pub use pdf::PdfOptions;
+#[cfg(feature = "pdf")]
+pub use pdf_book::BookOptions;
+pub use puzzle::{Puzzle, PuzzleMetadata};
+pub use reader::ReadGridError;
+pub use solver::{
+    CandidatesChange, DifficultyLevel, IntoSharedGrid, SearchStats, SolveOutcome, Solver,
+    SolverTimings, SolvingAction, SolvingActionKind, SolvingError, SolvingOption, SolvingStrategy,
+};
+pub use solver_config::SolverConfig;
+pub use stuck_report::StuckReport;
+pub use svg::SvgOptions;
+pub use symmetry::Symmetry;
+pub use teach::TechniqueHint;
+pub use tikz::TikzOptions;
+pub use zone_border::CellSide;
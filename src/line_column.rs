@@ -1,6 +1,8 @@
 use std::fmt;
 use std::ops::Add;
 
+use crate::lang::{Lang, Localized};
+
 /// Position (ligne, colonne) d'une case
 ///
 /// Implicitement, la première ligne est numérotée 0 et la première colonne est également numérotée 0.
@@ -8,11 +10,13 @@ use std::ops::Add;
 ///
 /// Dans la pratique, rien n'interdit d'avoir des lignes ou des colonnes avec une numérotation négative...
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineColumn {
     pub line: i32,
     pub column: i32,
 }
 
+/// Affichage en français (voir `display_in` pour une autre langue)
 impl fmt::Display for LineColumn {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "(lin={}, col={})", self.line, self.column)
@@ -25,6 +29,12 @@ impl LineColumn {
         LineColumn { line, column }
     }
 
+    /// Affichage dans la langue choisie
+    #[must_use]
+    pub fn display_in(&self, lang: Lang) -> Localized<'_, Self> {
+        Localized::new(self, lang)
+    }
+
     pub fn min(&mut self, other: LineColumn) {
         self.line = i32::min(self.line, other.line);
         self.column = i32::min(self.column, other.column);
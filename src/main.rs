@@ -1,19 +1,36 @@
 use std::env;
-use std::fs;
+use std::fs::File;
+use std::io::{self, Read};
 use std::str::FromStr;
 
-use tectonic::{Grid, Solver, SolvingOption};
+#[cfg(feature = "pdf")]
+use tectonic::{BookOptions, PdfOptions, PuzzleCollection};
+use tectonic::{
+    BorderStyle, Grid, GridFormatter, PuzzleReader, SolveOutcome, Solver, SolverConfig,
+};
 
 pub fn main() {
     // Arguments de la ligne de commande
     let args: Vec<String> = env::args().collect();
 
-    if args.len() == 2 {
+    match args.as_slice() {
         // Un nom de fichier passé en paramètre
-        solve_grid_in_file(&args[1]);
-    } else {
+        [_, path] => solve_grid_in_file(path, &GridFormatter::default()),
+        // Affichage des grilles avec les bordures des zones
+        [_, option, path] if option == "--box" => solve_grid_in_file(path, &GridFormatter::boxed()),
+        // Affichage des grilles en braille
+        [_, option, path] if option == "--braille" => solve_grid_in_file(
+            path,
+            &GridFormatter::new().border_style(BorderStyle::Braille),
+        ),
+        // Texte d'export d'une application Suguru passé en paramètre
+        #[cfg(feature = "formats")]
+        [_, option, app_string] if option == "--app" => solve_app_string(app_string),
+        // Page PDF imprimable d'une grille
+        #[cfg(feature = "pdf")]
+        [_, option, path, pdf_path] if option == "--pdf" => print_grid_in_file(path, pdf_path),
         // Aide utilisateur
-        help();
+        _ => help(),
     }
 }
 
@@ -35,9 +52,38 @@ Dans ce fichier, une zone est repérée par une lettre ('a', 'b', etc.) et chaqu
 par une lettre (la zone qui contient cette case) et le chiffre qu'elle contient ou la zone seulement si
 le chiffre de la case n'est pas encore connu.
 
-Les lignes 'vides' ou qui commencent par un '#' (commentaires) sont ignorées.
+Une ligne peut aussi être écrite sans espace avec deux caractères par case : la lettre de la zone
+suivie du chiffre de la case ou d'un '.' si le chiffre n'est pas connu (par exemple 'a1b.b2').
+
+Les lignes 'vides' et le texte qui suit un '#' (commentaires) sont ignorés. Les cases peuvent être
+séparées par des tabulations et les fins de ligne Windows sont acceptées.
+
+Un fichier peut aussi contenir plusieurs grilles : chaque grille commence alors par une ligne
+d'en-tête avec son nom entre '===' (par exemple '=== Grille 1 ===').
+
+Avec le nom de fichier '-', les grilles sont lues sur l'entrée standard (au fur et à mesure).
+
+Avec l'option '--box' avant le nom de fichier, les grilles sont affichées avec les bordures des
+zones. Avec l'option '--braille', les grilles sont affichées en braille (chiffres et bordures des
+zones) pour une imprimante braille ou une plage braille.
     ");
 
+    #[cfg(feature = "formats")]
+    println!(
+        "Le texte d'export d'une application Suguru (chiffres puis numéros de zone de chaque case, par
+exemple '3x3 102400002 011111222') peut aussi être passé en paramètre après l'option '--app'.
+"
+    );
+
+    #[cfg(feature = "pdf")]
+    println!(
+        "Avec l'option '--pdf' suivie du nom du fichier de la grille et du nom d'un fichier PDF, la
+grille est écrite dans une page PDF imprimable avec son titre, sa difficulté et sa solution. Si
+le fichier contient plusieurs grilles, le fichier PDF est un livre avec deux grilles par page,
+leurs difficultés et leurs solutions à la fin du livre.
+"
+    );
+
     println!("Exemple d'utilisation :\n");
     example();
 }
@@ -57,32 +103,102 @@ c  c  c2
     println!("La résolution de cette grille est alors :\n");
     let grid = Grid::from_str(file_content).unwrap();
     let mut solver = Solver::new(&grid);
-    let _ = solver.solve(&[SolvingOption::StepPrintAction]);
+    let _ = solver.solve(&SolverConfig::new().print_actions(true));
     println!("\n{solver}");
 }
 
-// Résolution d'une grille définie dans un fichier
-fn solve_grid_in_file(path: &str) {
+// Résolution des grilles définies dans un fichier (une seule grille ou une collection de grilles)
+// ou sur l'entrée standard (nom de fichier '-')
+fn solve_grid_in_file(path: &str, formatter: &GridFormatter) {
     println!("Lecture de '{path}'...");
-    match fs::read_to_string(path) {
-        Err(e) => println!("Erreur de lecture du fichier '{path}': {e}\n"),
-        Ok(file_content) => match Grid::from_str(&file_content) {
+    if path == "-" {
+        solve_grids(path, PuzzleReader::new(io::stdin().lock()), formatter);
+    } else {
+        match File::open(path) {
+            Err(e) => println!("Erreur de lecture du fichier '{path}': {e}\n"),
+            Ok(file) => solve_grids(path, PuzzleReader::new(file), formatter),
+        }
+    }
+}
+
+// Résolution des grilles au fur et à mesure de leur lecture
+fn solve_grids<R: Read>(path: &str, mut reader: PuzzleReader<R>, formatter: &GridFormatter) {
+    let mut index = 0;
+    while let Some(result) = reader.next() {
+        // Le nom des grilles n'est affiché que pour une collection (grille avec un en-tête ou
+        // après la première grille)
+        index += 1;
+        match result {
             Err(e) => println!("Erreur dans le fichier '{path}': {e}\n"),
-            Ok(grid) => {
-                let mut solver = Solver::new(&grid);
-                let res_solver = solver.solve(&[SolvingOption::StepPrintAction]);
-                match res_solver {
-                    Err(e) => println!("Erreur résolution avec le fichier '{path}': {e}\n"),
-                    Ok(done) => {
-                        if done {
-                            println!("Résolu ({})", solver.difficulty_level);
-                        } else {
-                            println!("(Non résolu :(");
-                        }
-                        println!("{solver}");
-                    }
+            Ok((name, puzzle)) => {
+                if reader.has_header() || index > 1 {
+                    println!("\n=== {name} ===");
                 }
+                print!("{}", puzzle.metadata.to_headers());
+                solve_grid(path, &puzzle.grid, formatter);
             }
-        },
+        }
+    }
+}
+
+// Résolution de la grille d'un texte d'export d'une application Suguru
+#[cfg(feature = "formats")]
+fn solve_app_string(app_string: &str) {
+    match Grid::from_app_string(app_string) {
+        Err(e) => println!("Erreur dans le texte '{app_string}': {e}\n"),
+        Ok(grid) => solve_grid(app_string, &grid, &GridFormatter::default()),
+    }
+}
+
+// Page PDF imprimable de la grille d'un fichier (avec le titre de ses lignes d'en-tête) ou livre
+// PDF des grilles d'un fichier qui contient plusieurs grilles
+#[cfg(feature = "pdf")]
+fn print_grid_in_file(path: &str, pdf_path: &str) {
+    let result_collection = File::open(path)
+        .map_err(|e| format!("Erreur de lecture du fichier '{path}': {e}"))
+        .and_then(|file| {
+            PuzzleCollection::from_reader(file)
+                .map_err(|e| format!("Erreur dans le fichier '{path}': {e}"))
+        });
+    let collection = match result_collection {
+        Err(message) => {
+            println!("{message}\n");
+            return;
+        }
+        Ok(collection) => collection,
+    };
+    let result_write = match collection.puzzles().collect::<Vec<_>>().as_slice() {
+        [(_, puzzle)] => {
+            let mut options = PdfOptions::new().difficulty(true).solution(true);
+            if let Some(title) = &puzzle.metadata.title {
+                options = options.title(title);
+            }
+            puzzle.grid.to_pdf(pdf_path, &options)
+        }
+        _ => collection.to_pdf_book(pdf_path, &BookOptions::default()),
+    };
+    match result_write {
+        Err(e) => println!("Erreur d'écriture du fichier '{pdf_path}': {e}\n"),
+        Ok(()) => println!("Grilles de '{path}' écrites dans '{pdf_path}'"),
+    }
+}
+
+// Résolution d'une grille d'un fichier
+fn solve_grid(path: &str, grid: &Grid, formatter: &GridFormatter) {
+    let mut solver = Solver::new(grid);
+    let res_solver = solver.solve(&SolverConfig::new().print_actions(true));
+    match res_solver {
+        SolveOutcome::Inconsistent(e) => {
+            println!("Erreur résolution avec le fichier '{path}': {e}\n");
+        }
+        SolveOutcome::Solved => {
+            println!("Résolu ({})", solver.difficulty_level);
+            println!("{}", formatter.format(&solver.to_grid()));
+        }
+        SolveOutcome::Stuck { .. } => {
+            println!("(Non résolu :(");
+            println!("{}", formatter.format(&solver.to_grid()));
+            println!("{}", solver.stuck_report());
+        }
     }
 }
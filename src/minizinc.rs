@@ -0,0 +1,181 @@
+use std::fmt::Write;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::neighboring_line_columns::NeighboringLineColumns;
+
+/// Nom de la variable MiniZinc d'une case
+/// (les coordonnées négatives sont préfixées par 'm')
+fn variable_name(line_column: LineColumn) -> String {
+    let coordinate = |n: i32| {
+        if n < 0 {
+            format!("m{}", -n)
+        } else {
+            format!("{n}")
+        }
+    };
+    format!(
+        "x_{}_{}",
+        coordinate(line_column.line),
+        coordinate(line_column.column)
+    )
+}
+
+impl Grid {
+    /// Modèle de contraintes MiniZinc de la grille
+    ///
+    /// Chaque case est une variable de domaine 1..taille de sa zone, avec :
+    /// * Une contrainte `all_different` par zone
+    /// * Une contrainte d'inégalité pour chaque paire de cases voisines (y compris en diagonale)
+    /// * Une contrainte d'égalité pour chaque chiffre déjà placé
+    ///
+    /// Ce modèle peut être résolu par un solver de contraintes externe (`minizinc grille.mzn`)
+    /// ou compilé en FlatZinc (`minizinc -c grille.mzn`) pour vérifier les résultats de ce crate.
+    #[must_use]
+    pub fn to_minizinc(&self) -> String {
+        let mut vec_line_columns: Vec<LineColumn> = self.hashmap_cells.keys().copied().collect();
+        vec_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+
+        let mut res = String::new();
+        res.push_str("% Grille tectonic\n");
+        res.push_str("include \"alldifferent.mzn\";\n\n");
+
+        // Variables et chiffres déjà placés
+        res.push_str("% Cases\n");
+        for line_column in &vec_line_columns {
+            let cell = self.get_cell(*line_column).unwrap();
+            let zone_len = self
+                .hashmap_zones
+                .get(&cell.c_zone)
+                .map_or(0, |zone| zone.set_line_column.len());
+            let _ = writeln!(
+                res,
+                "var 1..{zone_len}: {}; % zone '{}'",
+                variable_name(*line_column),
+                self.zone_name(cell.c_zone)
+            );
+        }
+        res.push('\n');
+
+        res.push_str("% Chiffres placés\n");
+        for line_column in &vec_line_columns {
+            let cell = self.get_cell(*line_column).unwrap();
+            if let CellContent::Number(n) = cell.content {
+                let _ = writeln!(res, "constraint {} = {n};", variable_name(*line_column));
+            }
+        }
+        res.push('\n');
+
+        // Chaque zone contient des chiffres tous différents
+        res.push_str("% Zones\n");
+        let mut vec_zones: Vec<char> = self.hashmap_zones.keys().copied().collect();
+        vec_zones.sort_unstable();
+        for c_zone in vec_zones {
+            let mut zone_line_columns: Vec<LineColumn> = self.hashmap_zones[&c_zone]
+                .set_line_column
+                .iter()
+                .copied()
+                .collect();
+            zone_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+            let vec_names: Vec<String> = zone_line_columns.into_iter().map(variable_name).collect();
+            let _ = writeln!(
+                res,
+                "constraint all_different([{}]); % zone '{}'",
+                vec_names.join(", "),
+                self.zone_name(c_zone)
+            );
+        }
+        res.push('\n');
+
+        // Deux cases voisines n'ont pas le même chiffre
+        res.push_str("% Cases voisines\n");
+        for line_column in &vec_line_columns {
+            for neighboring_line_column in NeighboringLineColumns::new(
+                *line_column,
+                self.min_line_column,
+                self.max_line_column,
+            ) {
+                // Chaque paire de cases voisines n'est écrite qu'une fois
+                if (neighboring_line_column.line, neighboring_line_column.column)
+                    > (line_column.line, line_column.column)
+                    && self.get_cell(neighboring_line_column).is_some()
+                {
+                    let _ = writeln!(
+                        res,
+                        "constraint {} != {};",
+                        variable_name(*line_column),
+                        variable_name(neighboring_line_column)
+                    );
+                }
+            }
+        }
+        res.push('\n');
+
+        res.push_str("solve satisfy;\n\n");
+
+        // Affichage de la solution ligne par ligne
+        let mut vec_lines = Vec::new();
+        for line in self.min_line_column.line..=self.max_line_column.line {
+            let mut vec_items = Vec::new();
+            for column in self.min_line_column.column..=self.max_line_column.column {
+                let line_column = LineColumn::new(line, column);
+                if self.get_cell(line_column).is_some() {
+                    vec_items.push(format!("show({})", variable_name(line_column)));
+                } else {
+                    vec_items.push("\" \"".to_string());
+                }
+            }
+            vec_lines.push(format!("{}, \"\\n\"", vec_items.join(", \" \", ")));
+        }
+        let _ = writeln!(res, "output [\n  {}\n];", vec_lines.join(",\n  "));
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_variable_name() {
+        assert_eq!(variable_name(LineColumn::new(1, 2)), "x_1_2");
+        assert_eq!(variable_name(LineColumn::new(-1, 0)), "x_m1_0");
+    }
+
+    #[test]
+    fn test_to_minizinc() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let model = grid.to_minizinc();
+
+        assert!(model.contains("var 1..1: x_0_0; % zone 'a'"));
+        assert!(model.contains("var 1..5: x_1_1; % zone 'b'"));
+        assert!(model.contains("constraint x_0_0 = 1;"));
+        assert!(model.contains("constraint x_2_2 = 2;"));
+        assert!(model.contains("constraint all_different([x_0_1, x_0_2, x_1_0, x_1_1, x_1_2]);"));
+        assert!(model.contains("constraint x_0_0 != x_1_1;"));
+        assert!(!model.contains("constraint x_1_1 != x_0_0;"));
+        assert!(model.contains("solve satisfy;"));
+        assert!(
+            model.contains("  show(x_2_0), \" \", show(x_2_1), \" \", show(x_2_2), \"\\n\"\n];")
+        );
+
+        // 3x3 cases : 6 paires horizontales, 6 verticales et 8 diagonales
+        assert_eq!(model.matches(" != ").count(), 20);
+        assert_eq!(model.matches("all_different(").count(), 3);
+
+        // Zones désignées par plusieurs lettres
+        let grid = Grid::from_str("aa1 ab  ab2\nab4 ab  ab").unwrap();
+        assert!(grid.to_minizinc().contains("var 1..1: x_0_0; % zone 'aa'"));
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+use std::sync::mpsc::Sender;
+
+use crate::grid::CellContent;
+use crate::line_column::LineColumn;
+
+/// Modification du contenu d'une case pendant la résolution (voir `Solver::subscribe`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellChange {
+    /// Case modifiée
+    pub line_column: LineColumn,
+
+    /// Contenu de la case avant la modification
+    pub before: CellContent,
+
+    /// Contenu de la case après la modification
+    pub after: CellContent,
+}
+
+/// Observateur notifié à chaque modification du contenu d'une case (chiffre placé ou chiffres
+/// possibles réduits), quelle que soit l'étape de résolution qui la provoque
+///
+/// Une closure `Fn(&CellChange)` ou l'émetteur `Sender<CellChange>` d'un canal peuvent être
+/// utilisés directement comme observateur :
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use std::sync::mpsc;
+/// use tectonic::{Grid, Solver, SolverConfig};
+///
+/// let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+/// let mut solver = Solver::new(&grid);
+///
+/// let (sender, receiver) = mpsc::channel();
+/// solver.subscribe(sender);
+/// assert!(solver.solve(&SolverConfig::default()).is_solved());
+///
+/// // Une notification par modification du contenu d'une case
+/// assert!(receiver.try_iter().count() > 0);
+/// ```
+pub trait CellObserver {
+    /// Le contenu d'une case a été modifié
+    fn cell_changed(&self, change: &CellChange);
+}
+
+impl<F: Fn(&CellChange)> CellObserver for F {
+    fn cell_changed(&self, change: &CellChange) {
+        self(change);
+    }
+}
+
+impl CellObserver for Sender<CellChange> {
+    fn cell_changed(&self, change: &CellChange) {
+        // Le récepteur du canal peut avoir été abandonné : ce n'est pas une erreur de résolution
+        let _ = self.send(change.clone());
+    }
+}
+
+/// Liste des observateurs d'un solver
+#[derive(Default)]
+pub(crate) struct CellObservers(Vec<Box<dyn CellObserver + Send>>);
+
+impl fmt::Debug for CellObservers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CellObservers({})", self.0.len())
+    }
+}
+
+impl CellObservers {
+    /// Ajoute un observateur
+    pub(crate) fn push(&mut self, observer: Box<dyn CellObserver + Send>) {
+        self.0.push(observer);
+    }
+
+    /// Supprime tous les observateurs
+    pub(crate) fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Retourne true s'il n'y a aucun observateur
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Notifie tous les observateurs d'une modification si le contenu de la case a changé
+    pub(crate) fn notify(
+        &self,
+        line_column: LineColumn,
+        before: &CellContent,
+        after: &CellContent,
+    ) {
+        if before == after {
+            return;
+        }
+        let change = CellChange {
+            line_column,
+            before: before.clone(),
+            after: after.clone(),
+        };
+        for observer in &self.0 {
+            observer.cell_changed(&change);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use crate::solver::{Solver, SolvingAction};
+    use std::str::FromStr;
+    use std::sync::{mpsc, Arc, Mutex};
+
+    #[test]
+    fn test_subscribe() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let changes_observer = Arc::clone(&changes);
+        let mut solver = Solver::new(&grid);
+        solver.subscribe(move |change: &CellChange| {
+            changes_observer.lock().unwrap().push(change.clone());
+        });
+
+        // Chaque action qui modifie des cases notifie ces cases
+        let _ = solver.solve_step().unwrap();
+        loop {
+            changes.lock().unwrap().clear();
+            let action = solver.solve_step().unwrap();
+            if action == SolvingAction::Solved {
+                break;
+            }
+            let mut notified: Vec<LineColumn> = changes
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|change| change.line_column)
+                .collect();
+            let mut affected_cells = action.affected_cells();
+            notified.sort_by_key(|line_column| (line_column.line, line_column.column));
+            affected_cells.sort_by_key(|line_column| (line_column.line, line_column.column));
+            assert_eq!(notified, affected_cells);
+            for change in changes.lock().unwrap().iter() {
+                assert_ne!(change.before, change.after);
+                assert_eq!(solver.cell_content(change.line_column), Some(&change.after));
+            }
+        }
+    }
+
+    #[test]
+    fn test_subscribe_channel() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        let mut solver = Solver::new(&grid);
+        solver.subscribe(sender);
+        let _ = solver.solve_step().unwrap();
+        let _ = solver.solve_step().unwrap();
+        assert!(receiver.try_iter().count() > 0);
+
+        // Le retour à la grille d'origine est notifié
+        solver.reset();
+        let changes: Vec<CellChange> = receiver.try_iter().collect();
+        assert!(!changes.is_empty());
+        assert!(changes
+            .iter()
+            .all(|change| change.after == CellContent::Undefined));
+
+        // Plus aucune notification après `unsubscribe_all`
+        solver.unsubscribe_all();
+        let _ = solver.solve_step().unwrap();
+        assert_eq!(receiver.try_iter().count(), 0);
+    }
+}
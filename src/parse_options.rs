@@ -0,0 +1,89 @@
+/// Traitement des lignes de cases de longueurs différentes lors de la lecture d'une grille avec
+/// `Grid::from_str_with_options`
+///
+/// La longueur attendue d'une ligne est celle de la première ligne de cases du texte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RaggedRows {
+    /// Les lignes de longueurs différentes forment une grille irrégulière (comportement de
+    /// `Grid::from_str`). Une case absente de la grille est un trou : une ligne plus courte que
+    /// la plus longue est donc complétée par des trous après sa dernière case
+    #[default]
+    Irregular,
+
+    /// Toutes les lignes doivent avoir la même longueur (une ligne de longueur différente est
+    /// souvent une faute de frappe)
+    Reject,
+}
+
+/// Marque d'une case sans chiffre après le nom de sa zone (voir
+/// `Grid::to_puzzle_string_with_marker`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EmptyCellMarker {
+    /// Nom de la zone seul (format de `Grid::from_str`, par exemple "b")
+    #[default]
+    None,
+
+    /// Nom de la zone suivi de '0' (par exemple "b0")
+    Zero,
+
+    /// Nom de la zone suivi de '_' (par exemple "b_")
+    Underscore,
+}
+
+impl EmptyCellMarker {
+    /// Caractères des marques lues avec l'option `ParseOptions::empty_cell_markers`
+    pub(crate) const MARKERS: [char; 2] = ['0', '_'];
+
+    /// Texte de la marque
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EmptyCellMarker::None => "",
+            EmptyCellMarker::Zero => "0",
+            EmptyCellMarker::Underscore => "_",
+        }
+    }
+}
+
+/// Options de lecture d'une grille avec `Grid::from_str_with_options`
+///
+/// Les options se construisent par chaînage :
+///
+/// ```rust
+/// use tectonic::{Grid, ParseGridError, ParseOptions, RaggedRows};
+///
+/// let options = ParseOptions::new().ragged_rows(RaggedRows::Reject);
+/// let result = Grid::from_str_with_options("a1 b  b2\nb4 b\nc  c  c2", &options);
+/// assert!(matches!(result, Err(ParseGridError::RaggedRow { line: 1, .. })));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Traitement des lignes de longueurs différentes
+    pub(crate) ragged_rows: RaggedRows,
+
+    /// Cases sans chiffre marquées par un '0' ou un '_' après le nom de leur zone
+    pub(crate) empty_cell_markers: bool,
+}
+
+impl ParseOptions {
+    /// Options par défaut : celles de `Grid::from_str`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Traitement des lignes de cases de longueurs différentes (voir `RaggedRows`)
+    #[must_use]
+    pub fn ragged_rows(mut self, ragged_rows: RaggedRows) -> Self {
+        self.ragged_rows = ragged_rows;
+        self
+    }
+
+    /// Lecture d'un '0' ou d'un '_' après le nom de la zone d'une case (par exemple "b0" ou "b_")
+    /// comme une case sans chiffre (ces cases sont des erreurs par défaut)
+    #[must_use]
+    pub fn empty_cell_markers(mut self, empty_cell_markers: bool) -> Self {
+        self.empty_cell_markers = empty_cell_markers;
+        self
+    }
+}
@@ -0,0 +1,529 @@
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::solver::{DifficultyLevel, Solver};
+use crate::solver_config::SolverConfig;
+use crate::zone_border::CellSide;
+
+/// Nombre de points PDF (1/72 de pouce) dans un millimètre
+const POINTS_PER_MM: f32 = 72.0 / 25.4;
+
+/// Marges de la page (en millimètres)
+pub(crate) const MARGIN_MM: f32 = 15.0;
+
+/// Taille des textes du titre, de la difficulté et de la légende de la solution (en points)
+pub(crate) const TITLE_SIZE: f32 = 20.0;
+const DIFFICULTY_SIZE: f32 = 12.0;
+const LABEL_SIZE: f32 = 8.0;
+
+/// Taille des cases de la solution par rapport aux cases de la grille
+const SOLUTION_RATIO: f32 = 0.25;
+
+/// Couleur des chiffres placés pendant la résolution (RVB entre 0 et 1)
+const PLACED_COLOR: &str = "0.184 0.373 0.702";
+
+/// Largeur des caractères ASCII imprimables (de ' ' à '~') de la police Helvetica (en 1/1000 de
+/// la taille de la police), pour centrer les textes
+#[rustfmt::skip]
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556,
+    278, 278, 584, 584, 584, 556, 1015,
+    667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833,
+    722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611,
+    278, 278, 278, 469, 556, 333,
+    556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833,
+    556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500,
+    334, 260, 334, 584,
+];
+
+/// Options de la page PDF d'une grille avec `Grid::to_pdf`
+///
+/// Les options se construisent par chaînage :
+///
+/// ```rust
+/// use tectonic::PdfOptions;
+///
+/// let options = PdfOptions::new()
+///     .cell_size(15)
+///     .title("Grille du jour")
+///     .difficulty(true)
+///     .solution(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PdfOptions {
+    /// Largeur et hauteur de la page (en millimètres)
+    pub(crate) page_size: (u32, u32),
+
+    /// Taille maximum d'une case (en millimètres)
+    pub(crate) cell_size: u32,
+
+    /// Titre en haut de la page
+    pub(crate) title: Option<String>,
+
+    /// Niveau de difficulté (calculé par le solver) sous le titre
+    pub(crate) difficulty: bool,
+
+    /// Solution en petit en bas de la page
+    pub(crate) solution: bool,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        PdfOptions {
+            page_size: (210, 297),
+            cell_size: 12,
+            title: None,
+            difficulty: false,
+            solution: false,
+        }
+    }
+}
+
+impl PdfOptions {
+    /// Options par défaut : page A4, cases de 12 mm, sans titre, sans difficulté et sans solution
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Largeur et hauteur de la page en millimètres (au moins 50 mm)
+    #[must_use]
+    pub fn page_size(mut self, width: u32, height: u32) -> Self {
+        self.page_size = (width.max(50), height.max(50));
+        self
+    }
+
+    /// Taille d'une case en millimètres (au moins 3 mm), réduite si la grille ne tient pas dans
+    /// la page
+    #[must_use]
+    pub fn cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size.max(3);
+        self
+    }
+
+    /// Titre en haut de la page
+    #[must_use]
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Niveau de difficulté de la grille (calculé par le solver) sous le titre
+    #[must_use]
+    pub fn difficulty(mut self, difficulty: bool) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Solution de la grille en petit en bas de la page (si le solver trouve la solution)
+    #[must_use]
+    pub fn solution(mut self, solution: bool) -> Self {
+        self.solution = solution;
+        self
+    }
+}
+
+impl Grid {
+    /// Écrit dans le fichier `path` une page PDF imprimable de la grille (voir `to_pdf_bytes`)
+    ///
+    /// # Errors
+    /// Erreur d'écriture du fichier
+    pub fn to_pdf(&self, path: impl AsRef<Path>, options: &PdfOptions) -> io::Result<()> {
+        std::fs::write(path, self.to_pdf_bytes(options))
+    }
+
+    /// Fichier PDF d'une page avec la grille à imprimer, centrée sous le titre et la difficulté
+    /// éventuels, et la solution en petit en bas à droite de la page si elle est demandée
+    ///
+    /// Les textes utilisent la police Helvetica standard du lecteur PDF (caractères latins
+    /// seulement)
+    #[must_use]
+    pub fn to_pdf_bytes(&self, options: &PdfOptions) -> Vec<u8> {
+        let (page_width, page_height) = (
+            mm(options.page_size.0 as f32),
+            mm(options.page_size.1 as f32),
+        );
+        let margin = mm(MARGIN_MM);
+        let mut page = PdfPage::default();
+
+        // Solution et difficulté calculées par le solver
+        let option_solution = if options.solution || options.difficulty {
+            let mut solver = Solver::new(self);
+            solver
+                .solve(&SolverConfig::new())
+                .is_solved()
+                .then(|| (solver.to_grid(), solver.difficulty_level))
+        } else {
+            None
+        };
+
+        // En-tête
+        let mut top = page_height - margin;
+        if let Some(title) = &options.title {
+            top -= TITLE_SIZE;
+            page.text(title, page_width / 2.0, top, TITLE_SIZE, true);
+            top -= TITLE_SIZE / 2.0;
+        }
+        if options.difficulty {
+            let difficulty_level = option_solution
+                .as_ref()
+                .map_or(DifficultyLevel::Unknown, |(_, level)| *level);
+            top -= DIFFICULTY_SIZE;
+            let text = difficulty_level.to_string();
+            page.text(&text, page_width / 2.0, top, DIFFICULTY_SIZE, false);
+            top -= DIFFICULTY_SIZE;
+        }
+
+        // Grille centrée dans la place disponible
+        let (width, height) = (self.width() as f32, self.height() as f32);
+        let option_solution = option_solution.filter(|_| options.solution);
+        if width > 0.0 {
+            let (mut available_height, mut nb_cells_high) = (top - margin, height);
+            if option_solution.is_some() {
+                available_height -= 2.0 * LABEL_SIZE + mm(MARGIN_MM);
+                nb_cells_high += height * SOLUTION_RATIO;
+            }
+            let cell = mm(options.cell_size as f32)
+                .min((page_width - 2.0 * margin) / width)
+                .min(available_height / nb_cells_high)
+                .max(0.0);
+            page.grid(self, (page_width - width * cell) / 2.0, top, cell);
+
+            if let Some((solution, _)) = &option_solution {
+                let small_cell = cell * SOLUTION_RATIO;
+                let (x, y) = (
+                    page_width - margin - width * small_cell,
+                    margin + height * small_cell,
+                );
+                page.text(
+                    "Solution",
+                    x + width * small_cell / 2.0,
+                    y + LABEL_SIZE / 2.0,
+                    LABEL_SIZE,
+                    false,
+                );
+                page.grid(solution, x, y, small_cell);
+            }
+        }
+
+        let mut document = PdfDocument::new(page_width, page_height);
+        document.push_page(page);
+        document.to_bytes()
+    }
+}
+
+/// Conversion de millimètres en points PDF
+pub(crate) fn mm(value: f32) -> f32 {
+    value * POINTS_PER_MM
+}
+
+/// Nombre dans un contenu PDF (au centième de point près)
+fn num(value: f32) -> String {
+    let text = format!("{value:.2}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Chaîne de caractères PDF (encodage WinAnsi) d'un texte : les caractères hors de l'alphabet
+/// latin sont remplacés par '?'
+fn pdf_string(text: &str) -> String {
+    let mut res = String::from("(");
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                res.push('\\');
+                res.push(c);
+            }
+            ' '..='~' => res.push(c),
+            '\u{a0}'..='\u{ff}' => write!(res, "\\{:03o}", u32::from(c)).unwrap(),
+            _ => res.push('?'),
+        }
+    }
+    res.push(')');
+    res
+}
+
+/// Largeur approchée d'un texte en Helvetica (en points)
+fn text_width(text: &str, size: f32) -> f32 {
+    let total: u32 = text
+        .chars()
+        .map(|c| match c {
+            ' '..='~' => u32::from(HELVETICA_WIDTHS[c as usize - ' ' as usize]),
+            _ => 556,
+        })
+        .sum();
+    total as f32 * size / 1000.0
+}
+
+/// Contenu (instructions de dessin) d'une page PDF, avec l'origine en bas à gauche de la page
+#[derive(Default)]
+pub(crate) struct PdfPage {
+    content: String,
+}
+
+impl PdfPage {
+    /// Texte centré sur `x` avec sa ligne de base en `y`, en gras ou non
+    pub(crate) fn text(&mut self, text: &str, x: f32, y: f32, size: f32, bold: bool) {
+        let font = if bold { "F2" } else { "F1" };
+        writeln!(
+            self.content,
+            "BT /{font} {} Tf {} {} Td {} Tj ET",
+            num(size),
+            num(x - text_width(text, size) / 2.0),
+            num(y),
+            pdf_string(text)
+        )
+        .unwrap();
+    }
+
+    /// Dessin d'une grille avec son coin supérieur gauche en (`x`, `y`) et des cases de `cell`
+    /// points : fines bordures entre les cases d'une même zone, bordures épaisses entre deux zones
+    /// et autour de la grille, chiffres connus au départ (en gras) et chiffres placés (en bleu)
+    pub(crate) fn grid(&mut self, grid: &Grid, x: f32, y: f32, cell: f32) {
+        let Some((min, _)) = grid.bounds() else {
+            return;
+        };
+
+        // Coin supérieur gauche d'une case dans la page
+        let origin = |line_column: LineColumn| {
+            (
+                x + (line_column.column - min.column) as f32 * cell,
+                y - (line_column.line - min.line) as f32 * cell,
+            )
+        };
+
+        let mut vec_cells: Vec<_> = grid.cells().collect();
+        vec_cells.sort_by_key(|(line_column, _)| (line_column.line, line_column.column));
+
+        let (mut thin_path, mut thick_path, mut texts) =
+            (String::new(), String::new(), String::new());
+        let size = cell * 0.6;
+        for (line_column, cell_content) in vec_cells {
+            let (left, top) = origin(*line_column);
+            let bottom = top - cell;
+            writeln!(
+                thin_path,
+                "{} {} {} {} re",
+                num(left),
+                num(bottom),
+                num(cell),
+                num(cell)
+            )
+            .unwrap();
+
+            // Chiffre centré dans la case (les chiffres ont tous la même largeur en Helvetica et
+            // une hauteur d'environ 0,72 fois la taille de la police)
+            if let CellContent::Number(n) = cell_content.content {
+                let (font, color) = if cell_content.is_given {
+                    ("F2", "0 0 0")
+                } else {
+                    ("F1", PLACED_COLOR)
+                };
+                writeln!(
+                    texts,
+                    "BT /{font} {} Tf {color} rg {} {} Td ({n}) Tj ET",
+                    num(size),
+                    num(left + cell / 2.0 - text_width("0", size) / 2.0),
+                    num(bottom + cell / 2.0 - size * 0.36)
+                )
+                .unwrap();
+            }
+        }
+
+        // Bordures épaisses entre les zones et autour de la grille
+        for (line_column, side) in grid.zone_borders() {
+            let (left, top) = origin(line_column);
+            let (right, bottom) = (left + cell, top - cell);
+            let ((x1, y1), (x2, y2)) = match side {
+                CellSide::Top => ((left, top), (right, top)),
+                CellSide::Right => ((right, top), (right, bottom)),
+                CellSide::Bottom => ((left, bottom), (right, bottom)),
+                CellSide::Left => ((left, top), (left, bottom)),
+            };
+            writeln!(
+                thick_path,
+                "{} {} m {} {} l",
+                num(x1),
+                num(y1),
+                num(x2),
+                num(y2)
+            )
+            .unwrap();
+        }
+
+        write!(
+            self.content,
+            "q\n0.6 G {} w\n{thin_path}S\n0 G {} w 2 J\n{thick_path}S\n{texts}Q\n",
+            num((cell / 40.0).max(0.25)),
+            num((cell / 12.0).max(0.5))
+        )
+        .unwrap();
+    }
+}
+
+/// Document PDF de pages de même taille, avec les polices standard Helvetica et Helvetica-Bold
+pub(crate) struct PdfDocument {
+    /// Largeur et hauteur des pages (en points)
+    page_size: (f32, f32),
+
+    /// Contenu des pages
+    pages: Vec<PdfPage>,
+}
+
+impl PdfDocument {
+    /// Document sans page, avec des pages de `width` x `height` points
+    pub(crate) fn new(width: f32, height: f32) -> Self {
+        PdfDocument {
+            page_size: (width, height),
+            pages: Vec::new(),
+        }
+    }
+
+    /// Ajoute une page à la fin du document
+    pub(crate) fn push_page(&mut self, page: PdfPage) {
+        self.pages.push(page);
+    }
+
+    /// Fichier PDF du document
+    ///
+    /// Les objets sont : 1 le catalogue, 2 la liste des pages, 3 et 4 les polices, puis pour
+    /// chaque page la page et son contenu
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let kids: Vec<String> = (0..self.pages.len())
+            .map(|index| format!("{} 0 R", 5 + 2 * index))
+            .collect();
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                self.pages.len()
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+        ];
+        for (index, page) in self.pages.iter().enumerate() {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                num(self.page_size.0),
+                num(self.page_size.1),
+                6 + 2 * index
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{}endstream",
+                page.content.len(),
+                page.content
+            ));
+        }
+
+        // En-tête (avec des caractères non ASCII pour signaler un fichier binaire), objets, table
+        // des positions des objets dans le fichier
+        let mut res = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(res.len());
+            res.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", index + 1).as_bytes());
+        }
+        let xref_offset = res.len();
+        let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            writeln!(xref, "{offset:010} 00000 n ").unwrap();
+        }
+        write!(
+            xref,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .unwrap();
+        res.extend_from_slice(xref.as_bytes());
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    /// Vérifie la table des positions des objets d'un fichier PDF et retourne son texte
+    fn check_pdf(pdf: &[u8]) -> String {
+        let text = String::from_utf8_lossy(pdf).to_string();
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        let xref_offset: usize = text.lines().rev().nth(1).unwrap().parse().unwrap();
+        assert!(pdf[xref_offset..].starts_with(b"xref\n"));
+        let xref = String::from_utf8_lossy(&pdf[xref_offset..]).to_string();
+        for (index, line) in xref
+            .lines()
+            .skip(3)
+            .take_while(|line| line.ends_with("n "))
+            .enumerate()
+        {
+            let offset: usize = line[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", index + 1).as_bytes()));
+        }
+        text
+    }
+
+    #[test]
+    fn test_pdf_string() {
+        assert_eq!(pdf_string("Grille (1)"), "(Grille \\(1\\))");
+        assert_eq!(pdf_string("Très"), "(Tr\\350s)");
+        assert_eq!(pdf_string("a→b"), "(a?b)");
+        assert_eq!(num(12.0), "12");
+        assert_eq!(num(0.184), "0.18");
+        assert!((text_width("10", 10.0) - 11.12).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_to_pdf_bytes() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let text = check_pdf(&grid.to_pdf_bytes(&PdfOptions::default()));
+        assert!(text.contains("/Count 1"));
+        assert!(text.contains("/MediaBox [0 0 595.28 841.89]"));
+        assert_eq!(text.matches(" re\n").count(), 9);
+        assert_eq!(text.matches(") Tj ET").count(), 4);
+        assert!(!text.contains("BT /F1 "));
+
+        let options = PdfOptions::new()
+            .page_size(100, 100)
+            .title("Grille du jour")
+            .difficulty(true)
+            .solution(true);
+        let text = check_pdf(&grid.to_pdf_bytes(&options));
+        assert!(text.contains("/MediaBox [0 0 283.46 283.46]"));
+        assert!(text.contains("(Grille du jour) Tj"));
+        assert!(text.contains("(Difficult\\351 moyenne) Tj"));
+        assert!(text.contains("(Solution) Tj"));
+        assert_eq!(text.matches(" re\n").count(), 18);
+        assert_eq!(text.matches(") Tj ET").count(), 3 + 4 + 9);
+
+        // Grille sans solution : pas de solution et difficulté inconnue
+        let grid = Grid::from_str("a1 b\nb  b").unwrap();
+        let text = check_pdf(&grid.to_pdf_bytes(&options));
+        assert!(text.contains("(Difficult\\351 inconnue) Tj"));
+        assert!(!text.contains("(Solution) Tj"));
+    }
+
+    #[test]
+    fn test_to_pdf() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let path = std::env::temp_dir().join(format!("tectonic_{}.pdf", std::process::id()));
+        grid.to_pdf(&path, &PdfOptions::default()).unwrap();
+        let pdf = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(pdf, grid.to_pdf_bytes(&PdfOptions::default()));
+    }
+}
@@ -0,0 +1,312 @@
+use std::io;
+use std::path::Path;
+
+use crate::backend::SolverBackend;
+use crate::backtracking::BacktrackingSolver;
+use crate::collection::PuzzleCollection;
+use crate::grid::Grid;
+use crate::pdf::{mm, PdfDocument, PdfPage, MARGIN_MM, TITLE_SIZE};
+use crate::solver::{DifficultyLevel, Solver};
+use crate::solver_config::SolverConfig;
+
+/// Taille des textes du nom et de la difficulté d'une grille et des numéros de page (en points)
+const NAME_SIZE: f32 = 11.0;
+const DETAIL_SIZE: f32 = 9.0;
+const PAGE_NUMBER_SIZE: f32 = 9.0;
+
+/// Texte à la place de la solution d'une grille sans solution
+const NO_SOLUTION: Option<&str> = Some("Aucune solution");
+
+/// Taille maximum d'une case (en millimètres)
+const MAX_CELL_MM: f32 = 12.0;
+
+/// Espace autour de chaque grille dans la page (en millimètres)
+const PADDING_MM: f32 = 4.0;
+
+/// Options d'un livre de grilles au format PDF avec `PuzzleCollection::to_pdf_book`
+///
+/// Les options se construisent par chaînage :
+///
+/// ```rust
+/// use tectonic::BookOptions;
+///
+/// let options = BookOptions::new().puzzles_per_page(4).solutions_per_page(12);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookOptions {
+    /// Largeur et hauteur des pages (en millimètres)
+    pub(crate) page_size: (u32, u32),
+
+    /// Nombre de grilles par page
+    pub(crate) puzzles_per_page: u32,
+
+    /// Nombre de solutions par page
+    pub(crate) solutions_per_page: u32,
+
+    /// Pages de solutions à la fin du livre
+    pub(crate) solutions: bool,
+}
+
+impl Default for BookOptions {
+    fn default() -> Self {
+        BookOptions {
+            page_size: (210, 297),
+            puzzles_per_page: 2,
+            solutions_per_page: 6,
+            solutions: true,
+        }
+    }
+}
+
+impl BookOptions {
+    /// Options par défaut : pages A4 avec 2 grilles par page puis les solutions à la fin du livre,
+    /// 6 solutions par page
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Largeur et hauteur des pages en millimètres (au moins 50 mm)
+    #[must_use]
+    pub fn page_size(mut self, width: u32, height: u32) -> Self {
+        self.page_size = (width.max(50), height.max(50));
+        self
+    }
+
+    /// Nombre de grilles par page (au moins 1)
+    #[must_use]
+    pub fn puzzles_per_page(mut self, puzzles_per_page: u32) -> Self {
+        self.puzzles_per_page = puzzles_per_page.max(1);
+        self
+    }
+
+    /// Nombre de solutions par page (au moins 1)
+    #[must_use]
+    pub fn solutions_per_page(mut self, solutions_per_page: u32) -> Self {
+        self.solutions_per_page = solutions_per_page.max(1);
+        self
+    }
+
+    /// Pages de solutions à la fin du livre
+    #[must_use]
+    pub fn solutions(mut self, solutions: bool) -> Self {
+        self.solutions = solutions;
+        self
+    }
+}
+
+/// Emplacement d'une grille dans une page : coin supérieur gauche, largeur et hauteur (en points)
+#[derive(Clone, Copy)]
+struct Slot {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+impl PuzzleCollection {
+    /// Écrit dans le fichier `path` le livre PDF des grilles de la collection (voir
+    /// `to_pdf_book_bytes`)
+    ///
+    /// # Errors
+    /// Erreur d'écriture du fichier
+    pub fn to_pdf_book(&self, path: impl AsRef<Path>, options: &BookOptions) -> io::Result<()> {
+        std::fs::write(path, self.to_pdf_book_bytes(options))
+    }
+
+    /// Fichier PDF d'un livre avec les grilles de la collection (plusieurs grilles par page, dans
+    /// l'ordre de la collection) avec leur nom et leur niveau de difficulté calculé par le solver,
+    /// puis une section avec les solutions des grilles
+    ///
+    /// Une grille que le solver ne sait pas résoudre a une difficulté inconnue et sa solution est
+    /// recherchée par `BacktrackingSolver`. Une grille sans solution est signalée par 'Aucune
+    /// solution' dans la section des solutions. Les pages sont numérotées.
+    #[must_use]
+    pub fn to_pdf_book_bytes(&self, options: &BookOptions) -> Vec<u8> {
+        let (page_width, page_height) = (
+            mm(options.page_size.0 as f32),
+            mm(options.page_size.1 as f32),
+        );
+        let mut document = PdfDocument::new(page_width, page_height);
+        let mut page_number = 0;
+        let mut new_page = |document: &mut PdfDocument, page: PdfPage| {
+            let mut page = page;
+            page_number += 1;
+            let text = page_number.to_string();
+            page.text(
+                &text,
+                page_width / 2.0,
+                mm(MARGIN_MM) / 2.0,
+                PAGE_NUMBER_SIZE,
+                false,
+            );
+            document.push_page(page);
+        };
+
+        // Résolution de chaque grille, par une recherche exhaustive si le solver est bloqué
+        let solved: Vec<(&str, &Grid, Option<Grid>, DifficultyLevel)> = self
+            .iter()
+            .map(|(name, grid)| {
+                let mut solver = Solver::new(grid);
+                if solver.solve(&SolverConfig::new()).is_solved() {
+                    return (name, grid, Some(solver.to_grid()), solver.difficulty_level);
+                }
+                let mut backtracking = BacktrackingSolver::new(grid);
+                let solution = SolverBackend::solve(&mut backtracking)
+                    .is_solved()
+                    .then(|| backtracking.grid().clone());
+                (name, grid, solution, DifficultyLevel::Unknown)
+            })
+            .collect();
+
+        // Pages des grilles
+        let top = page_height - mm(MARGIN_MM);
+        let per_page = options.puzzles_per_page as usize;
+        for chunk in solved.chunks(per_page) {
+            let mut page = PdfPage::default();
+            let slots = slots(per_page, page_width, top);
+            for ((name, grid, _, level), slot) in chunk.iter().zip(slots) {
+                draw_slot(&mut page, grid, name, Some(&level.to_string()), slot);
+            }
+            new_page(&mut document, page);
+        }
+
+        // Pages des solutions, avec le titre de la section sur la première page
+        if options.solutions {
+            let per_page = options.solutions_per_page as usize;
+            for (index, chunk) in solved.chunks(per_page).enumerate() {
+                let mut page = PdfPage::default();
+                let mut top = top;
+                if index == 0 {
+                    top -= TITLE_SIZE;
+                    page.text("Solutions", page_width / 2.0, top, TITLE_SIZE, true);
+                    top -= TITLE_SIZE / 2.0;
+                }
+                let slots = slots(per_page, page_width, top);
+                for ((name, _, solution, _), slot) in chunk.iter().zip(slots) {
+                    match solution {
+                        Some(solution) => draw_slot(&mut page, solution, name, None, slot),
+                        None => {
+                            draw_slot(&mut page, &Grid::default(), name, NO_SOLUTION, slot);
+                        }
+                    }
+                }
+                new_page(&mut document, page);
+            }
+        }
+
+        document.to_bytes()
+    }
+}
+
+/// Emplacements de `count` grilles d'une page, en lignes et colonnes entre les marges et sous
+/// `top` : plus de lignes que de colonnes si la page est plus haute que large
+fn slots(count: usize, page_width: f32, top: f32) -> Vec<Slot> {
+    let margin = mm(MARGIN_MM);
+    let (width, height) = (page_width - 2.0 * margin, top - margin);
+    let mut nb_lines = (1..=count).find(|n| n * n >= count).unwrap_or(1);
+    let mut nb_columns = count.div_ceil(nb_lines);
+    if width > height {
+        (nb_lines, nb_columns) = (nb_columns, nb_lines);
+    }
+    let (slot_width, slot_height) = (width / nb_columns as f32, height / nb_lines as f32);
+    (0..count)
+        .map(|index| Slot {
+            x: margin + (index % nb_columns) as f32 * slot_width,
+            y: top - (index / nb_columns) as f32 * slot_height,
+            width: slot_width,
+            height: slot_height,
+        })
+        .collect()
+}
+
+/// Dessin d'une grille dans son emplacement, sous son nom et un détail éventuel (la difficulté)
+fn draw_slot(page: &mut PdfPage, grid: &Grid, name: &str, detail: Option<&str>, slot: Slot) {
+    let padding = mm(PADDING_MM);
+    let center = slot.x + slot.width / 2.0;
+    let mut top = slot.y - padding - NAME_SIZE;
+    page.text(name, center, top, NAME_SIZE, true);
+    if let Some(detail) = detail {
+        top -= DETAIL_SIZE * 1.5;
+        page.text(detail, center, top, DETAIL_SIZE, false);
+    }
+    top -= padding;
+
+    let (width, height) = (grid.width() as f32, grid.height() as f32);
+    if width > 0.0 {
+        let available_height = top - (slot.y - slot.height) - padding;
+        let cell = mm(MAX_CELL_MM)
+            .min((slot.width - 2.0 * padding) / width)
+            .min(available_height / height)
+            .max(0.0);
+        page.grid(grid, center - width * cell / 2.0, top, cell);
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_slots() {
+        let (page_width, top) = (mm(210.0), mm(297.0 - MARGIN_MM));
+        let slots_2 = slots(2, page_width, top);
+        assert_eq!(slots_2.len(), 2);
+        assert_eq!(slots_2[0].x, slots_2[1].x);
+        assert!(slots_2[1].y < slots_2[0].y);
+
+        // 2 lignes de 2 colonnes si la page est plus haute que large, 2 lignes de 3 colonnes sinon
+        let slots_4 = slots(4, page_width, top);
+        assert_eq!(slots_4[1].y, slots_4[0].y);
+        assert_eq!(slots_4[2].x, slots_4[0].x);
+        let slots_6 = slots(6, mm(297.0), mm(210.0 - MARGIN_MM));
+        assert_eq!(slots_6[2].y, slots_6[0].y);
+        assert_eq!(slots_6[3].x, slots_6[0].x);
+    }
+
+    #[test]
+    fn test_to_pdf_book_bytes() {
+        let collection = PuzzleCollection::from_str(
+            "
+=== Grille 1 ===
+a1 b  b2
+b4 b  b
+c  c  c2
+
+=== Grille 2 ===
+a  a2
+
+=== Grille 3 ===
+a1 b
+b  b
+
+=== Grille 4 ===
+a  a  a  a  a
+",
+        )
+        .unwrap();
+        let pdf = collection.to_pdf_book_bytes(&BookOptions::default());
+        let text = String::from_utf8_lossy(&pdf).to_string();
+        assert!(text.starts_with("%PDF-1.4\n"));
+
+        // 2 pages de grilles et 1 page de solutions
+        assert!(text.contains("/Count 3"));
+        assert!(text.contains("(Grille 1) Tj"));
+        assert!(text.contains("(Difficult\\351 moyenne) Tj"));
+        assert_eq!(text.matches("(Difficult\\351 inconnue) Tj").count(), 2);
+        assert!(text.contains("(Solutions) Tj"));
+        assert_eq!(text.matches("(Grille 3) Tj").count(), 2);
+        assert_eq!(text.matches("(Aucune solution) Tj").count(), 1);
+
+        // Les cases des 4 grilles, puis des solutions des 3 grilles qui en ont une (trouvée par
+        // une recherche exhaustive pour la grille 4)
+        assert_eq!(text.matches(" re\n").count(), 9 + 2 + 4 + 5 + 9 + 2 + 5);
+
+        let options = BookOptions::new().puzzles_per_page(4).solutions(false);
+        let text = String::from_utf8_lossy(&collection.to_pdf_book_bytes(&options)).to_string();
+        assert!(text.contains("/Count 1"));
+        assert!(!text.contains("(Solutions) Tj"));
+    }
+}
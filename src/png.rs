@@ -0,0 +1,506 @@
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::solver::{Solver, SolvingAction};
+use crate::zone_border::CellSide;
+
+/// Couleurs de la palette de l'image (rouge, vert, bleu)
+const PALETTE: [[u8; 3]; 6] = [
+    [0xff, 0xff, 0xff], // Hors de la grille (transparent)
+    [0xff, 0xff, 0xff], // Fond d'une case
+    [0x99, 0x99, 0x99], // Bordure fine entre deux cases d'une même zone
+    [0x00, 0x00, 0x00], // Bordure épaisse et chiffre connu au départ
+    [0x2f, 0x5f, 0xb3], // Chiffre placé pendant la résolution
+    [0xff, 0xf2, 0xa8], // Fond d'une case modifiée par une étape de la résolution
+];
+const TRANSPARENT: u8 = 0;
+const BACKGROUND: u8 = 1;
+const THIN: u8 = 2;
+const BLACK: u8 = 3;
+const PLACED: u8 = 4;
+const TARGET: u8 = 5;
+
+/// Nombre maximum d'images d'une animation de la résolution
+const MAX_FRAMES: usize = 1000;
+
+/// Dessin des chiffres 1 à 9 dans une matrice de 5x7 points (5 bits de poids faible de chaque
+/// ligne, de haut en bas)
+const DIGITS: [[u8; 7]; 9] = [
+    [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+    [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+    [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+    [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+    [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+    [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+    [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+    [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+    [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+];
+
+/// Image en couleurs indexées dans `PALETTE`
+struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Self {
+        Canvas {
+            width,
+            height,
+            pixels: vec![TRANSPARENT; usize::try_from(width * height).unwrap()],
+        }
+    }
+
+    /// Remplit le rectangle de coin supérieur gauche (x, y) et de taille w x h (limité à l'image)
+    fn fill_rect(&mut self, x: i64, y: i64, w: i64, h: i64, color: u8) {
+        let (width, height) = (i64::from(self.width), i64::from(self.height));
+        for py in y.max(0)..(y + h).min(height) {
+            for px in x.max(0)..(x + w).min(width) {
+                self.pixels[usize::try_from(py * width + px).unwrap()] = color;
+            }
+        }
+    }
+
+    /// Dessine le chiffre `n` centré en (cx, cy) avec des points de `dot` pixels (chiffre en gras
+    /// si `bold`)
+    fn draw_digit(&mut self, n: u8, cx: i64, cy: i64, dot: i64, bold: bool, color: u8) {
+        let (x0, y0) = (cx - 5 * dot / 2, cy - 7 * dot / 2);
+        let extra = if bold { (dot / 2).max(1) } else { 0 };
+        for (row, bits) in DIGITS[usize::from(n - 1)].iter().enumerate() {
+            for col in 0..5 {
+                if bits >> (4 - col) & 1 == 1 {
+                    let (x, y) = (x0 + col * dot, y0 + i64::try_from(row).unwrap() * dot);
+                    self.fill_rect(x, y, dot + extra, dot, color);
+                }
+            }
+        }
+    }
+}
+
+impl Grid {
+    /// Image PNG de la grille (vignette, image pour un réseau social, etc.) avec des cases de
+    /// `scale` pixels (au moins 8)
+    ///
+    /// Le dessin est celui de `to_svg` sans les chiffres possibles : fines bordures entre les cases
+    /// d'une même zone, bordures épaisses entre deux zones et autour de la grille, chiffres connus
+    /// au départ en gras et chiffres placés pendant la résolution en bleu. Les trous de la grille
+    /// sont transparents.
+    #[must_use]
+    pub fn to_png(&self, scale: u32) -> Vec<u8> {
+        png(&self.canvas(scale, &[]))
+    }
+
+    /// Animation PNG (APNG) de la résolution de la grille, pour un réseau social ou un support
+    /// pédagogique : une image par étape de la résolution (avec des cases de `scale` pixels), les
+    /// cases modifiées par l'étape sur fond jaune, chaque image étant affichée `frame_delay_ms`
+    /// millisecondes
+    ///
+    /// La première image est la grille à résoudre et la dernière la grille résolue (ou la grille
+    /// à l'étape où la résolution est bloquée). Un lecteur d'images sans animation n'affiche que
+    /// la première image.
+    #[must_use]
+    pub fn to_apng(&self, scale: u32, frame_delay_ms: u16) -> Vec<u8> {
+        let mut frames = vec![self.canvas(scale, &[])];
+        let mut solver = Solver::new(self);
+        while frames.len() < MAX_FRAMES {
+            match solver.solve_step() {
+                Ok(SolvingAction::InitPossibleNumbers) => (),
+                Ok(SolvingAction::Solved | SolvingAction::NoAction) | Err(_) => break,
+                Ok(action) => {
+                    frames.push(solver.to_grid().canvas(scale, &action.affected_cells()));
+                }
+            }
+        }
+        apng(&frames, frame_delay_ms)
+    }
+
+    /// Dessin de la grille avec des cases de `scale` pixels et les cases `targets` sur fond jaune
+    fn canvas(&self, scale: u32, targets: &[LineColumn]) -> Canvas {
+        let size = i64::from(scale.max(8));
+        let thick = (size / 12).max(2);
+        let margin = thick;
+        let (min, max) = self
+            .bounds()
+            .unwrap_or((LineColumn::default(), LineColumn::new(-1, -1)));
+        let (width, height) = (
+            i64::from(max.column - min.column + 1),
+            i64::from(max.line - min.line + 1),
+        );
+        let mut canvas = Canvas::new(
+            u32::try_from(width * size + 2 * margin).unwrap(),
+            u32::try_from(height * size + 2 * margin).unwrap(),
+        );
+
+        // Coin supérieur gauche d'une case dans l'image
+        let origin = |line_column: &LineColumn| {
+            (
+                margin + i64::from(line_column.column - min.column) * size,
+                margin + i64::from(line_column.line - min.line) * size,
+            )
+        };
+
+        // Fond et bordures fines de chaque case
+        for (line_column, _) in self.cells() {
+            let (x, y) = origin(line_column);
+            let background = if targets.contains(line_column) {
+                TARGET
+            } else {
+                BACKGROUND
+            };
+            canvas.fill_rect(x, y, size + 1, size + 1, THIN);
+            canvas.fill_rect(x + 1, y + 1, size - 1, size - 1, background);
+        }
+
+        // Bordures épaisses entre les zones et autour de la grille, puis chiffres
+        let (start, length) = (-thick / 2, size + thick);
+        for (line_column, side) in self.zone_borders() {
+            let (x, y) = origin(&line_column);
+            match side {
+                CellSide::Top => canvas.fill_rect(x + start, y + start, length, thick, BLACK),
+                CellSide::Right => {
+                    canvas.fill_rect(x + size + start, y + start, thick, length, BLACK);
+                }
+                CellSide::Bottom => {
+                    canvas.fill_rect(x + start, y + size + start, length, thick, BLACK);
+                }
+                CellSide::Left => canvas.fill_rect(x + start, y + start, thick, length, BLACK),
+            }
+        }
+        for (line_column, cell) in self.cells() {
+            if let CellContent::Number(n) = cell.content {
+                let (x, y) = origin(line_column);
+                let dot = (size * 3 / 5 / 7).max(1);
+                let color = if cell.is_given { BLACK } else { PLACED };
+                canvas.draw_digit(n, x + size / 2, y + size / 2, dot, cell.is_given, color);
+            }
+        }
+        canvas
+    }
+}
+
+/// Fichier PNG (couleurs indexées sur 8 bits) d'une image
+fn png(canvas: &Canvas) -> Vec<u8> {
+    let mut res = png_header(canvas);
+    write_chunk(&mut res, b"IDAT", &image_data(canvas));
+    write_chunk(&mut res, b"IEND", &[]);
+    res
+}
+
+/// Fichier APNG (PNG animé) d'images de même taille affichées chacune `delay_ms` millisecondes
+fn apng(frames: &[Canvas], delay_ms: u16) -> Vec<u8> {
+    let mut res = png_header(&frames[0]);
+
+    // Nombre d'images et nombre de répétitions (infini)
+    let mut control = u32::try_from(frames.len()).unwrap().to_be_bytes().to_vec();
+    control.extend_from_slice(&0_u32.to_be_bytes());
+    // Le bloc acTL doit être avant les données de la première image
+    let ihdr_end = 8 + 12 + 13;
+    let mut animation_control = Vec::new();
+    write_chunk(&mut animation_control, b"acTL", &control);
+    res.splice(ihdr_end..ihdr_end, animation_control);
+
+    // Les blocs fcTL et fdAT sont numérotés à partir de 0
+    let mut sequence = 0_u32;
+    for (index, canvas) in frames.iter().enumerate() {
+        let mut frame_control = sequence.to_be_bytes().to_vec();
+        frame_control.extend_from_slice(&canvas.width.to_be_bytes());
+        frame_control.extend_from_slice(&canvas.height.to_be_bytes());
+        // Position (0, 0), durée (en 1/1000 s), image remplacée par la suivante sans fusion
+        frame_control.extend_from_slice(&[0; 8]);
+        frame_control.extend_from_slice(&delay_ms.to_be_bytes());
+        frame_control.extend_from_slice(&1000_u16.to_be_bytes());
+        frame_control.extend_from_slice(&[0, 0]);
+        write_chunk(&mut res, b"fcTL", &frame_control);
+        sequence += 1;
+
+        if index == 0 {
+            write_chunk(&mut res, b"IDAT", &image_data(canvas));
+        } else {
+            let mut frame_data = sequence.to_be_bytes().to_vec();
+            frame_data.extend_from_slice(&image_data(canvas));
+            write_chunk(&mut res, b"fdAT", &frame_data);
+            sequence += 1;
+        }
+    }
+    write_chunk(&mut res, b"IEND", &[]);
+    res
+}
+
+/// Début d'un fichier PNG : signature, dimensions de l'image et palette
+fn png_header(canvas: &Canvas) -> Vec<u8> {
+    let mut res = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&canvas.width.to_be_bytes());
+    header.extend_from_slice(&canvas.height.to_be_bytes());
+    // Profondeur de 8 bits, couleurs indexées, compression, filtre et entrelacement par défaut
+    header.extend_from_slice(&[8, 3, 0, 0, 0]);
+    write_chunk(&mut res, b"IHDR", &header);
+    write_chunk(&mut res, b"PLTE", PALETTE.as_flattened());
+    write_chunk(&mut res, b"tRNS", &[0]);
+    res
+}
+
+/// Données compressées d'une image (blocs IDAT et fdAT)
+fn image_data(canvas: &Canvas) -> Vec<u8> {
+    // Chaque ligne de l'image commence par son filtre (aucun)
+    let row_len = usize::try_from(canvas.width).unwrap();
+    let mut data = Vec::with_capacity(canvas.pixels.len() + canvas.pixels.len() / row_len.max(1));
+    for row in canvas.pixels.chunks(row_len.max(1)) {
+        data.push(0);
+        data.extend_from_slice(row);
+    }
+    zlib(&data, row_len + 1)
+}
+
+/// Ajoute un bloc PNG (longueur, type, données et CRC)
+fn write_chunk(res: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    res.extend_from_slice(&u32::try_from(data.len()).unwrap().to_be_bytes());
+    let start = res.len();
+    res.extend_from_slice(kind);
+    res.extend_from_slice(data);
+    let crc = crc32(&res[start..]);
+    res.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// CRC-32 (ISO 3309) des blocs PNG
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Somme de contrôle Adler-32 du format zlib
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in bytes {
+        a = (a + u32::from(*byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+/// Écriture bit à bit d'un flux deflate (bits de poids faible en premier)
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    /// Écrit les `count` bits de poids faible de `value`
+    fn write(&mut self, value: u32, count: u32) {
+        self.buffer |= u64::from(value) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.bytes.push(u8::try_from(self.buffer & 0xFF).unwrap());
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Écrit un code de Huffman de `count` bits (bit de poids fort en premier)
+    fn write_code(&mut self, code: u32, count: u32) {
+        self.write(code.reverse_bits() >> (32 - count), count);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(u8::try_from(self.buffer & 0xFF).unwrap());
+        }
+        self.bytes
+    }
+}
+
+/// Longueurs de base des codes de longueur 257 à 285 de deflate
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+
+/// Distances de base des codes de distance 0 à 29 de deflate
+const DISTANCE_BASES: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+
+/// Nombre de bits supplémentaires d'un code de longueur ou de distance de base `index`
+fn extra_bits(index: usize, is_distance: bool) -> u32 {
+    let index = u32::try_from(index).unwrap();
+    if is_distance {
+        (index / 2).saturating_sub(1)
+    } else if index == 28 {
+        0
+    } else {
+        (index / 4).saturating_sub(1)
+    }
+}
+
+/// Écrit le symbole `symbol` (0 à 287) avec les codes de Huffman fixes de deflate
+fn write_symbol(writer: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => writer.write_code(0x30 + symbol, 8),
+        144..=255 => writer.write_code(0x190 + symbol - 144, 9),
+        256..=279 => writer.write_code(symbol - 256, 7),
+        _ => writer.write_code(0xC0 + symbol - 280, 8),
+    }
+}
+
+/// Flux zlib des données `data` compressées par deflate avec les codes de Huffman fixes
+///
+/// Seules les répétitions de l'octet précédent et de la ligne précédente (de `row_len` octets)
+/// sont recherchées : cela suffit pour une image faite de grandes surfaces de même couleur
+fn zlib(data: &[u8], row_len: usize) -> Vec<u8> {
+    let mut writer = BitWriter {
+        bytes: vec![0x78, 0x01],
+        buffer: 0,
+        count: 0,
+    };
+    // Un seul bloc (dernier bloc) avec les codes fixes
+    writer.write(1, 1);
+    writer.write(1, 2);
+
+    let match_len = |index: usize, distance: usize| {
+        if distance == 0 || distance > index || distance > 32768 {
+            return 0;
+        }
+        (0..258.min(data.len() - index))
+            .take_while(|offset| data[index + offset] == data[index + offset - distance])
+            .count()
+    };
+    let mut index = 0;
+    while index < data.len() {
+        let (length, distance) = [1, row_len]
+            .into_iter()
+            .map(|distance| (match_len(index, distance), distance))
+            .max()
+            .unwrap();
+        if length < 3 {
+            write_symbol(&mut writer, u32::from(data[index]));
+            index += 1;
+            continue;
+        }
+
+        let length_index = LENGTH_BASES
+            .iter()
+            .rposition(|base| usize::from(*base) <= length)
+            .unwrap();
+        write_symbol(&mut writer, 257 + u32::try_from(length_index).unwrap());
+        let extra = u32::try_from(length - usize::from(LENGTH_BASES[length_index])).unwrap();
+        writer.write(extra, extra_bits(length_index, false));
+
+        let distance_index = DISTANCE_BASES
+            .iter()
+            .rposition(|base| usize::from(*base) <= distance)
+            .unwrap();
+        writer.write_code(u32::try_from(distance_index).unwrap(), 5);
+        let extra = u32::try_from(distance - usize::from(DISTANCE_BASES[distance_index])).unwrap();
+        writer.write(extra, extra_bits(distance_index, true));
+
+        index += length;
+    }
+    write_symbol(&mut writer, 256);
+
+    let mut res = writer.finish();
+    res.extend_from_slice(&adler32(data).to_be_bytes());
+    res
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    /// Blocs (type et données) d'un fichier PNG dont les CRC sont vérifiés
+    fn chunks(png: &[u8]) -> Vec<(String, Vec<u8>)> {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let mut res = Vec::new();
+        let mut index = 8;
+        while index < png.len() {
+            let len = usize::try_from(u32::from_be_bytes(
+                png[index..index + 4].try_into().unwrap(),
+            ))
+            .unwrap();
+            let kind = &png[index + 4..index + 8];
+            let data = &png[index + 8..index + 8 + len];
+            let crc =
+                u32::from_be_bytes(png[index + 8 + len..index + 12 + len].try_into().unwrap());
+            assert_eq!(crc32(&png[index + 4..index + 8 + len]), crc);
+            res.push((String::from_utf8(kind.to_vec()).unwrap(), data.to_vec()));
+            index += 12 + len;
+        }
+        res
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn test_zlib() {
+        // 'a' suivi d'une répétition de 9 octets à la distance 1 (flux vérifié avec zlib)
+        assert_eq!(
+            zlib(b"aaaaaaaaaa", 100),
+            vec![0x78, 0x01, 0x4B, 0x84, 0x03, 0x00, 0x14, 0xE1, 0x03, 0xCB]
+        );
+    }
+
+    #[test]
+    fn test_to_png() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let png = grid.to_png(40);
+        let chunks = chunks(&png);
+        let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+        assert_eq!(kinds, vec!["IHDR", "PLTE", "tRNS", "IDAT", "IEND"]);
+        // 3 cases de 40 pixels et une marge de 3 pixels de chaque côté
+        assert_eq!(&chunks[0].1[..8], &[0, 0, 0, 126, 0, 0, 0, 126]);
+        // Compression des grandes surfaces de même couleur
+        assert!(png.len() < 126 * 127 / 10);
+        assert_ne!(grid.to_png(8), png);
+    }
+
+    #[test]
+    fn test_to_apng() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let apng = grid.to_apng(20, 500);
+        let chunks = chunks(&apng);
+        let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+        assert_eq!(
+            &kinds[..6],
+            &["IHDR", "acTL", "PLTE", "tRNS", "fcTL", "IDAT"]
+        );
+        assert_eq!(kinds.last(), Some(&"IEND"));
+
+        // Une image par étape de la résolution
+        let nb_frames = kinds.iter().filter(|kind| **kind == "fcTL").count();
+        assert!(nb_frames > 2);
+        assert_eq!(
+            kinds.iter().filter(|kind| **kind == "fdAT").count(),
+            nb_frames - 1
+        );
+        let nb_frames = u32::try_from(nb_frames).unwrap();
+        assert_eq!(chunks[1].1, [nb_frames.to_be_bytes(), [0; 4]].concat());
+
+        // Numéros des blocs fcTL et fdAT et durée d'affichage des images
+        let sequences: Vec<u32> = chunks
+            .iter()
+            .filter(|(kind, _)| kind == "fcTL" || kind == "fdAT")
+            .map(|(_, data)| u32::from_be_bytes(data[..4].try_into().unwrap()))
+            .collect();
+        assert_eq!(sequences, (0..2 * nb_frames - 1).collect::<Vec<_>>());
+        assert_eq!(&chunks[4].1[20..24], &[1, 244, 3, 232]);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::grid::{normalized_text, Grid, ParseGridError};
+
+/// Début d'une ligne d'en-tête avec une information sur la grille
+const METADATA_MARK: &str = "#!";
+
+/// Informations sur une grille lues dans les lignes d'en-tête `#! clef: valeur` de son texte
+///
+/// ```text
+/// #! title: Grille du jour
+/// #! author: Moi
+/// #! source: Mon livre de grilles, page 12
+/// #! difficulty: 3
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PuzzleMetadata {
+    /// Titre de la grille (clef `title`)
+    pub title: Option<String>,
+
+    /// Auteur de la grille (clef `author`)
+    pub author: Option<String>,
+
+    /// Origine de la grille : livre, site, etc. (clef `source`)
+    pub source: Option<String>,
+
+    /// Difficulté annoncée par la source de la grille (clef `difficulty`)
+    pub difficulty: Option<u8>,
+
+    /// Autres informations (par clef)
+    pub extra: BTreeMap<String, String>,
+}
+
+impl PuzzleMetadata {
+    /// Indique si aucune information n'est connue
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == PuzzleMetadata::default()
+    }
+
+    /// Lecture des lignes d'en-tête `#! clef: valeur` d'un texte (les autres lignes sont
+    /// ignorées, ainsi que les lignes d'en-tête sans ':')
+    ///
+    /// # Errors
+    /// Une erreur `ParseGridError::InvalidLine` est retournée si la difficulté n'est pas un
+    /// nombre
+    pub fn from_headers(s: &str) -> Result<PuzzleMetadata, ParseGridError> {
+        let mut metadata = PuzzleMetadata::default();
+        for str_line in normalized_text(s).lines() {
+            let Some(header) = str_line.trim().strip_prefix(METADATA_MARK) else {
+                continue;
+            };
+            let Some((key, value)) = header.split_once(':') else {
+                continue;
+            };
+            let (key, value) = (key.trim().to_lowercase(), value.trim().to_string());
+            match key.as_str() {
+                "title" => metadata.title = Some(value),
+                "author" => metadata.author = Some(value),
+                "source" => metadata.source = Some(value),
+                "difficulty" => match value.parse::<u8>() {
+                    Ok(difficulty) => metadata.difficulty = Some(difficulty),
+                    Err(_) => return Err(ParseGridError::InvalidLine(str_line.trim().to_string())),
+                },
+                _ => {
+                    metadata.extra.insert(key, value);
+                }
+            }
+        }
+        Ok(metadata)
+    }
+
+    /// Lignes d'en-tête `#! clef: valeur` de ces informations (lues par `from_headers`)
+    #[must_use]
+    pub fn to_headers(&self) -> String {
+        let mut res = String::new();
+        let known = [
+            ("title", self.title.clone()),
+            ("author", self.author.clone()),
+            ("source", self.source.clone()),
+            ("difficulty", self.difficulty.map(|d| d.to_string())),
+        ];
+        for (key, value) in known {
+            if let Some(value) = value {
+                writeln!(res, "{METADATA_MARK} {key}: {value}").unwrap();
+            }
+        }
+        for (key, value) in &self.extra {
+            writeln!(res, "{METADATA_MARK} {key}: {value}").unwrap();
+        }
+        res
+    }
+}
+
+/// Grille avec les informations de ses lignes d'en-tête (voir `PuzzleMetadata`)
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use tectonic::Puzzle;
+///
+/// let puzzle = Puzzle::from_str(
+///     "
+/// #! title: Exemple
+/// #! difficulty: 1
+/// a1 b  b2
+/// b4 b  b
+/// c  c  c2
+/// ",
+/// )
+/// .unwrap();
+/// assert_eq!(puzzle.metadata.title.as_deref(), Some("Exemple"));
+/// assert_eq!(puzzle.metadata.difficulty, Some(1));
+/// assert_eq!(puzzle.grid.cell_count(), 9);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Puzzle {
+    /// Grille
+    pub grid: Grid,
+
+    /// Informations sur la grille
+    pub metadata: PuzzleMetadata,
+}
+
+impl Puzzle {
+    /// Texte de la grille avec ses lignes d'en-tête (au format de `Puzzle::from_str`)
+    #[must_use]
+    pub fn to_puzzle_string(&self) -> String {
+        format!(
+            "{}{}",
+            self.metadata.to_headers(),
+            self.grid.to_puzzle_string()
+        )
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Les lignes d'en-tête sont des commentaires pour `Grid::from_str`
+        Ok(Puzzle {
+            grid: Grid::from_str(s)?,
+            metadata: PuzzleMetadata::from_headers(s)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_puzzle_metadata() {
+        let s = "
+        # Commentaire
+        #! Title : Grille du jour
+        #! author: Moi
+        #! source: Mon livre, page 12
+        #! difficulty: 3
+        #! date: 2024-01-01
+        #! sans clef
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ";
+        let puzzle = Puzzle::from_str(s).unwrap();
+        assert_eq!(puzzle.grid, Grid::from_str(s).unwrap());
+        let metadata = &puzzle.metadata;
+        assert_eq!(metadata.title.as_deref(), Some("Grille du jour"));
+        assert_eq!(metadata.author.as_deref(), Some("Moi"));
+        assert_eq!(metadata.source.as_deref(), Some("Mon livre, page 12"));
+        assert_eq!(metadata.difficulty, Some(3));
+        assert_eq!(metadata.extra.len(), 1);
+        assert_eq!(metadata.extra["date"], "2024-01-01");
+
+        let text = puzzle.to_puzzle_string();
+        assert!(text.starts_with("#! title: Grille du jour\n#! author: Moi\n"));
+        assert_eq!(Puzzle::from_str(&text).unwrap(), puzzle);
+
+        assert!(Puzzle::from_str("a1").unwrap().metadata.is_empty());
+    }
+
+    #[test]
+    fn test_puzzle_metadata_nok() {
+        let error = Puzzle::from_str("#! difficulty: difficile\na1\n").unwrap_err();
+        assert_eq!(error.token(), "#! difficulty: difficile");
+    }
+}
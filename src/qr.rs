@@ -0,0 +1,618 @@
+use std::fmt::Write;
+
+use crate::grid::Grid;
+
+/// Largeur de la marge blanche autour du QR code (en modules)
+const QUIET_ZONE: usize = 4;
+
+/// Version maximale d'un QR code
+const MAX_VERSION: usize = 40;
+
+/// Nombre d'octets de correction d'erreur par bloc au niveau M pour chaque version (à partir de
+/// la version 1)
+const EC_CODEWORDS_M: [usize; MAX_VERSION] = [
+    10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+];
+
+/// Nombre de blocs de correction d'erreur au niveau M pour chaque version (à partir de la
+/// version 1)
+const NB_BLOCKS_M: [usize; MAX_VERSION] = [
+    1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25,
+    26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+];
+
+/// Niveau de correction d'erreur M dans les informations de format
+const FORMAT_LEVEL_M: u32 = 0b00;
+
+impl Grid {
+    /// Image SVG du QR code du texte `prefix` suivi de l'encodage compact de la grille (voir
+    /// `encode`)
+    ///
+    /// `prefix` permet par exemple de coder l'URL d'une application qui affiche la grille
+    /// (`https://example.com/suguru?p=`). Avec un préfixe vide, le QR code contient seulement
+    /// l'encodage de la grille.
+    ///
+    /// Le QR code (norme ISO/IEC 18004) est en mode octet avec le niveau de correction d'erreur M,
+    /// dans la plus petite version possible entre 1 et 40. Retourne None si le texte est trop long
+    /// pour un QR code de version 40 (2331 octets)
+    #[must_use]
+    pub fn to_qr_svg(&self, prefix: &str) -> Option<String> {
+        let text = format!("{prefix}{}", self.encode());
+        let modules = qr_modules(text.as_bytes())?;
+        Some(svg(&modules))
+    }
+}
+
+/// Image SVG d'une matrice de modules (true pour un module noir)
+fn svg(modules: &[Vec<bool>]) -> String {
+    let size = modules.len() + 2 * QUIET_ZONE;
+    let mut path = String::new();
+    for (y, row) in modules.iter().enumerate() {
+        for (x, dark) in row.iter().enumerate() {
+            if *dark {
+                if !path.is_empty() {
+                    path.push(' ');
+                }
+                write!(path, "M{},{}h1v1h-1z", x + QUIET_ZONE, y + QUIET_ZONE).unwrap();
+            }
+        }
+    }
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {size} {size}\" \
+         shape-rendering=\"crispEdges\">\n\
+         <rect width=\"{size}\" height=\"{size}\" fill=\"#ffffff\"/>\n\
+         <path d=\"{path}\" fill=\"#000000\"/>\n\
+         </svg>\n"
+    )
+}
+
+/// Matrice des modules du QR code de `data` dans la plus petite version possible
+fn qr_modules(data: &[u8]) -> Option<Vec<Vec<bool>>> {
+    let version = (1..=MAX_VERSION).find(|version| {
+        4 + count_bits(*version) + data.len() * 8 <= data_codewords(*version) * 8
+    })?;
+
+    let codewords = add_error_correction(version, &encode_data(version, data));
+    let mut qr = QrMatrix::new(version);
+    qr.draw_function_patterns();
+    qr.draw_codewords(&codewords);
+
+    // Choix du masque de plus faible pénalité
+    let mut best: Option<(usize, QrMatrix)> = None;
+    for mask in 0..8 {
+        let mut candidate = qr.clone();
+        candidate.apply_mask(mask);
+        candidate.draw_format_bits(mask);
+        let penalty = candidate.penalty();
+        if best
+            .as_ref()
+            .is_none_or(|(best_penalty, _)| penalty < *best_penalty)
+        {
+            best = Some((penalty, candidate));
+        }
+    }
+    best.map(|(_, qr)| qr.modules)
+}
+
+/// Nombre de bits du nombre d'octets en mode octet
+fn count_bits(version: usize) -> usize {
+    if version < 10 {
+        8
+    } else {
+        16
+    }
+}
+
+/// Nombre de modules de données et de correction d'erreur d'un QR code (tous les modules sauf
+/// ceux des motifs fixes)
+fn data_modules(version: usize) -> usize {
+    let mut res = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let count = version / 7 + 2;
+        res -= (25 * count - 10) * count - 55;
+        if version >= 7 {
+            res -= 36;
+        }
+    }
+    res
+}
+
+/// Blocs d'un QR code de niveau M : nombre d'octets de correction par bloc, puis nombre de blocs
+/// et nombre d'octets de données par bloc pour chacun des deux groupes de blocs (les blocs du
+/// second groupe ont un octet de plus)
+fn blocks(version: usize) -> (usize, usize, usize, usize, usize) {
+    let (ec_len, nb_blocks) = (EC_CODEWORDS_M[version - 1], NB_BLOCKS_M[version - 1]);
+    let codewords = data_modules(version) / 8;
+    let blocks_2 = codewords % nb_blocks;
+    let data_1 = codewords / nb_blocks - ec_len;
+    (ec_len, nb_blocks - blocks_2, data_1, blocks_2, data_1 + 1)
+}
+
+/// Nombre d'octets de données d'un QR code de niveau M
+fn data_codewords(version: usize) -> usize {
+    let (_, blocks_1, data_1, blocks_2, data_2) = blocks(version);
+    blocks_1 * data_1 + blocks_2 * data_2
+}
+
+/// Octets de données en mode octet (indicateur de mode, nombre d'octets, données, terminateur et
+/// octets de remplissage)
+fn encode_data(version: usize, data: &[u8]) -> Vec<u8> {
+    let mut bits: Vec<bool> = Vec::new();
+    let mut push_bits = |value: usize, count: usize| {
+        for i in (0..count).rev() {
+            bits.push((value >> i) & 1 == 1);
+        }
+    };
+    push_bits(0b0100, 4);
+    push_bits(data.len(), count_bits(version));
+    for byte in data {
+        push_bits(usize::from(*byte), 8);
+    }
+
+    let capacity = data_codewords(version) * 8;
+    let terminator = (capacity - bits.len()).min(4);
+    bits.extend(std::iter::repeat_n(false, terminator));
+    while !bits.len().is_multiple_of(8) {
+        bits.push(false);
+    }
+    let mut codewords: Vec<u8> = bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0, |acc, bit| acc << 1 | u8::from(*bit)))
+        .collect();
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if codewords.len() * 8 >= capacity {
+            break;
+        }
+        codewords.push(pad);
+    }
+    codewords
+}
+
+/// Découpage en blocs, ajout des octets de correction d'erreur et entrelacement des blocs
+fn add_error_correction(version: usize, data: &[u8]) -> Vec<u8> {
+    let (ec_len, blocks_1, data_1, blocks_2, data_2) = blocks(version);
+    let divisor = reed_solomon_divisor(ec_len);
+
+    let mut vec_blocks: Vec<(&[u8], Vec<u8>)> = Vec::new();
+    let mut start = 0;
+    for len in std::iter::repeat_n(data_1, blocks_1).chain(std::iter::repeat_n(data_2, blocks_2)) {
+        let block = &data[start..start + len];
+        vec_blocks.push((block, reed_solomon_remainder(block, &divisor)));
+        start += len;
+    }
+
+    let mut res = Vec::new();
+    for i in 0..data_1.max(data_2) {
+        for (block, _) in &vec_blocks {
+            if let Some(byte) = block.get(i) {
+                res.push(*byte);
+            }
+        }
+    }
+    for i in 0..ec_len {
+        for (_, ec) in &vec_blocks {
+            res.push(ec[i]);
+        }
+    }
+    res
+}
+
+/// Produit dans le corps de Galois GF(2^8) de polynôme x^8 + x^4 + x^3 + x^2 + 1
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u16 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= u16::from((y >> i) & 1) * u16::from(x);
+    }
+    u8::try_from(z).unwrap()
+}
+
+/// Coefficients (sans celui de plus haut degré) du polynôme générateur de Reed-Solomon de degré
+/// `degree`
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut res = vec![0; degree];
+    res[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            res[j] = gf_multiply(res[j], root);
+            if j + 1 < degree {
+                res[j] ^= res[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    res
+}
+
+/// Octets de correction d'erreur de Reed-Solomon d'un bloc de données
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut res = vec![0; divisor.len()];
+    for byte in data {
+        let factor = byte ^ res[0];
+        res.remove(0);
+        res.push(0);
+        for (r, coef) in res.iter_mut().zip(divisor) {
+            *r ^= gf_multiply(*coef, factor);
+        }
+    }
+    res
+}
+
+/// Matrice d'un QR code en construction
+#[derive(Clone)]
+struct QrMatrix {
+    /// Version du QR code
+    version: usize,
+
+    /// Couleur de chaque module (true pour noir), indexée par ligne puis colonne
+    modules: Vec<Vec<bool>>,
+
+    /// Modules des motifs fixes (repères, synchronisation, format, etc.) qui ne sont pas masqués
+    is_function: Vec<Vec<bool>>,
+}
+
+impl QrMatrix {
+    fn new(version: usize) -> QrMatrix {
+        let size = 17 + 4 * version;
+        QrMatrix {
+            version,
+            modules: vec![vec![false; size]; size],
+            is_function: vec![vec![false; size]; size],
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Module d'un motif fixe en colonne `x` et ligne `y`
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y][x] = dark;
+        self.is_function[y][x] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        let size = self.size();
+
+        // Motifs de synchronisation
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        // Repères des trois coins (avec leurs séparateurs)
+        for (x, y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            for yy in y.saturating_sub(4)..=(y + 4).min(size - 1) {
+                for xx in x.saturating_sub(4)..=(x + 4).min(size - 1) {
+                    let distance = xx.abs_diff(x).max(yy.abs_diff(y));
+                    self.set_function(xx, yy, distance != 2 && distance != 4);
+                }
+            }
+        }
+
+        // Motifs d'alignement (sauf sur les repères des coins)
+        let positions = alignment_positions(self.version);
+        let last = positions.len().saturating_sub(1);
+        for (i, x) in positions.iter().enumerate() {
+            for (j, y) in positions.iter().enumerate() {
+                if (i, j) == (0, 0) || (i, j) == (0, last) || (i, j) == (last, 0) {
+                    continue;
+                }
+                for yy in y - 2..=y + 2 {
+                    for xx in x - 2..=x + 2 {
+                        let distance = xx.abs_diff(*x).max(yy.abs_diff(*y));
+                        self.set_function(xx, yy, distance != 1);
+                    }
+                }
+            }
+        }
+
+        // Emplacements réservés des informations de format et de version
+        self.draw_format_bits(0);
+        self.draw_version_bits();
+    }
+
+    /// Informations de format (niveau de correction d'erreur et masque), en deux exemplaires
+    fn draw_format_bits(&mut self, mask: usize) {
+        let bits = format_bits(mask);
+        let bit = |i: usize| (bits >> i) & 1 == 1;
+        let size = self.size();
+
+        for i in 0..=5 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        // Module toujours noir
+        self.set_function(8, size - 8, true);
+    }
+
+    /// Informations de version (à partir de la version 7), en deux exemplaires
+    fn draw_version_bits(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let bits = version_bits(self.version);
+        let size = self.size();
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 == 1;
+            let (a, b) = (size - 11 + i % 3, i / 3);
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    /// Placement des octets en zigzag par paires de colonnes depuis le coin inférieur droit
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size();
+        let mut i = 0;
+        let mut right = size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vert in 0..size {
+                let y = if upward { size - 1 - vert } else { vert };
+                for x in [right, right - 1] {
+                    if !self.is_function[y][x] && i < codewords.len() * 8 {
+                        self.modules[y][x] = (codewords[i / 8] >> (7 - i % 8)) & 1 == 1;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 3 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// Inversion des modules de données selon le masque `mask`
+    fn apply_mask(&mut self, mask: usize) {
+        let size = self.size();
+        for y in 0..size {
+            for x in 0..size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                if invert && !self.is_function[y][x] {
+                    self.modules[y][x] = !self.modules[y][x];
+                }
+            }
+        }
+    }
+
+    /// Pénalité de la matrice (plus elle est faible, plus le QR code est facile à lire)
+    fn penalty(&self) -> usize {
+        let size = self.size();
+        let mut res = 0;
+
+        // Lignes et colonnes : suites de modules de même couleur et motifs semblables aux repères
+        for index in 0..size {
+            for line in [
+                (0..size)
+                    .map(|i| self.modules[index][i])
+                    .collect::<Vec<bool>>(),
+                (0..size)
+                    .map(|i| self.modules[i][index])
+                    .collect::<Vec<bool>>(),
+            ] {
+                let mut run = 1;
+                for i in 1..=size {
+                    if i < size && line[i] == line[i - 1] {
+                        run += 1;
+                    } else {
+                        if run >= 5 {
+                            res += run - 2;
+                        }
+                        run = 1;
+                    }
+                }
+
+                // Longueurs des suites de modules en alternant les couleurs, de la première suite
+                // claire (éventuellement vide) à la dernière suite claire, prolongées par la marge
+                // claire autour du QR code
+                let mut runs = vec![size];
+                let mut color = false;
+                for dark in line {
+                    if dark != color {
+                        runs.push(0);
+                        color = dark;
+                    }
+                    *runs.last_mut().unwrap() += 1;
+                }
+                if color {
+                    runs.push(0);
+                }
+                *runs.last_mut().unwrap() += size;
+
+                // Motif 1:1:3:1:1 précédé ou suivi d'au moins 4 modules clairs
+                for window in runs.windows(7).step_by(2) {
+                    let n = window[1];
+                    if window[1..6] == [n, n, 3 * n, n, n] {
+                        res += 40 * usize::from(window[0] >= 4 * n && window[6] >= n);
+                        res += 40 * usize::from(window[6] >= 4 * n && window[0] >= n);
+                    }
+                }
+            }
+        }
+
+        // Carrés de 2x2 modules de même couleur
+        for y in 1..size {
+            for x in 1..size {
+                let color = self.modules[y][x];
+                if self.modules[y - 1][x] == color
+                    && self.modules[y][x - 1] == color
+                    && self.modules[y - 1][x - 1] == color
+                {
+                    res += 3;
+                }
+            }
+        }
+
+        // Proportion de modules noirs
+        let total = size * size;
+        let dark = self.modules.iter().flatten().filter(|dark| **dark).count();
+        res += 10 * ((dark * 20).abs_diff(total * 10).div_ceil(total) - 1);
+        res
+    }
+}
+
+/// Positions des centres des motifs d'alignement (en ligne et en colonne)
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let size = 17 + 4 * version;
+    let mut res: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    res.push(6);
+    res.reverse();
+    res
+}
+
+/// Informations de format (15 bits avec le code BCH et le masque de format)
+fn format_bits(mask: usize) -> u32 {
+    let data = FORMAT_LEVEL_M << 3 | u32::try_from(mask).unwrap();
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+    }
+    (data << 10 | rem) ^ 0x5412
+}
+
+/// Informations de version (18 bits avec le code BCH)
+fn version_bits(version: usize) -> u32 {
+    let version = u32::try_from(version).unwrap();
+    let mut rem = version;
+    for _ in 0..12 {
+        rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+    }
+    version << 12 | rem
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_reed_solomon() {
+        // Exemple "HELLO WORLD" en version 1-M
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        assert_eq!(
+            reed_solomon_remainder(&data, &reed_solomon_divisor(10)),
+            vec![196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+        );
+    }
+
+    #[test]
+    fn test_format_version_bits() {
+        assert_eq!(format_bits(0), 0b101_0100_0001_0010);
+        assert_eq!(version_bits(7), 0x07C94);
+        assert_eq!(alignment_positions(7), vec![6, 22, 38]);
+        assert_eq!(alignment_positions(10), vec![6, 28, 50]);
+        assert_eq!(alignment_positions(32), vec![6, 34, 60, 86, 112, 138]);
+
+        // Nombre total d'octets de chaque version
+        for version in 1..=MAX_VERSION {
+            let (ec_len, blocks_1, _, blocks_2, _) = blocks(version);
+            let qr = {
+                let mut qr = QrMatrix::new(version);
+                qr.draw_function_patterns();
+                qr
+            };
+            let data_modules = qr.is_function.iter().flatten().filter(|f| !**f).count();
+            assert_eq!(
+                data_codewords(version) + ec_len * (blocks_1 + blocks_2),
+                data_modules / 8
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_qr_svg() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let svg = grid.to_qr_svg("").unwrap();
+        assert!(svg.starts_with("<svg "));
+        // Version 1 : 21 modules et 4 modules de marge de chaque côté
+        assert!(svg.contains("viewBox=\"0 0 29 29\""));
+
+        let modules = qr_modules(grid.encode().as_bytes()).unwrap();
+        // Repère du coin supérieur gauche
+        assert!(modules[0][..7].iter().all(|dark| *dark));
+        assert!(!modules[1][1]);
+
+        // Version 3 pour 42 octets
+        let svg = grid.to_qr_svg("https://example.com/suguru?p=").unwrap();
+        assert!(svg.contains("viewBox=\"0 0 37 37\""));
+
+        // Version 11 pour 231 octets (une grille de 15x15 cases), version 40 au plus
+        assert_eq!(qr_modules(&[b'x'; 231]).unwrap().len(), 61);
+        assert_eq!(qr_modules(&[b'x'; 2331]).unwrap().len(), 177);
+        assert!(grid.to_qr_svg(&"x".repeat(2332)).is_none());
+    }
+
+    #[test]
+    fn test_qr_modules_reference() {
+        // QR code de 'Suguru' produit par la bibliothèque qrcodegen (niveau M, masque 2)
+        let expected = [
+            "#######  #  # #######",
+            "#     #  ## # #     #",
+            "# ### # ##### # ### #",
+            "# ### # #  ## # ### #",
+            "# ### # ## ## # ### #",
+            "#     # #   # #     #",
+            "####### # # # #######",
+            "        # ###        ",
+            "# #####  ## # #####  ",
+            "     # ## # #  # ####",
+            " # # ####### #  #### ",
+            "# #    # ##    ######",
+            "# ####### ## #  #    ",
+            "        #  ##### ### ",
+            "#######     # ## # # ",
+            "#     # # #####  ## #",
+            "# ### # # # #    # # ",
+            "# ### # ### #   ###  ",
+            "# ### # # ## #   ##  ",
+            "#     #   #    ####  ",
+            "####### #### # # # # ",
+        ];
+        let modules = qr_modules(b"Suguru").unwrap();
+        let lines: Vec<String> = modules
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|dark| if *dark { '#' } else { ' ' })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(lines, expected);
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+
+use crate::grid::{Grid, GridParser, ParseGridError};
+use crate::parse_options::ParseOptions;
+
+/// Erreur rencontrée lors de la lecture d'une grille avec `Grid::from_reader`
+#[derive(Debug)]
+pub enum ReadGridError {
+    /// Erreur de lecture du texte de la grille
+    Io(io::Error),
+
+    /// Le texte n'a pas le format de `Grid::from_str`
+    Parse(ParseGridError),
+}
+
+impl fmt::Display for ReadGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadGridError::Io(e) => write!(f, "{e}"),
+            ReadGridError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for ReadGridError {}
+
+impl From<io::Error> for ReadGridError {
+    fn from(e: io::Error) -> Self {
+        ReadGridError::Io(e)
+    }
+}
+
+impl From<ParseGridError> for ReadGridError {
+    fn from(e: ParseGridError) -> Self {
+        ReadGridError::Parse(e)
+    }
+}
+
+/// Lignes d'un texte lues au fur et à mesure, sans fin de ligne, comme celles du texte
+/// `normalized_text` (sans BOM au début et avec les fins de ligne "\r\n" ou '\r' acceptées)
+pub(crate) struct SourceLines<R> {
+    reader: R,
+
+    // Lignes déjà lues mais pas encore retournées (une lecture jusqu'à '\n' peut contenir
+    // plusieurs lignes terminées par '\r')
+    pending: VecDeque<String>,
+
+    is_first: bool,
+}
+
+impl<R: BufRead> SourceLines<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        SourceLines {
+            reader,
+            pending: VecDeque::new(),
+            is_first: true,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for SourceLines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(line) = self.pending.pop_front() {
+            return Some(Ok(line));
+        }
+
+        let mut buffer = String::new();
+        match self.reader.read_line(&mut buffer) {
+            Err(e) => return Some(Err(e)),
+            Ok(0) => return None,
+            Ok(_) => (),
+        }
+        let mut text = buffer.as_str();
+        if self.is_first {
+            text = text.strip_prefix('\u{feff}').unwrap_or(text);
+            self.is_first = false;
+        }
+        if let Some(line) = text.strip_suffix('\n') {
+            text = line.strip_suffix('\r').unwrap_or(line);
+        }
+        self.pending.extend(text.split('\r').map(str::to_string));
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+impl Grid {
+    /// Lecture d'une grille au format de `Grid::from_str` depuis un fichier, l'entrée standard,
+    /// etc. : le texte est lu et analysé ligne par ligne
+    ///
+    /// # Errors
+    /// Une erreur est retournée si le texte ne peut pas être lu ou s'il n'a pas le format de
+    /// `Grid::from_str`
+    pub fn from_reader<R: Read>(reader: R) -> Result<Grid, ReadGridError> {
+        let options = ParseOptions::default();
+        let mut parser = GridParser::new(&options);
+        for (index, result_line) in SourceLines::new(BufReader::new(reader)).enumerate() {
+            parser.parse_source_line(index + 1, &result_line?)?;
+        }
+        Ok(parser.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_source_lines() {
+        let text = "\u{feff}a\r\nb\rc\n\nd";
+        let lines: Vec<String> = SourceLines::new(text.as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["a", "b", "c", "", "d"]);
+        let normalized = crate::grid::normalized_text(text);
+        assert_eq!(lines, normalized.lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let s = "# Exemple\r\na1 b  b2\r\nb4 b  b\r\nc  c  c2\r\n";
+        let grid = Grid::from_reader(s.as_bytes()).unwrap();
+        assert_eq!(grid, Grid::from_str(s).unwrap());
+
+        match Grid::from_reader("a1\n\nb0\n".as_bytes()) {
+            Err(ReadGridError::Parse(e)) => assert_eq!(e.source_line().unwrap().number, 3),
+            _ => panic!("Erreur attendue"),
+        }
+
+        // Texte qui n'est pas en UTF-8
+        let bytes: &[u8] = b"a1 \xff\n";
+        assert!(matches!(
+            Grid::from_reader(bytes),
+            Err(ReadGridError::Io(_))
+        ));
+    }
+}
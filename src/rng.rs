@@ -0,0 +1,69 @@
+/// Générateur de nombres pseudo-aléatoires (`SplitMix64`)
+///
+/// Les tirages sont reproductibles : une même graine produit toujours la même suite de nombres,
+/// quelle que soit la plateforme
+#[derive(Clone, Debug)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Générateur initialisé avec une graine
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    /// Nombre pseudo-aléatoire suivant
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Nombre pseudo-aléatoire entre 0 et `n` exclu (`n` > 0)
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        let n = u64::try_from(n).unwrap();
+        usize::try_from(self.next_u64() % n).unwrap()
+    }
+
+    /// Mélange les éléments d'un tableau (Fisher-Yates)
+    pub(crate) fn shuffle<T>(&mut self, values: &mut [T]) {
+        for index in (1..values.len()).rev() {
+            let other_index = self.below(index + 1);
+            values.swap(index, other_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_rng_reproducible() {
+        let mut rng_1 = Rng::new(42);
+        let mut rng_2 = Rng::new(42);
+        for _ in 0..10 {
+            assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+
+        for _ in 0..100 {
+            assert!(rng_1.below(7) < 7);
+        }
+    }
+
+    #[test]
+    fn test_rng_shuffle() {
+        let mut values: Vec<u8> = (1..=9).collect();
+        Rng::new(7).shuffle(&mut values);
+        let mut sorted_values = values.clone();
+        sorted_values.sort_unstable();
+        assert_eq!(sorted_values, (1..=9).collect::<Vec<u8>>());
+
+        let mut values_2: Vec<u8> = (1..=9).collect();
+        Rng::new(7).shuffle(&mut values_2);
+        assert_eq!(values, values_2);
+    }
+}
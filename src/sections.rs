@@ -0,0 +1,148 @@
+use crate::grid::{cells_text, normalized_text, Grid, ParseGridError};
+
+/// En-tête de la section des zones
+const ZONES_SECTION: &str = "[zones]";
+
+/// En-tête de la section des chiffres
+const VALUES_SECTION: &str = "[values]";
+
+impl Grid {
+    /// Construction d'une grille à partir d'un texte en deux sections de même forme : la section
+    /// `[zones]` donne la zone de chaque case et la section `[values]` donne son chiffre (ou un
+    /// point si le chiffre n'est pas connu) :
+    ///
+    /// ```text
+    /// [zones]
+    /// abb
+    /// bbb
+    /// ccc
+    ///
+    /// [values]
+    /// 1.2
+    /// 4..
+    /// ..2
+    /// ```
+    ///
+    /// Dans une ligne sans espace, chaque caractère est une case. Dans une ligne avec des espaces,
+    /// les cases sont séparées par des espaces (une zone peut alors être désignée par plusieurs
+    /// lettres).
+    ///
+    /// Les lignes vides et les lignes de commentaires (qui commencent par `#`) sont ignorées
+    ///
+    /// # Errors
+    /// Une erreur est retournée (ligne, colonne et texte de la case) si une section manque, si
+    /// les deux sections n'ont pas la même forme, si une zone n'est pas désignée par des lettres ou
+    /// si un chiffre n'est pas entre 1 et 9
+    pub fn from_sections(s: &str) -> Result<Grid, ParseGridError> {
+        let mut option_zones: Option<Vec<Vec<String>>> = None;
+        let mut option_values: Option<Vec<Vec<String>>> = None;
+        let mut current_section: Option<&mut Vec<Vec<String>>> = None;
+
+        let s = normalized_text(s);
+        for str_line in s.lines() {
+            let str_line = cells_text(str_line);
+            if str_line.is_empty() {
+                continue;
+            }
+            match str_line.to_lowercase().as_str() {
+                ZONES_SECTION => current_section = Some(option_zones.insert(Vec::new())),
+                VALUES_SECTION => current_section = Some(option_values.insert(Vec::new())),
+                _ => match current_section.as_mut() {
+                    Some(section) => section.push(split_tokens(str_line)),
+                    None => return Err(ParseGridError::InvalidLine(str_line.to_string())),
+                },
+            }
+        }
+
+        let zones = option_zones
+            .ok_or_else(|| ParseGridError::MissingSection(ZONES_SECTION.to_string()))?;
+        let values = option_values
+            .ok_or_else(|| ParseGridError::MissingSection(VALUES_SECTION.to_string()))?;
+
+        let mut grid = Grid::default();
+        for line in 0..zones.len().max(values.len()) {
+            let line_zones = zones.get(line).map(Vec::as_slice).unwrap_or_default();
+            let line_values = values.get(line).map(Vec::as_slice).unwrap_or_default();
+            let i_line = i32::try_from(line).unwrap();
+            for column in 0..line_zones.len().max(line_values.len()) {
+                let i_column = i32::try_from(column).unwrap();
+                let (name, str_value) = match (line_zones.get(column), line_values.get(column)) {
+                    (Some(name), Some(str_value)) => (name, str_value),
+                    (Some(token), None) | (None, Some(token)) => {
+                        return Err(ParseGridError::shape_mismatch(i_line, i_column, token))
+                    }
+                    (None, None) => unreachable!(),
+                };
+                if !Grid::is_valid_zone_name(name) {
+                    return Err(ParseGridError::invalid_token(i_line, i_column, name));
+                }
+                let content = match str_value.as_str() {
+                    "." => None,
+                    _ => Some(ParseGridError::parse_digit(i_line, i_column, str_value)?),
+                };
+                let c_zone = grid.zone_id(name)?;
+                grid.add_cell((i_line, i_column), c_zone, content);
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+/// Cases d'une ligne d'une section : séparées par des espaces ou un caractère par case
+fn split_tokens(str_line: &str) -> Vec<String> {
+    if str_line.contains(char::is_whitespace) {
+        str_line.split_whitespace().map(str::to_string).collect()
+    } else {
+        str_line.chars().map(|c| c.to_string()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_sections() {
+        let grid = Grid::from_sections(
+            "
+        # Zones et chiffres dans deux sections
+        [zones]
+        abb
+        bbb
+        ccc
+
+        [values]
+        1.2
+        4..
+        ..2
+        ",
+        )
+        .unwrap();
+        assert_eq!(grid, Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap());
+
+        // Cases séparées par des espaces
+        let grid = Grid::from_sections("[values]\n1 . 2\n[zones]\naa ab ab\n").unwrap();
+        assert_eq!(grid.to_puzzle_string(), "aa1 ab  ab2\n");
+    }
+
+    #[test]
+    fn test_from_sections_nok() {
+        let error = Grid::from_sections("[zones]\nab\n").unwrap_err();
+        assert_eq!(
+            error,
+            ParseGridError::MissingSection("[values]".to_string())
+        );
+
+        let error = Grid::from_sections("[zones]\nab\nbb\n[values]\n1.\n.\n").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (1, 1, "b"));
+
+        let error = Grid::from_sections("[zones]\nab\n[values]\n10\n").unwrap_err();
+        assert_eq!((error.line(), error.column(), error.token()), (0, 1, "0"));
+
+        let error = Grid::from_sections("ab\n[zones]\nab\n[values]\n1.\n").unwrap_err();
+        assert_eq!(error, ParseGridError::InvalidLine("ab".to_string()));
+    }
+}
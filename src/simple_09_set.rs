@@ -28,9 +28,28 @@ fn not_digit_mask_bit(digit: u8) -> u16 {
 /// Cette structure permet de gérer un set de chiffres de 0 à 9 (1 digit)
 /// Comme le nombre d'éléments est limité à 10 chiffres, on utilise les
 /// bits d'un u16 pour marquer les éléments du set
+///
+/// Avec la feature `serde`, le set est sérialisé par la liste triée de ses chiffres
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<u8>", into = "Vec<u8>")
+)]
 pub struct Simple09Set(u16);
 
+impl From<Vec<u8>> for Simple09Set {
+    fn from(digits: Vec<u8>) -> Self {
+        Simple09Set::new(&digits)
+    }
+}
+
+impl From<Simple09Set> for Vec<u8> {
+    fn from(simple_09_set: Simple09Set) -> Self {
+        simple_09_set.as_vec_u8()
+    }
+}
+
 impl fmt::Display for Simple09Set {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self.as_vec_u8())
@@ -1,20 +1,22 @@
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use crate::action_formatter::ActionFormatter;
 use crate::grid::{CellContent, Grid};
+use crate::lang::{Lang, Localized};
 use crate::line_column::LineColumn;
 use crate::neighboring_line_columns::NeighboringLineColumns;
+use crate::observer::{CellObserver, CellObservers};
 use crate::simple_09_set::Simple09Set;
-
-// Niveau max de récursion par défaut avec la fonction récursive `solve_try_and_see`.
-// Cette fonction peut être appelée récursivement si la grille à résoudre
-// est très complexe (ou si elle est en cours de construction)
-// On stoppe les niveaux trop élevés de recherche par récursion qui correspondrait
-// à une solution trop difficile à trouver
-const DEFAULT_MAX_TRY_AND_SEE_RECURSION_LEVEL: i32 = 3;
+use crate::solver_config::{SolverConfig, DEFAULT_MAX_TRY_AND_SEE_RECURSION_LEVEL};
 
 /// Options lors de la résolution
+///
+/// Ces options sont conservées pour compatibilité avec `Solver::solve_with_options`.
+/// La configuration d'une résolution se fait de préférence avec `SolverConfig`
 pub enum SolvingOption {
     /// Affichage de l'action faite à chaque étape de la résolution
     StepPrintAction,
@@ -32,22 +34,21 @@ pub enum SolvingOption {
     /// Une valeur de 0, inhibe cette possibilité qui peut mener à des temps de calculs relativement long
     /// Une valeur d'au moins 3 est nécessaire pour des grilles très très difficiles
     MaxTryAndSeeRecursionLevel(i32),
-}
 
-impl SolvingOption {
-    fn get_max_try_and_see_recursion_level(options: &[SolvingOption], default_level: i32) -> i32 {
-        for option in options {
-            if let SolvingOption::MaxTryAndSeeRecursionLevel(level) = option {
-                return *level;
-            }
-        }
+    /// Recherche par 'essai' en approfondissement itératif : on cherche d'abord une incohérence
+    /// (ou une solution) avec un seul niveau de récursion pour toutes les cases, puis avec 2 niveaux, etc.
+    /// jusqu'au niveau max de récursion
+    /// Cela permet de trouver rapidement les réfutations simples et d'évaluer au plus juste la difficulté
+    TryAndSeeIterativeDeepening,
 
-        default_level
-    }
+    /// Les éliminations de chiffres (dans une zone ou dans les cases voisines) sont appliquées à toute
+    /// la grille en une seule étape de résolution qui retourne alors une action `SolvingAction::Batch`
+    BatchEliminations,
 }
 
 /// Action possible effectuée à chaque étape de résolution
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SolvingAction {
     /// La grille est résolue
     Solved,
@@ -59,16 +60,22 @@ pub enum SolvingAction {
     SinglePossibleNumber(LineColumn, u8),
 
     /// Suppression des chiffres possibles d'une case qui sont déjà dans la zone de cette case
-    NumbersInZone(LineColumn, char, Vec<u8>),
+    NumbersInZone(LineColumn, char, Vec<u8>, CandidatesChange),
 
     /// Seule case possible pour un chiffre d'une zone
     OnlyNumberInZone(char, LineColumn, u8),
 
     /// Suppression des chiffres d'une case qui sont déjà dans une de ses cases voisines
-    NumbersNeighboring(LineColumn, Vec<u8>),
+    NumbersNeighboring(LineColumn, Vec<u8>, CandidatesChange),
 
     /// Suppression des chiffres d'une paire de valeurs dans les cases voisines
-    DualValuesPair(LineColumn, LineColumn, LineColumn, Vec<u8>),
+    DualValuesPair(
+        LineColumn,
+        LineColumn,
+        LineColumn,
+        Vec<u8>,
+        CandidatesChange,
+    ),
 
     // Force une valeur dans une paire de possibilité car elle mène une solution
     // après évaluation de la résolution en testant cette valeur
@@ -78,123 +85,283 @@ pub enum SolvingAction {
     // après évaluation de la résolution en testant cette valeur
     TryAndFail(LineColumn, u8, u8),
 
+    /// Ensemble d'actions appliquées en une seule étape de résolution
+    Batch(Vec<SolvingAction>),
+
     /// Aucune action de résolution trouvée
     NoAction,
 }
 
-impl fmt::Display for SolvingAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl SolvingAction {
+    /// Regroupe une liste d'actions en une seule action
+    fn from_vec(mut vec_actions: Vec<SolvingAction>) -> Self {
+        match vec_actions.len() {
+            0 => SolvingAction::NoAction,
+            1 => vec_actions.pop().unwrap(),
+            _ => SolvingAction::Batch(vec_actions),
+        }
+    }
+
+    /// Affichage dans la langue choisie
+    #[must_use]
+    pub fn display_in(&self, lang: Lang) -> Localized<'_, Self> {
+        Localized::new(self, lang)
+    }
+
+    /// Texte de l'action selon une mise en forme personnalisée
+    #[must_use]
+    pub fn format_with(&self, formatter: &dyn ActionFormatter) -> String {
+        formatter.format_action(self)
+    }
+
+    /// Type de l'action (sans ses données)
+    #[must_use]
+    pub fn kind(&self) -> SolvingActionKind {
         match self {
-            Self::Solved => {
-                write!(f, "Grille résolue")
-            }
-            Self::InitPossibleNumbers => {
-                write!(f, "Initialisation des chiffres possibles des cases...")
-            }
-            Self::SinglePossibleNumber(line_column, n) => {
-                write!(
-                    f,
-                    "[{n}] est la seule possibilité pour la case {line_column}"
-                )
-            }
-            Self::OnlyNumberInZone(c_zone, line_column, n) => {
-                write!(
-                    f,
-                    "Zone '{c_zone}', seule la case {line_column} est possible pour [{n}]"
-                )
-            }
-            Self::NumbersInZone(line_column, c_zone, vec_n) => {
-                write!(
-                    f,
-                    "{vec_n:?} déjà placé dans la zone '{c_zone}' de la case {line_column}"
-                )
-            }
-            Self::NumbersNeighboring(line_column, vec_n) => {
-                write!(
-                    f,
-                    "{vec_n:?} est dans les cases voisines de la case {line_column}"
-                )
-            }
-            Self::DualValuesPair(line_column_pair_1, line_column_pair_2, line_column, vec_n) => {
-                write!(
-                    f,
-                    "{vec_n:?} impossible dans la case {line_column} selon les cases voisines {line_column_pair_1} et {line_column_pair_2}"
-                )
-            }
-            Self::TryAndSolve(line_column, n_ok, autre_n) => {
-                write!(
-                    f,
-                    "Entre [{n_ok}] et [{autre_n}] pour {line_column}, [{n_ok}] mène à une solution"
-                )
-            }
-            Self::TryAndFail(line_column, n_fail, n_ok) => {
-                write!(
-                    f,
-                    "[{n_ok}] est placé pour {line_column} car le choix de [{n_fail}] mène à une incohérence"
-                )
-            }
-            SolvingAction::NoAction => {
-                write!(f, "Aucune action de résolution trouvée")
-            }
+            Self::Solved => SolvingActionKind::Solved,
+            Self::InitPossibleNumbers => SolvingActionKind::InitPossibleNumbers,
+            Self::SinglePossibleNumber(..) => SolvingActionKind::SinglePossibleNumber,
+            Self::NumbersInZone(..) => SolvingActionKind::NumbersInZone,
+            Self::OnlyNumberInZone(..) => SolvingActionKind::OnlyNumberInZone,
+            Self::NumbersNeighboring(..) => SolvingActionKind::NumbersNeighboring,
+            Self::DualValuesPair(..) => SolvingActionKind::DualValuesPair,
+            Self::TryAndSolve(..) => SolvingActionKind::TryAndSolve,
+            Self::TryAndFail(..) => SolvingActionKind::TryAndFail,
+            Self::Batch(_) => SolvingActionKind::Batch,
+            Self::NoAction => SolvingActionKind::NoAction,
+        }
+    }
+
+    /// Stratégie de résolution qui a produit cette action
+    /// (pour une action `Batch`, la stratégie de sa première action)
+    #[must_use]
+    pub fn strategy(&self) -> Option<SolvingStrategy> {
+        match self {
+            Self::SinglePossibleNumber(..) => Some(SolvingStrategy::SinglePossibleNumber),
+            Self::NumbersInZone(..) => Some(SolvingStrategy::NumbersInZone),
+            Self::OnlyNumberInZone(..) => Some(SolvingStrategy::OnlyNumberInZone),
+            Self::NumbersNeighboring(..) => Some(SolvingStrategy::NumbersNeighboring),
+            Self::DualValuesPair(..) => Some(SolvingStrategy::DualValuesPair),
+            Self::TryAndSolve(..) | Self::TryAndFail(..) => Some(SolvingStrategy::TryAndSee),
+            Self::Batch(vec_actions) => vec_actions.first().and_then(SolvingAction::strategy),
+            Self::Solved | Self::InitPossibleNumbers | Self::NoAction => None,
+        }
+    }
+
+    /// Cases dont le contenu est modifié par cette action
+    /// (pour une action `Batch`, les cases modifiées par toutes ses actions)
+    #[must_use]
+    pub fn affected_cells(&self) -> Vec<LineColumn> {
+        match self {
+            Self::SinglePossibleNumber(line_column, _)
+            | Self::NumbersInZone(line_column, _, _, _)
+            | Self::OnlyNumberInZone(_, line_column, _)
+            | Self::NumbersNeighboring(line_column, _, _)
+            | Self::DualValuesPair(_, _, line_column, _, _)
+            | Self::TryAndSolve(line_column, _, _)
+            | Self::TryAndFail(line_column, _, _) => vec![*line_column],
+            Self::Batch(vec_actions) => vec_actions
+                .iter()
+                .flat_map(SolvingAction::affected_cells)
+                .collect(),
+            Self::Solved | Self::InitPossibleNumbers | Self::NoAction => Vec::new(),
+        }
+    }
+
+    /// Chiffre placé dans la case modifiée par cette action (si l'action place un chiffre)
+    #[must_use]
+    pub fn placed_digit(&self) -> Option<u8> {
+        match self {
+            Self::SinglePossibleNumber(_, n)
+            | Self::OnlyNumberInZone(_, _, n)
+            | Self::TryAndSolve(_, n, _)
+            | Self::TryAndFail(_, _, n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Chiffres supprimés des possibilités de la case modifiée par cette action
+    /// (si l'action est une élimination de chiffres)
+    #[must_use]
+    pub fn removed_digits(&self) -> &[u8] {
+        match self {
+            Self::NumbersInZone(_, _, vec_n, _)
+            | Self::NumbersNeighboring(_, vec_n, _)
+            | Self::DualValuesPair(_, _, _, vec_n, _) => vec_n,
+            _ => &[],
         }
     }
+
+    /// Chiffres possibles de la case modifiée avant et après cette action
+    /// (si l'action est une élimination de chiffres)
+    #[must_use]
+    pub fn candidates_change(&self) -> Option<&CandidatesChange> {
+        match self {
+            Self::NumbersInZone(_, _, _, change)
+            | Self::NumbersNeighboring(_, _, change)
+            | Self::DualValuesPair(_, _, _, _, change) => Some(change),
+            _ => None,
+        }
+    }
+}
+
+/// Chiffres possibles d'une case avant et après une élimination de chiffres
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandidatesChange {
+    /// Chiffres possibles avant l'élimination
+    pub before: Vec<u8>,
+
+    /// Chiffres possibles après l'élimination
+    pub after: Vec<u8>,
+}
+
+impl CandidatesChange {
+    fn new(before: Simple09Set, after: Simple09Set) -> Self {
+        CandidatesChange {
+            before: before.as_vec_u8(),
+            after: after.as_vec_u8(),
+        }
+    }
+}
+
+/// Type d'une action de résolution (voir `SolvingAction::kind`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolvingActionKind {
+    Solved,
+    InitPossibleNumbers,
+    SinglePossibleNumber,
+    NumbersInZone,
+    OnlyNumberInZone,
+    NumbersNeighboring,
+    DualValuesPair,
+    TryAndSolve,
+    TryAndFail,
+    Batch,
+    NoAction,
+}
+
+impl fmt::Display for SolvingAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_in(Lang::French))
+    }
 }
 
 /// Cas d'erreurs possibles pendant la résolution de la grille tectonic
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SolvingError {
-    /// Zone avec trop de cases
-    ZoneTooLong(char),
+    /// Zone avec trop de cases (nom de la zone, nombre de cases de la zone)
+    ZoneTooLong(String, usize),
+
+    /// Case avec un chiffre plus grand que la taille de la zone (nom de la zone, case, chiffre)
+    ZoneWithUnexpectedNumber(String, LineColumn, u8),
 
-    /// Case avec un chiffre plus grand que la taille de la zone
-    ZoneWithUnexpectedNumber(char, LineColumn, u8),
+    /// Zone dont les cases ne sont pas toutes reliées entre elles horizontalement ou
+    /// verticalement (nom de la zone)
+    ZoneNotContiguous(String),
 
-    /// Deux cases voisines avec le même chiffre
+    /// Deux cases voisines avec le même chiffre (case, case voisine, chiffre)
     NeighboringWithSameNumber(LineColumn, LineColumn, u8),
 
-    /// Deux cases d'une même zone avec le même chiffre
-    ZoneWithSameNumber(char, u8),
+    /// Deux cases d'une même zone avec le même chiffre (nom de la zone, première case, seconde
+    /// case, chiffre)
+    /// Les 2 cases sont triées par ligne puis colonne
+    ZoneWithSameNumber(String, LineColumn, LineColumn, u8),
 
-    /// Aucun chiffre possible pour une case
-    NoPossibleNumber(LineColumn),
+    /// Aucun chiffre possible pour une case (case, nom de la zone de la case)
+    NoPossibleNumber(LineColumn, String),
+
+    /// La grille n'a aucune solution
+    NoSolution,
+
+    /// L'action à cet index d'une séquence rejouée par `Solver::replay` ne s'applique pas
+    ActionNotApplicable(usize),
 
     /// Erreur d'implémentation qui ne devrait pas arriver :)
     BadImplementation,
+
+    /// Erreur accompagnée de l'état de la grille au moment de l'erreur (voir
+    /// `SolverConfig::snapshot_on_error`)
+    WithGrid(Box<SolvingError>, String),
 }
 
-impl fmt::Display for SolvingError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl SolvingError {
+    /// Ajoute à l'erreur l'état de la grille au moment de l'erreur
+    #[must_use]
+    pub fn with_grid(self, grid: &Grid) -> Self {
         match self {
-            SolvingError::ZoneTooLong(c_zone) => {
-                write!(f, "La zone '{c_zone}' est trop grande")
-            }
-            SolvingError::ZoneWithUnexpectedNumber(c_zone, line_column, n) => {
-                write!(
-                    f,
-                    "Le chiffre '{n}' en {line_column} n'est pas possible dans la zone '{c_zone}'"
-                )
-            }
-            SolvingError::NeighboringWithSameNumber(line_column_1, line_column_2, n) => {
-                write!(f, "Le chiffre {n} apparaît dans les cases voisines {line_column_1} et {line_column_2}")
-            }
-            Self::ZoneWithSameNumber(c_zone, n) => {
-                write!(
-                    f,
-                    "Le chiffre '{n}' apparaît plusieurs fois dans la zone '{c_zone}'"
-                )
-            }
-            Self::NoPossibleNumber(line_column) => {
-                write!(f, "Aucun chiffre possible dans la case {line_column}")
-            }
-            SolvingError::BadImplementation => write!(f, "Erreur inattendue (voir source code...)"),
+            SolvingError::WithGrid(error, _) => SolvingError::WithGrid(error, grid.to_string()),
+            error => SolvingError::WithGrid(Box::new(error), grid.to_string()),
+        }
+    }
+
+    /// Erreur d'origine (sans l'état de la grille éventuellement ajouté)
+    #[must_use]
+    pub fn root_cause(&self) -> &SolvingError {
+        match self {
+            SolvingError::WithGrid(error, _) => error,
+            error => error,
         }
     }
+
+    /// État de la grille au moment de l'erreur (s'il a été ajouté à l'erreur)
+    #[must_use]
+    pub fn grid_snapshot(&self) -> Option<&str> {
+        match self {
+            SolvingError::WithGrid(_, grid) => Some(grid),
+            _ => None,
+        }
+    }
+
+    /// Affichage dans la langue choisie
+    #[must_use]
+    pub fn display_in(&self, lang: Lang) -> Localized<'_, Self> {
+        Localized::new(self, lang)
+    }
+}
+
+impl fmt::Display for SolvingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_in(Lang::French))
+    }
 }
 
 impl std::error::Error for SolvingError {}
 
+/// Résultat d'une résolution complète avec `Solver::solve`
+#[derive(Debug)]
+#[must_use]
+pub enum SolveOutcome {
+    /// La grille est résolue
+    Solved,
+
+    /// La résolution est bloquée : les stratégies disponibles ne trouvent plus d'action possible
+    Stuck {
+        /// Cases dont le chiffre n'est pas encore connu
+        unresolved_cells: Vec<LineColumn>,
+
+        /// Stratégie la plus difficile tentée avant de s'arrêter
+        hardest_technique_tried: SolvingStrategy,
+    },
+
+    /// La grille n'est pas (ou plus) cohérente
+    Inconsistent(SolvingError),
+}
+
+impl SolveOutcome {
+    /// Retourne true si la grille est résolue
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        matches!(self, SolveOutcome::Solved)
+    }
+}
+
 /// Niveau de difficulté rencontré pendant la résolution
+///
+/// Les niveaux correspondent à une échelle de 1 à 5 étoiles (voir `as_u8` et `from_score`)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DifficultyLevel {
     #[default]
     Unknown,
@@ -203,25 +370,193 @@ pub enum DifficultyLevel {
     Medium,
     Hard,
     VeryHard,
+
+    /// Recherches par 'essai' imbriquées (un essai nécessite lui-même des recherches par 'essai')
+    Expert,
+}
+
+impl DifficultyLevel {
+    /// Liste de tous les niveaux connus, du plus facile au plus difficile
+    pub const ALL: [DifficultyLevel; 5] = [
+        DifficultyLevel::Easy,
+        DifficultyLevel::Medium,
+        DifficultyLevel::Hard,
+        DifficultyLevel::VeryHard,
+        DifficultyLevel::Expert,
+    ];
+
+    /// Note du niveau de difficulté : de 1 (facile) à 5 (expert), 0 si la difficulté est inconnue
+    #[must_use]
+    pub fn as_u8(self) -> u8 {
+        match self {
+            DifficultyLevel::Unknown => 0,
+            DifficultyLevel::Easy => 1,
+            DifficultyLevel::Medium => 2,
+            DifficultyLevel::Hard => 3,
+            DifficultyLevel::VeryHard => 4,
+            DifficultyLevel::Expert => 5,
+        }
+    }
+
+    /// Niveau de difficulté d'après une note de 1 (facile) à 5 (expert)
+    /// Une note de 0 correspond à une difficulté inconnue et une note supérieure à 5 au niveau expert
+    #[must_use]
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            0 => DifficultyLevel::Unknown,
+            1 => DifficultyLevel::Easy,
+            2 => DifficultyLevel::Medium,
+            3 => DifficultyLevel::Hard,
+            4 => DifficultyLevel::VeryHard,
+            _ => DifficultyLevel::Expert,
+        }
+    }
+
+    /// Affichage dans la langue choisie
+    #[must_use]
+    pub fn display_in(&self, lang: Lang) -> Localized<'_, Self> {
+        Localized::new(self, lang)
+    }
 }
 
 impl fmt::Display for DifficultyLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_in(Lang::French))
+    }
+}
+
+/// Stratégies de résolution utilisées par le solver
+///
+/// Les stratégies sont ordonnées de la plus simple à la plus difficile
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolvingStrategy {
+    /// Case avec qu'une seule possibilité de chiffre
+    SinglePossibleNumber,
+
+    /// Suppression des chiffres possibles d'une case qui sont déjà dans la zone de cette case
+    NumbersInZone,
+
+    /// Seule case possible pour un chiffre d'une zone
+    OnlyNumberInZone,
+
+    /// Suppression des chiffres d'une case qui sont déjà dans une de ses cases voisines
+    NumbersNeighboring,
+
+    /// Suppression des chiffres d'une paire de valeurs dans les cases voisines
+    DualValuesPair,
+
+    /// Recherche par 'essai' d'une valeur dans une paire de possibilités
+    TryAndSee,
+}
+
+impl SolvingStrategy {
+    /// Liste de toutes les stratégies, de la plus simple à la plus difficile
+    pub const ALL: [SolvingStrategy; 6] = [
+        SolvingStrategy::SinglePossibleNumber,
+        SolvingStrategy::NumbersInZone,
+        SolvingStrategy::OnlyNumberInZone,
+        SolvingStrategy::NumbersNeighboring,
+        SolvingStrategy::DualValuesPair,
+        SolvingStrategy::TryAndSee,
+    ];
+
+    /// Niveau de difficulté associé à cette stratégie
+    #[must_use]
+    pub fn difficulty_level(self) -> DifficultyLevel {
         match self {
-            Self::Unknown => write!(f, "Difficulté inconnue"),
-            Self::Easy => write!(f, "Difficulté facile"),
-            Self::Medium => write!(f, "Difficulté moyenne"),
-            Self::Hard => write!(f, "Difficile"),
-            Self::VeryHard => write!(f, "Très difficile"),
+            SolvingStrategy::SinglePossibleNumber
+            | SolvingStrategy::NumbersInZone
+            | SolvingStrategy::OnlyNumberInZone => DifficultyLevel::Easy,
+            SolvingStrategy::NumbersNeighboring => DifficultyLevel::Medium,
+            SolvingStrategy::DualValuesPair => DifficultyLevel::Hard,
+            SolvingStrategy::TryAndSee => DifficultyLevel::VeryHard,
         }
     }
 }
 
+impl SolvingStrategy {
+    /// Affichage dans la langue choisie
+    #[must_use]
+    pub fn display_in(&self, lang: Lang) -> Localized<'_, Self> {
+        Localized::new(self, lang)
+    }
+}
+
+impl fmt::Display for SolvingStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_in(Lang::French))
+    }
+}
+
+/// Temps passé dans chaque stratégie de résolution (voir `SolverConfig::measure_timings`)
+///
+/// Le temps de la stratégie `SolvingStrategy::TryAndSee` inclut le temps passé à résoudre les
+/// grilles testées lors des recherches par 'essai' (`try_and_see_recursion`)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SolverTimings {
+    /// Temps cumulé passé dans chaque stratégie
+    pub strategies: HashMap<SolvingStrategy, Duration>,
+
+    /// Temps cumulé passé à résoudre les grilles testées lors des recherches par 'essai'
+    pub try_and_see_recursion: Duration,
+}
+
+impl SolverTimings {
+    /// Temps cumulé passé dans une stratégie
+    #[must_use]
+    pub fn strategy(&self, strategy: SolvingStrategy) -> Duration {
+        self.strategies.get(&strategy).copied().unwrap_or_default()
+    }
+
+    /// Temps cumulé passé dans toutes les stratégies
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.strategies.values().sum()
+    }
+}
+
+/// Statistiques de la recherche pendant la résolution
+///
+/// Ces statistiques cumulent les étapes faites par le solver principal et par tous les solvers
+/// créés lors des recherches par 'essai'
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Nombre d'étapes de résolution explorées
+    pub nodes_explored: usize,
+
+    /// Nombre d'essais (valeur forcée dans une case) évalués
+    pub trials: usize,
+
+    /// Niveau de récursion maximal atteint lors des recherches par 'essai'
+    pub max_depth_reached: i32,
+
+    /// Nombre de copies du contenu des cases de la grille effectuées
+    pub grid_clones: usize,
+}
+
+impl SearchStats {
+    /// Cumule les statistiques d'une recherche annexe
+    fn merge(&mut self, other: &SearchStats) {
+        self.nodes_explored += other.nodes_explored;
+        self.trials += other.trials;
+        self.max_depth_reached = i32::max(self.max_depth_reached, other.max_depth_reached);
+        self.grid_clones += other.grid_clones;
+    }
+}
+
 /// Structure pour la résolution d'une grille tectonic
-#[derive(Debug, Default)]
+///
+/// La grille d'origine (zones et positions des cases) est partagée et n'est jamais modifiée :
+/// le solver ne conserve que le contenu de travail des cases (chiffres placés et chiffres possibles).
+/// Les solvers créés lors des recherches par 'essai' partagent ainsi la même grille d'origine.
+#[derive(Debug)]
 pub struct Solver {
-    /// Grille tectonic
-    grid: Grid,
+    /// Grille tectonic d'origine
+    original_grid: Arc<Grid>,
+
+    /// Contenu de travail des cases de la grille
+    contents: HashMap<LineColumn, CellContent>,
 
     /// True lorsque toutes les cases avec un contenu `Undefined` ont été traitées
     init_cell_contents: bool,
@@ -234,11 +569,88 @@ pub struct Solver {
 
     /// Niveau de récursion dans la rechercher try & see
     pub try_and_see_recursion_level: i32,
+
+    /// Recherche try & see en approfondissement itératif
+    pub try_and_see_iterative_deepening: bool,
+
+    /// Éliminations appliquées à toute la grille en une seule étape
+    pub batch_eliminations: bool,
+
+    /// Stratégies de résolution utilisées (de la plus simple à la plus difficile)
+    strategies: Vec<SolvingStrategy>,
+
+    /// Niveaux de difficulté personnalisés des stratégies (voir `SolverConfig::difficulty_level`)
+    difficulty_levels: HashMap<SolvingStrategy, DifficultyLevel>,
+
+    /// Statistiques de la recherche
+    search_stats: SearchStats,
+
+    /// Mesure du temps passé dans chaque stratégie
+    measure_timings: bool,
+
+    /// Temps passé dans chaque stratégie (si `measure_timings`)
+    timings: SolverTimings,
+
+    /// États de travail sauvegardés par `snapshot` (par libellé)
+    snapshots: HashMap<String, SolverSnapshot>,
+
+    /// Actions effectuées depuis la construction du solver (ou depuis le dernier `reset`)
+    history: Vec<SolvingAction>,
+
+    /// Enregistrement des actions effectuées dans `history`
+    /// (inutile pour les solvers créés lors des recherches par 'essai')
+    record_history: bool,
+
+    /// Observateurs notifiés à chaque modification du contenu d'une case
+    observers: CellObservers,
+}
+
+/// État de travail d'un solver sauvegardé par `Solver::snapshot`
+#[derive(Clone, Debug)]
+struct SolverSnapshot {
+    contents: HashMap<LineColumn, CellContent>,
+    init_cell_contents: bool,
+    difficulty_level: DifficultyLevel,
+    history: Vec<SolvingAction>,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new(Grid::default())
+    }
+}
+
+/// Grille d'origine partagée d'un solver (voir `Solver::new`) : une grille empruntée (`&Grid`) est
+/// copiée, une grille déplacée (`Grid`) ou partagée (`Arc<Grid>`) ne l'est pas
+pub trait IntoSharedGrid {
+    /// Grille partagée
+    fn into_shared_grid(self) -> Arc<Grid>;
+}
+
+impl IntoSharedGrid for &Grid {
+    fn into_shared_grid(self) -> Arc<Grid> {
+        Arc::new(self.clone())
+    }
+}
+
+impl IntoSharedGrid for Grid {
+    fn into_shared_grid(self) -> Arc<Grid> {
+        Arc::new(self)
+    }
+}
+
+impl IntoSharedGrid for Arc<Grid> {
+    fn into_shared_grid(self) -> Arc<Grid> {
+        self
+    }
 }
 
 impl fmt::Display for Solver {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.grid)
+        if f.alternate() {
+            return write!(f, "{:#}", self.to_grid());
+        }
+        write!(f, "{}", self.to_grid())
     }
 }
 
@@ -247,110 +659,509 @@ impl Solver {
     /// Il faut utiliser en suite l'une des primitives de résolution :
     /// * `solve` : Pour rechercher une solution
     /// * `solve_step` : Pour les différentes étapes de résolution
+    ///
+    /// La grille d'origine n'est pas modifiée par la résolution : seul le contenu des cases est
+    /// dupliqué. Une grille empruntée (`&Grid`) est copiée, une grille déplacée (`Grid`) ou
+    /// partagée (`Arc<Grid>`) ne l'est pas (voir `IntoSharedGrid`).
     #[must_use]
-    pub fn new(grid: &Grid) -> Self {
+    pub fn new(grid: impl IntoSharedGrid) -> Self {
+        let original_grid = grid.into_shared_grid();
+        let contents = Self::grid_contents(&original_grid);
+        Self::from_contents(original_grid, contents)
+    }
+
+    /// Constructeur de l'algorithme de résolution qui vérifie immédiatement la structure de
+    /// la grille (taille des zones, chiffres compatibles avec la taille de leur zone, etc.)
+    /// # Errors
+    /// Une erreur est retournée si la structure de la grille est incorrecte
+    pub fn try_new(grid: impl IntoSharedGrid) -> Result<Self, SolvingError> {
+        let solver = Self::new(grid);
+        solver.check_initial()?;
+        Ok(solver)
+    }
+
+    /// Contenu des cases d'une grille
+    fn grid_contents(grid: &Grid) -> HashMap<LineColumn, CellContent> {
+        grid.hashmap_cells
+            .iter()
+            .map(|(line_column, cell)| (*line_column, cell.content.clone()))
+            .collect()
+    }
+
+    /// Constructeur d'après une grille partagée et un contenu de travail des cases
+    fn from_contents(original_grid: Arc<Grid>, contents: HashMap<LineColumn, CellContent>) -> Self {
         Solver {
-            grid: grid.clone(),
+            original_grid,
+            contents,
             init_cell_contents: false,
             difficulty_level: DifficultyLevel::default(),
             max_try_and_see_recursion_level: DEFAULT_MAX_TRY_AND_SEE_RECURSION_LEVEL,
             try_and_see_recursion_level: 0,
+            try_and_see_iterative_deepening: false,
+            batch_eliminations: false,
+            strategies: SolvingStrategy::ALL.to_vec(),
+            difficulty_levels: HashMap::new(),
+            search_stats: SearchStats::default(),
+            measure_timings: false,
+            timings: SolverTimings::default(),
+            snapshots: HashMap::new(),
+            history: Vec::new(),
+            record_history: true,
+            observers: CellObservers::default(),
         }
     }
 
-    /// Retourne true si la grille est résolue
+    /// Statistiques de la recherche effectuée depuis la construction du solver (ou depuis le
+    /// dernier `reset`)
     #[must_use]
-    pub fn is_solved(&self) -> bool {
-        for cell in self.grid.hashmap_cells.values() {
-            if let CellContent::Number(_) = cell.content {
-                continue;
-            }
-            return false;
-        }
-        true
+    pub fn search_stats(&self) -> SearchStats {
+        self.search_stats
     }
 
-    /// Effectue les callbacks définis en option à chaque étape de la résolution
-    fn do_step_callback(&self, options: &[SolvingOption], action: &SolvingAction) {
-        for option in options {
-            match option {
-                SolvingOption::StepPrintAction => println!("{action}"),
-                SolvingOption::StepCallbackAction(f) => f(action),
-                SolvingOption::StepPrintGrid => println!("{self}"),
-                SolvingOption::StepCallbackSolver(f) => f(self),
-                SolvingOption::MaxTryAndSeeRecursionLevel(_) => (),
-            }
-        }
+    /// Temps passé dans chaque stratégie depuis la construction du solver (ou depuis le dernier
+    /// `reset`)
+    /// Ces temps ne sont mesurés que si la résolution est configurée avec
+    /// `SolverConfig::measure_timings`
+    #[must_use]
+    pub fn timings(&self) -> &SolverTimings {
+        &self.timings
     }
 
-    /// Tente de résoudre la grille en itérant continûment sur toutes les étapes de résolution
-    /// Retourne true si la grille est résolue
-    /// # Errors
-    /// Une erreur est retournée si la grille n'est pas (ou plus) cohérente
-    pub fn solve(&mut self, options: &[SolvingOption]) -> Result<bool, SolvingError> {
-        // Choix optionnel pour le niveau de récursion dans les recherches très difficiles...
-        self.max_try_and_see_recursion_level = SolvingOption::get_max_try_and_see_recursion_level(
-            options,
-            DEFAULT_MAX_TRY_AND_SEE_RECURSION_LEVEL,
-        );
-
-        #[allow(while_true)]
-        while true {
-            // Etape de résolution
-            let action_solve_step = self.solve_step()?;
+    /// Revient à l'état de la grille d'origine : tous les chiffres placés et toutes les
+    /// éliminations de chiffres possibles sont oubliés
+    /// La configuration de la résolution (niveau de récursion, stratégies, etc.) est conservée
+    pub fn reset(&mut self) {
+        self.replace_contents(Self::grid_contents(&self.original_grid));
+        self.init_cell_contents = false;
+        self.difficulty_level = DifficultyLevel::default();
+        self.search_stats = SearchStats::default();
+        self.timings = SolverTimings::default();
+        self.history.clear();
+    }
 
-            // Callback(s) demandé(s) à chaque étape
-            self.do_step_callback(options, &action_solve_step);
+    /// Actions effectuées depuis la construction du solver (ou depuis le dernier `reset`)
+    /// Ces actions peuvent être rejouées sur la même grille avec `replay`
+    #[must_use]
+    pub fn history(&self) -> &[SolvingAction] {
+        &self.history
+    }
 
-            // Status après cette action ?
-            match action_solve_step {
-                SolvingAction::Solved => return Ok(true),
-                SolvingAction::NoAction => return Ok(false),
-                _ => continue,
+    /// Stratégies de résolution qui ont effectivement produit une action depuis la construction
+    /// du solver (ou depuis le dernier `reset`), de la plus simple à la plus difficile
+    #[must_use]
+    pub fn techniques_used(&self) -> BTreeSet<SolvingStrategy> {
+        let mut vec_actions: Vec<&SolvingAction> = self.history.iter().collect();
+        let mut techniques = BTreeSet::new();
+        while let Some(action) = vec_actions.pop() {
+            if let SolvingAction::Batch(batch_actions) = action {
+                vec_actions.extend(batch_actions);
+            } else if let Some(strategy) = action.strategy() {
+                techniques.insert(strategy);
             }
         }
-        Err(SolvingError::BadImplementation)
+        techniques
     }
 
-    /// Applique une étape de résolution
-    /// Retourne une action effectuée pour rechercher la solution
-    /// Si `SolvingAction::Solved` est retourné, c'est que la grille est résolue
-    /// Si `SolvingAction::NoAction` est retourné, c'est que l'algorithme a épuisé les
-    /// étapes de sa recherche
-    /// # Errors
-    /// Une erreur est retournée si la grille n'est pas (ou plus) cohérente
-    pub fn solve_step(&mut self) -> Result<SolvingAction, SolvingError> {
-        // Vérifie la cohérence de la grille
-        self.check()?;
-
-        // Initialisation une fois des possibilités
-        if !self.init_cell_contents {
-            self.init_cell_contents = true;
-            return Ok(self.solve_step_possible_numbers());
+    /// Enregistre une action effectuée dans l'historique
+    fn record_action(&mut self, action: &SolvingAction) {
+        if self.record_history && *action != SolvingAction::NoAction {
+            self.history.push(action.clone());
         }
+    }
 
-        // Grille résolue ?
-        if self.is_solved() {
-            return Ok(SolvingAction::Solved);
+    /// Rejoue une séquence d'actions enregistrées (voir `history`) en vérifiant que chacune
+    /// d'elles est toujours applicable à l'état courant de la grille
+    /// # Errors
+    /// `SolvingError::ActionNotApplicable(index)` est retourné si l'action à cet index de la
+    /// séquence ne s'applique pas, ou une autre erreur si la grille n'est plus cohérente
+    pub fn replay(&mut self, actions: &[SolvingAction]) -> Result<(), SolvingError> {
+        for (index, action) in actions.iter().enumerate() {
+            if !self.replay_action(action) {
+                return Err(SolvingError::ActionNotApplicable(index));
+            }
+            self.record_action(action);
+            self.check()?;
         }
+        Ok(())
+    }
 
-        // Listes des fonctions -> action / niveau de difficulté pour la résolution
-        #[allow(clippy::type_complexity)]
-        let vec_of_functions: Vec<(fn(&mut Self) -> SolvingAction, DifficultyLevel)> = vec![
-            (Self::solve_single_possible_number, DifficultyLevel::Easy),
-            (Self::solve_numbers_in_zone, DifficultyLevel::Easy),
-            (Self::solve_only_number_in_zone, DifficultyLevel::Easy),
-            (Self::solve_numbers_neighboring, DifficultyLevel::Medium),
-            (Self::solve_dual_values_pair, DifficultyLevel::Hard),
-            (Self::solve_try_and_see, DifficultyLevel::VeryHard),
-        ];
-
-        // Parcourt des fonctions de résolution à la recherche d'une action possible
-        for (function, difficulty) in vec_of_functions {
-            let action = function(self);
-            if let SolvingAction::NoAction = action {
+    /// Applique une action enregistrée
+    /// Retourne false (sans modifier la grille) si l'action ne s'applique pas
+    fn replay_action(&mut self, action: &SolvingAction) -> bool {
+        // Chiffres possibles d'une case (si elle n'a pas encore de chiffre placé)
+        let possible_numbers =
+            |solver: &Solver, line_column: &LineColumn| match solver.contents.get(line_column) {
+                Some(CellContent::PossibleNumbers(simple_09_set)) => Some(*simple_09_set),
+                _ => None,
+            };
+
+        match action {
+            SolvingAction::Solved => self.is_solved(),
+            SolvingAction::InitPossibleNumbers => {
+                if self.init_cell_contents {
+                    return false;
+                }
+                self.init_cell_contents = true;
+                let _ = self.solve_step_possible_numbers();
+                true
+            }
+            SolvingAction::SinglePossibleNumber(line_column, n) => {
+                match possible_numbers(self, line_column) {
+                    Some(simple_09_set) if simple_09_set == Simple09Set::new(&[*n]) => {
+                        self.set_cell_content(*line_column, CellContent::Number(*n));
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            SolvingAction::OnlyNumberInZone(c_zone, line_column, n) => {
+                match possible_numbers(self, line_column) {
+                    Some(simple_09_set)
+                        if simple_09_set.contains(*n)
+                            && self.cell_zone(*line_column) == *c_zone =>
+                    {
+                        self.set_cell_content(*line_column, CellContent::Number(*n));
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            SolvingAction::TryAndSolve(line_column, n_ok, autre_n)
+            | SolvingAction::TryAndFail(line_column, autre_n, n_ok) => {
+                match possible_numbers(self, line_column) {
+                    Some(simple_09_set)
+                        if simple_09_set == Simple09Set::new(&[*n_ok, *autre_n]) =>
+                    {
+                        self.set_cell_content(*line_column, CellContent::Number(*n_ok));
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            SolvingAction::NumbersInZone(line_column, _, _, change)
+            | SolvingAction::NumbersNeighboring(line_column, _, change)
+            | SolvingAction::DualValuesPair(_, _, line_column, _, change) => {
+                if let SolvingAction::NumbersInZone(_, c_zone, _, _) = action {
+                    if self.cell_zone(*line_column) != *c_zone {
+                        return false;
+                    }
+                }
+                // Les chiffres possibles de la case doivent être ceux d'avant l'élimination
+                match possible_numbers(self, line_column) {
+                    Some(simple_09_set)
+                        if simple_09_set.as_vec_u8() == change.before
+                            && change.after.len() < change.before.len() =>
+                    {
+                        self.set_cell_content(
+                            *line_column,
+                            CellContent::PossibleNumbers(Simple09Set::new(&change.after)),
+                        );
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            SolvingAction::Batch(vec_actions) => {
+                // Toutes les actions doivent s'appliquer : sinon l'état initial est restauré
+                let contents = self.contents.clone();
+                let init_cell_contents = self.init_cell_contents;
+                if vec_actions.iter().all(|action| self.replay_action(action)) {
+                    true
+                } else {
+                    self.replace_contents(contents);
+                    self.init_cell_contents = init_cell_contents;
+                    false
+                }
+            }
+            SolvingAction::NoAction => true,
+        }
+    }
+
+    /// Retourne true si la grille est résolue
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.contents.values().all(CellContent::is_number)
+    }
+
+    /// Tente de résoudre la grille en itérant continûment sur toutes les étapes de résolution
+    /// Retourne :
+    /// * `SolveOutcome::Solved` si la grille est résolue
+    /// * `SolveOutcome::Stuck` si les stratégies de résolution ne trouvent plus d'action possible
+    /// * `SolveOutcome::Inconsistent` si la grille n'est pas (ou plus) cohérente
+    pub fn solve(&mut self, config: &SolverConfig) -> SolveOutcome {
+        self.max_try_and_see_recursion_level = config.max_recursion;
+        self.try_and_see_iterative_deepening = config.iterative_deepening;
+        self.batch_eliminations = config.batch_eliminations;
+        self.strategies.clone_from(&config.strategies);
+        self.difficulty_levels.clone_from(&config.difficulty_levels);
+        self.measure_timings = config.measure_timings;
+
+        loop {
+            // Etape de résolution
+            let action_solve_step = match self.solve_step() {
+                Ok(action) => action,
+                Err(e) if config.snapshot_on_error => {
+                    return SolveOutcome::Inconsistent(e.with_grid(&self.to_grid()))
+                }
+                Err(e) => return SolveOutcome::Inconsistent(e),
+            };
+
+            // Callback(s) demandé(s) à chaque étape
+            config.do_step_callback(self, &action_solve_step);
+
+            // Status après cette action ?
+            match action_solve_step {
+                SolvingAction::Solved => return SolveOutcome::Solved,
+                SolvingAction::NoAction => {
+                    return SolveOutcome::Stuck {
+                        unresolved_cells: self.unresolved_cells(),
+                        hardest_technique_tried: self.hardest_strategy_tried(),
+                    }
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    /// Tente de résoudre la grille avec une liste d'options (compatibilité)
+    /// Equivalent à `solve` avec la configuration `SolverConfig::from(options)`
+    pub fn solve_with_options(&mut self, options: &[SolvingOption]) -> SolveOutcome {
+        self.solve(&SolverConfig::from(options))
+    }
+
+    /// Sauvegarde l'état de travail courant (chiffres placés et chiffres possibles) sous un libellé
+    /// Une sauvegarde précédente avec le même libellé est remplacée
+    pub fn snapshot(&mut self, label: &str) {
+        let snapshot = SolverSnapshot {
+            contents: self.contents.clone(),
+            init_cell_contents: self.init_cell_contents,
+            difficulty_level: self.difficulty_level,
+            history: self.history.clone(),
+        };
+        self.snapshots.insert(label.to_string(), snapshot);
+    }
+
+    /// Revient à l'état de travail sauvegardé sous ce libellé par `snapshot`
+    /// La sauvegarde est conservée et peut être restaurée plusieurs fois
+    /// Retourne false (sans rien modifier) si aucune sauvegarde n'a ce libellé
+    pub fn restore(&mut self, label: &str) -> bool {
+        match self.snapshots.get(label) {
+            Some(snapshot) => {
+                let snapshot = snapshot.clone();
+                self.replace_contents(snapshot.contents);
+                self.init_cell_contents = snapshot.init_cell_contents;
+                self.difficulty_level = snapshot.difficulty_level;
+                self.history = snapshot.history;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Libellés (triés) des états de travail sauvegardés par `snapshot`
+    #[must_use]
+    pub fn snapshot_labels(&self) -> Vec<&str> {
+        let mut labels: Vec<&str> = self.snapshots.keys().map(String::as_str).collect();
+        labels.sort_unstable();
+        labels
+    }
+
+    /// Liste (triée par ligne puis colonne) des cases dont le chiffre n'est pas encore connu
+    #[must_use]
+    pub fn unresolved_cells(&self) -> Vec<LineColumn> {
+        let mut vec_line_columns: Vec<LineColumn> = self
+            .contents
+            .iter()
+            .filter(|(_, content)| !matches!(content, CellContent::Number(_)))
+            .map(|(line_column, _)| *line_column)
+            .collect();
+        vec_line_columns.sort_by_key(|line_column| (line_column.line, line_column.column));
+        vec_line_columns
+    }
+
+    /// Copie de la grille dans son état courant de résolution
+    #[must_use]
+    pub fn to_grid(&self) -> Grid {
+        let mut grid = Grid::clone(&self.original_grid);
+        for (line_column, content) in &self.contents {
+            if let Some(cell) = grid.get_mut_cell(*line_column) {
+                cell.content = content.clone();
+            }
+        }
+        grid
+    }
+
+    /// Grille d'origine (telle que donnée au constructeur)
+    #[must_use]
+    pub fn original_grid(&self) -> &Grid {
+        &self.original_grid
+    }
+
+    /// Grille d'origine partagée, pour construire un autre solver sans la copier
+    pub(crate) fn shared_original_grid(&self) -> Arc<Grid> {
+        Arc::clone(&self.original_grid)
+    }
+
+    /// Contenu d'une case de la grille en cours de résolution
+    pub(crate) fn cell_content(&self, line_column: LineColumn) -> Option<&CellContent> {
+        self.contents.get(&line_column)
+    }
+
+    /// Zone d'une case de la grille
+    pub(crate) fn cell_zone(&self, line_column: LineColumn) -> char {
+        self.original_grid.get_cell(line_column).unwrap().c_zone
+    }
+
+    /// Copie de travail du solver (contenu des cases et configuration de la résolution) pour
+    /// tester des stratégies sans modifier ce solver
+    /// Les chiffres possibles des cases sont initialisés si ce n'est pas encore fait
+    pub(crate) fn working_copy(&self) -> Solver {
+        let mut solver =
+            Solver::from_contents(Arc::clone(&self.original_grid), self.contents.clone());
+        solver.init_cell_contents = self.init_cell_contents;
+        solver.max_try_and_see_recursion_level = self.max_try_and_see_recursion_level;
+        solver.try_and_see_recursion_level = self.try_and_see_recursion_level;
+        solver.try_and_see_iterative_deepening = self.try_and_see_iterative_deepening;
+        solver.difficulty_levels.clone_from(&self.difficulty_levels);
+        solver.record_history = false;
+        if !solver.init_cell_contents {
+            solver.init_cell_contents = true;
+            let _ = solver.solve_step_possible_numbers();
+        }
+        solver
+    }
+
+    /// Modifie le contenu d'une case de la grille en cours de résolution
+    /// Les observateurs (voir `subscribe`) sont notifiés si le contenu de la case change
+    fn set_cell_content(&mut self, line_column: LineColumn, content: CellContent) {
+        let before = self.contents.insert(line_column, content);
+        if self.observers.is_empty() {
+            return;
+        }
+        if let Some(before) = before {
+            self.observers
+                .notify(line_column, &before, &self.contents[&line_column]);
+        }
+    }
+
+    /// Remplace le contenu de toutes les cases de la grille en cours de résolution
+    /// Les observateurs (voir `subscribe`) sont notifiés pour chaque case dont le contenu change
+    fn replace_contents(&mut self, contents: HashMap<LineColumn, CellContent>) {
+        let before_contents = std::mem::replace(&mut self.contents, contents);
+        if self.observers.is_empty() {
+            return;
+        }
+        for (line_column, before) in &before_contents {
+            if let Some(after) = self.contents.get(line_column) {
+                self.observers.notify(*line_column, before, after);
+            }
+        }
+    }
+
+    /// Ajoute un observateur notifié à chaque modification du contenu d'une case (chiffre placé
+    /// ou chiffres possibles réduits), indépendamment des étapes de la résolution
+    ///
+    /// Les modifications faites dans les grilles testées lors des recherches par 'essai' ne sont
+    /// pas notifiées : seules les modifications de la grille en cours de résolution le sont
+    pub fn subscribe(&mut self, observer: impl CellObserver + Send + 'static) {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Supprime tous les observateurs ajoutés par `subscribe`
+    pub fn unsubscribe_all(&mut self) {
+        self.observers.clear();
+    }
+
+    /// Niveau de difficulté d'une stratégie pour cette résolution
+    fn strategy_difficulty_level(&self, strategy: SolvingStrategy) -> DifficultyLevel {
+        self.difficulty_levels
+            .get(&strategy)
+            .copied()
+            .unwrap_or_else(|| strategy.difficulty_level())
+    }
+
+    /// Stratégie la plus difficile tentée par `solve_step`
+    /// (la recherche par 'essai' n'est pas tentée si le niveau de récursion max est atteint)
+    pub(crate) fn hardest_strategy_tried(&self) -> SolvingStrategy {
+        self.strategies_tried()
+            .last()
+            .copied()
+            .unwrap_or(SolvingStrategy::SinglePossibleNumber)
+    }
+
+    /// Stratégies tentées par `solve_step` (de la plus simple à la plus difficile)
+    /// La recherche par 'essai' n'est pas tentée si le niveau max de récursion est atteint
+    pub(crate) fn strategies_tried(&self) -> Vec<SolvingStrategy> {
+        self.strategies
+            .iter()
+            .copied()
+            .filter(|strategy| {
+                *strategy != SolvingStrategy::TryAndSee
+                    || self.try_and_see_recursion_level < self.max_try_and_see_recursion_level
+            })
+            .collect()
+    }
+
+    /// Applique une étape de résolution
+    /// Retourne une action effectuée pour rechercher la solution
+    /// Si `SolvingAction::Solved` est retourné, c'est que la grille est résolue
+    /// Si `SolvingAction::NoAction` est retourné, c'est que l'algorithme a épuisé les
+    /// étapes de sa recherche
+    /// # Errors
+    /// Une erreur est retournée si la grille n'est pas (ou plus) cohérente
+    pub fn solve_step(&mut self) -> Result<SolvingAction, SolvingError> {
+        let action = match self.solve_step_action() {
+            Ok(action) => action,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    error = %e,
+                    recursion_level = self.try_and_see_recursion_level,
+                    "grille incohérente"
+                );
+                return Err(e);
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            kind = ?action.kind(),
+            recursion_level = self.try_and_see_recursion_level,
+            "{action}"
+        );
+
+        self.record_action(&action);
+        Ok(action)
+    }
+
+    /// Recherche et applique l'action d'une étape de résolution (voir `solve_step`)
+    fn solve_step_action(&mut self) -> Result<SolvingAction, SolvingError> {
+        self.search_stats.nodes_explored += 1;
+
+        // Vérifie la cohérence de la grille
+        self.check()?;
+
+        // Initialisation une fois des possibilités
+        if !self.init_cell_contents {
+            self.init_cell_contents = true;
+            return Ok(self.solve_step_possible_numbers());
+        }
+
+        // Grille résolue ?
+        if self.is_solved() {
+            return Ok(SolvingAction::Solved);
+        }
+
+        // Parcourt des stratégies de résolution (de la plus simple à la plus difficile) à la
+        // recherche d'une action possible
+        for strategy in self.strategies.clone() {
+            let action = self.apply_strategy(strategy);
+            if let SolvingAction::NoAction = action {
             } else {
-                self.difficulty_level = DifficultyLevel::max(self.difficulty_level, difficulty);
+                self.difficulty_level = DifficultyLevel::max(
+                    self.difficulty_level,
+                    self.strategy_difficulty_level(strategy),
+                );
                 return Ok(action);
             }
         }
@@ -359,13 +1170,83 @@ impl Solver {
         Ok(SolvingAction::NoAction)
     }
 
+    /// Applique de façon répétée une seule stratégie de résolution jusqu'à ce qu'elle ne
+    /// trouve plus d'action possible
+    /// Retourne la liste des actions effectuées (précédée de `SolvingAction::InitPossibleNumbers`
+    /// si l'initialisation des chiffres possibles n'avait pas encore été faite)
+    /// # Errors
+    /// Une erreur est retournée si la grille n'est pas (ou plus) cohérente
+    pub fn apply_strategy_all(
+        &mut self,
+        strategy: SolvingStrategy,
+    ) -> Result<Vec<SolvingAction>, SolvingError> {
+        let mut vec_actions = Vec::new();
+
+        // Vérifie la cohérence de la grille
+        self.check()?;
+
+        // Initialisation une fois des possibilités
+        if !self.init_cell_contents {
+            self.init_cell_contents = true;
+            let action = self.solve_step_possible_numbers();
+            self.record_action(&action);
+            vec_actions.push(action);
+        }
+
+        loop {
+            self.search_stats.nodes_explored += 1;
+            let action = self.apply_strategy(strategy);
+            if action == SolvingAction::NoAction {
+                break;
+            }
+            self.difficulty_level = DifficultyLevel::max(
+                self.difficulty_level,
+                self.strategy_difficulty_level(strategy),
+            );
+            self.record_action(&action);
+            vec_actions.push(action);
+            self.check()?;
+        }
+
+        Ok(vec_actions)
+    }
+
+    /// Applique une fois une stratégie de résolution
+    pub(crate) fn apply_strategy(&mut self, strategy: SolvingStrategy) -> SolvingAction {
+        let start = self.measure_timings.then(Instant::now);
+        let action = match strategy {
+            SolvingStrategy::SinglePossibleNumber => self.solve_single_possible_number(),
+            SolvingStrategy::NumbersInZone => {
+                if self.batch_eliminations {
+                    self.solve_numbers_in_zone_batch()
+                } else {
+                    self.solve_numbers_in_zone()
+                }
+            }
+            SolvingStrategy::OnlyNumberInZone => self.solve_only_number_in_zone(),
+            SolvingStrategy::NumbersNeighboring => {
+                if self.batch_eliminations {
+                    self.solve_numbers_neighboring_batch()
+                } else {
+                    self.solve_numbers_neighboring()
+                }
+            }
+            SolvingStrategy::DualValuesPair => self.solve_dual_values_pair(),
+            SolvingStrategy::TryAndSee => self.solve_try_and_see(),
+        };
+        if let Some(start) = start {
+            *self.timings.strategies.entry(strategy).or_default() += start.elapsed();
+        }
+        action
+    }
+
     /// Etape initiale de résolution pour modifier toutes les cases avec un
     /// contenu `Undefined` en un contenu `PossibleNumbers` selon le nombre de
     /// cases dans la zone
     fn solve_step_possible_numbers(&mut self) -> SolvingAction {
         // Prépare la liste des des chiffres possibles par zone
         let mut zone_hash_map: HashMap<char, Simple09Set> = HashMap::new();
-        for (c_zone, zone) in &self.grid.hashmap_zones {
+        for (c_zone, zone) in &self.original_grid.hashmap_zones {
             let nb_cases = zone.set_line_column.len();
             let mut simple_09_set = Simple09Set::default();
             // On ne considère que le nombre de cases de la zone pour la liste
@@ -377,12 +1258,16 @@ impl Solver {
             zone_hash_map.insert(*c_zone, simple_09_set);
         }
         // Recherche de toutes les cases avec un contenu 'Undefined'
-        for cell in self.grid.hashmap_cells.values_mut() {
-            if let CellContent::Undefined = cell.content {
-                // Case à traiter, encore à Undefined...
-                let simple_09_set = zone_hash_map.get(&cell.c_zone).unwrap();
-                cell.content = CellContent::PossibleNumbers(*simple_09_set);
-            }
+        let vec_line_columns: Vec<LineColumn> = self
+            .contents
+            .iter()
+            .filter(|(_, content)| **content == CellContent::Undefined)
+            .map(|(line_column, _)| *line_column)
+            .collect();
+        for line_column in vec_line_columns {
+            // Case à traiter, encore à Undefined...
+            let simple_09_set = zone_hash_map.get(&self.cell_zone(line_column)).unwrap();
+            self.set_cell_content(line_column, CellContent::PossibleNumbers(*simple_09_set));
         }
 
         // Cet étape n'est réalisée qu'une fois en début de résolution
@@ -392,15 +1277,17 @@ impl Solver {
     /// Etape pour identifier les cases qui n'ont qu'une seule possibilité pour le chiffre
     fn solve_single_possible_number(&mut self) -> SolvingAction {
         // Recherche de toutes les cases avec un contenu 'PossibleNumbers' avec une seule possibilité
-        for cell in self.grid.hashmap_cells.values_mut() {
-            if let CellContent::PossibleNumbers(simple_09_set) = cell.content.clone() {
+        let option_single = self.contents.iter().find_map(|(line_column, content)| {
+            if let CellContent::PossibleNumbers(simple_09_set) = content {
                 if simple_09_set.len() == 1 {
-                    let vec_n = simple_09_set.as_vec_u8();
-                    let n = vec_n[0];
-                    cell.content = CellContent::Number(n);
-                    return SolvingAction::SinglePossibleNumber(cell.line_column, n);
+                    return Some((*line_column, simple_09_set.as_vec_u8()[0]));
                 }
             }
+            None
+        });
+        if let Some((line_column, n)) = option_single {
+            self.set_cell_content(line_column, CellContent::Number(n));
+            return SolvingAction::SinglePossibleNumber(line_column, n);
         }
 
         SolvingAction::NoAction
@@ -408,13 +1295,25 @@ impl Solver {
 
     /// Etape pour éliminer les chiffres déjà présents dans la zone d'une case
     fn solve_numbers_in_zone(&mut self) -> SolvingAction {
+        SolvingAction::from_vec(self.eliminate_numbers_in_zone(false))
+    }
+
+    /// Etape pour éliminer les chiffres déjà présents dans la zone de toutes les cases de la grille
+    fn solve_numbers_in_zone_batch(&mut self) -> SolvingAction {
+        SolvingAction::from_vec(self.eliminate_numbers_in_zone(true))
+    }
+
+    /// Élimine les chiffres déjà présents dans la zone d'une case (`all_cells` = false)
+    /// ou de toutes les cases (`all_cells` = true)
+    fn eliminate_numbers_in_zone(&mut self, all_cells: bool) -> Vec<SolvingAction> {
+        let mut vec_actions = Vec::new();
+
         // Prépare la liste des chiffres déjà placés par zone
         let mut zone_hash_map: HashMap<char, Simple09Set> = HashMap::new();
-        for (c_zone, zone) in &self.grid.hashmap_zones {
+        for (c_zone, zone) in &self.original_grid.hashmap_zones {
             let mut simple_09_set = Simple09Set::default();
             for line_column in &zone.set_line_column {
-                let cell = self.grid.get_cell(*line_column).unwrap();
-                if let CellContent::Number(n) = cell.content {
+                if let CellContent::Number(n) = self.contents[line_column] {
                     simple_09_set.insert(n);
                 }
             }
@@ -422,9 +1321,10 @@ impl Solver {
         }
 
         // Recherche de toutes les cases avec un contenu 'PossibleNumbers'
-        for cell in self.grid.hashmap_cells.values_mut() {
-            if let CellContent::PossibleNumbers(cell_simple_09_set) = cell.content.clone() {
-                let c_zone = cell.c_zone;
+        let mut vec_changes = Vec::new();
+        for (line_column, content) in &self.contents {
+            if let CellContent::PossibleNumbers(cell_simple_09_set) = *content {
+                let c_zone = self.original_grid.get_cell(*line_column).unwrap().c_zone;
                 let mut simple_09_set = *zone_hash_map.get(&c_zone).unwrap();
                 simple_09_set = simple_09_set.intersection(cell_simple_09_set);
                 if !simple_09_set.is_empty() {
@@ -435,13 +1335,24 @@ impl Solver {
                     for n in &vec_n {
                         new_cell_simple_09_set.remove(*n);
                     }
-                    cell.content = CellContent::PossibleNumbers(new_cell_simple_09_set);
-                    return SolvingAction::NumbersInZone(cell.line_column, c_zone, vec_n);
+                    vec_changes.push((*line_column, new_cell_simple_09_set));
+                    vec_actions.push(SolvingAction::NumbersInZone(
+                        *line_column,
+                        c_zone,
+                        vec_n,
+                        CandidatesChange::new(cell_simple_09_set, new_cell_simple_09_set),
+                    ));
+                    if !all_cells {
+                        break;
+                    }
                 }
             }
         }
+        for (line_column, simple_09_set) in vec_changes {
+            self.set_cell_content(line_column, CellContent::PossibleNumbers(simple_09_set));
+        }
 
-        SolvingAction::NoAction
+        vec_actions
     }
 
     /// Etape pour identifier une seule case possible pour une valeur dans une zone
@@ -459,14 +1370,13 @@ impl Solver {
         let mut vec_zones_hash_map_only_numbers = Vec::new();
 
         // Parcourt de toutes les zones
-        for (c_zone, zone) in &self.grid.hashmap_zones {
+        for (c_zone, zone) in &self.original_grid.hashmap_zones {
             // HashMap pour repérer les possibilités
             let mut hash_map_only_numbers = HashMap::new();
 
             // Parcourt des cases de la zone
             for line_column in &zone.set_line_column {
-                let cell = self.grid.get_cell(*line_column).unwrap();
-                if let CellContent::PossibleNumbers(simple_09_set) = cell.content {
+                if let CellContent::PossibleNumbers(simple_09_set) = self.contents[line_column] {
                     // Case avec plusieurs possibilités de chiffres
                     // On renseigne le HashMap des possibilités de la zone
                     let vec_n = simple_09_set.as_vec_u8();
@@ -494,8 +1404,7 @@ impl Solver {
             for (digit, only_number) in hash_map_only_numbers {
                 if let OnlyNumber::OnlyLineColumn(line_column) = only_number {
                     // Il n'y a qu'une seule case possible pour ce digit dans cette zone
-                    let cell = self.grid.get_mut_cell(line_column).unwrap();
-                    cell.content = CellContent::Number(digit);
+                    self.set_cell_content(line_column, CellContent::Number(digit));
                     return SolvingAction::OnlyNumberInZone(c_zone, line_column, digit);
                 }
             }
@@ -506,11 +1415,24 @@ impl Solver {
 
     /// Etape pour éliminer les chiffres déjà présents dans les cases voisines
     fn solve_numbers_neighboring(&mut self) -> SolvingAction {
+        SolvingAction::from_vec(self.eliminate_numbers_neighboring(false))
+    }
+
+    /// Etape pour éliminer les chiffres déjà présents dans les cases voisines de toutes les cases de la grille
+    fn solve_numbers_neighboring_batch(&mut self) -> SolvingAction {
+        SolvingAction::from_vec(self.eliminate_numbers_neighboring(true))
+    }
+
+    /// Élimine les chiffres déjà présents dans les cases voisines d'une case (`all_cells` = false)
+    /// ou de toutes les cases (`all_cells` = true)
+    fn eliminate_numbers_neighboring(&mut self, all_cells: bool) -> Vec<SolvingAction> {
+        let mut vec_actions = Vec::new();
+
         // Liste des cases avec un contenu 'PossibleNumbers'
         let mut vec_line_columns_possible_numbers: Vec<(LineColumn, Simple09Set)> = Vec::new();
-        for cell in self.grid.hashmap_cells.values() {
-            if let CellContent::PossibleNumbers(simple_09_set) = cell.content {
-                vec_line_columns_possible_numbers.push((cell.line_column, simple_09_set));
+        for (line_column, content) in &self.contents {
+            if let CellContent::PossibleNumbers(simple_09_set) = content {
+                vec_line_columns_possible_numbers.push((*line_column, *simple_09_set));
             }
         }
 
@@ -521,16 +1443,14 @@ impl Solver {
             // Parcourt des cases voisines
             let neighboring_line_columns = NeighboringLineColumns::new(
                 cell_line_column,
-                self.grid.min_line_column,
-                self.grid.max_line_column,
+                self.original_grid.min_line_column,
+                self.original_grid.max_line_column,
             );
             for neighboring_line_column in neighboring_line_columns {
-                let option_cell = self.grid.get_cell(neighboring_line_column);
-                if let Some(neighboring_cell) = option_cell {
-                    if let CellContent::Number(neighboring_n) = neighboring_cell.content {
-                        // Simple_09_set des chiffres dans les cases voisines
-                        neighboring_simple_09_set.insert(neighboring_n);
-                    }
+                let option_content = self.contents.get(&neighboring_line_column);
+                if let Some(CellContent::Number(neighboring_n)) = option_content {
+                    // Simple_09_set des chiffres dans les cases voisines
+                    neighboring_simple_09_set.insert(*neighboring_n);
                 }
             }
 
@@ -539,18 +1459,27 @@ impl Solver {
             if !intersection_simple_09set.is_empty() {
                 // les valeurs dans intersection_simple_09set sont déjà affectées à des cases voisines
                 // Elles ne sont pas possible pour cette case en line_column
-                let cell = self.grid.get_mut_cell(cell_line_column).unwrap();
                 let vec_n = intersection_simple_09set.as_vec_u8();
                 let mut new_cell_simple_09_set = cell_simple_09_set;
                 for n in &vec_n {
                     new_cell_simple_09_set.remove(*n);
                 }
-                cell.content = CellContent::PossibleNumbers(new_cell_simple_09_set);
-                return SolvingAction::NumbersNeighboring(cell.line_column, vec_n);
+                self.set_cell_content(
+                    cell_line_column,
+                    CellContent::PossibleNumbers(new_cell_simple_09_set),
+                );
+                vec_actions.push(SolvingAction::NumbersNeighboring(
+                    cell_line_column,
+                    vec_n,
+                    CandidatesChange::new(cell_simple_09_set, new_cell_simple_09_set),
+                ));
+                if !all_cells {
+                    break;
+                }
             }
         }
 
-        SolvingAction::NoAction
+        vec_actions
     }
 
     /// Etape pour éliminer une paire de chiffres dans une case voisine de 2 autres
@@ -558,8 +1487,8 @@ impl Solver {
     fn solve_dual_values_pair(&mut self) -> SolvingAction {
         // HashMap des cases avec une paire de valeurs possibles
         let mut hash_map_line_column: HashMap<LineColumn, Simple09Set> = HashMap::new();
-        for (line_column, cell) in &self.grid.hashmap_cells {
-            if let CellContent::PossibleNumbers(simple_09_set) = cell.content {
+        for (line_column, content) in &self.contents {
+            if let CellContent::PossibleNumbers(simple_09_set) = *content {
                 if simple_09_set.len() == 2 {
                     hash_map_line_column.insert(*line_column, simple_09_set);
                 }
@@ -597,32 +1526,32 @@ impl Solver {
                                 LineColumn::new(relative_c.0, relative_c.1);
                             let line_column_c = *line_column_a + relative_line_column_c;
                             // Examen de la case line_column_c de la grille
-                            let option_cell_c = self.grid.get_mut_cell(line_column_c);
-                            if let Some(cell_c) = option_cell_c {
-                                if let CellContent::PossibleNumbers(simple_09_set_c) =
-                                    cell_c.content
-                                {
-                                    let intersection =
-                                        simple_09_set_c.intersection(*simple_09_set_a);
-                                    if !intersection.is_empty() {
-                                        // Bingo !
-                                        // On a trouve une case c avec un ensemble de valeurs possibles
-                                        // qui contient une partie des paires de valeurs possibles des
-                                        // cases a et b qui l'avoisinent...
-                                        let vec_n = intersection.as_vec_u8();
-                                        let mut new_simple_09_set_c = simple_09_set_c;
-                                        for n in &vec_n {
-                                            new_simple_09_set_c.remove(*n);
-                                        }
-                                        cell_c.content =
-                                            CellContent::PossibleNumbers(new_simple_09_set_c);
-                                        return SolvingAction::DualValuesPair(
-                                            *line_column_a,
-                                            line_column_b,
-                                            line_column_c,
-                                            vec_n,
-                                        );
+                            let option_content_c = self.contents.get(&line_column_c).cloned();
+                            if let Some(CellContent::PossibleNumbers(simple_09_set_c)) =
+                                option_content_c
+                            {
+                                let intersection = simple_09_set_c.intersection(*simple_09_set_a);
+                                if !intersection.is_empty() {
+                                    // Bingo !
+                                    // On a trouve une case c avec un ensemble de valeurs possibles
+                                    // qui contient une partie des paires de valeurs possibles des
+                                    // cases a et b qui l'avoisinent...
+                                    let vec_n = intersection.as_vec_u8();
+                                    let mut new_simple_09_set_c = simple_09_set_c;
+                                    for n in &vec_n {
+                                        new_simple_09_set_c.remove(*n);
                                     }
+                                    self.set_cell_content(
+                                        line_column_c,
+                                        CellContent::PossibleNumbers(new_simple_09_set_c),
+                                    );
+                                    return SolvingAction::DualValuesPair(
+                                        *line_column_a,
+                                        line_column_b,
+                                        line_column_c,
+                                        vec_n,
+                                        CandidatesChange::new(simple_09_set_c, new_simple_09_set_c),
+                                    );
                                 }
                             }
                         }
@@ -643,63 +1572,137 @@ impl Solver {
 
         // HashMap des cases avec une paire de valeurs possibles
         let mut hash_map_line_column: HashMap<LineColumn, Simple09Set> = HashMap::new();
-        for (line_column, cell) in &self.grid.hashmap_cells {
-            if let CellContent::PossibleNumbers(simple_09_set) = cell.content {
+        for (line_column, content) in &self.contents {
+            if let CellContent::PossibleNumbers(simple_09_set) = *content {
                 if simple_09_set.len() == 2 {
                     hash_map_line_column.insert(*line_column, simple_09_set);
                 }
             }
         }
 
-        // On teste brutalement la résolution en forçant les valeurs possibles pour les cases sélectionnées
+        if self.try_and_see_iterative_deepening {
+            // Approfondissement itératif : on essaie toutes les cases avec un niveau de récursion
+            // supplémentaire, puis 2, etc.
+            for max_recursion_level in
+                (self.try_and_see_recursion_level + 1)..=self.max_try_and_see_recursion_level
+            {
+                let action = self.try_and_see_pairs(&hash_map_line_column, max_recursion_level);
+                if action != SolvingAction::NoAction {
+                    return action;
+                }
+            }
+            SolvingAction::NoAction
+        } else {
+            self.try_and_see_pairs(&hash_map_line_column, self.max_try_and_see_recursion_level)
+        }
+    }
+
+    /// Configuration de résolution pour les grilles testées par 'essai' : celle de cette
+    /// résolution (avec son barème des difficultés) et un niveau de récursion limité à
+    /// `max_recursion_level`
+    fn try_and_see_config(&self, max_recursion_level: i32) -> SolverConfig {
+        let mut config = SolverConfig::new()
+            .max_recursion(max_recursion_level)
+            .iterative_deepening(self.try_and_see_iterative_deepening)
+            .batch_eliminations(self.batch_eliminations)
+            .strategies(self.strategies.iter().copied());
+        config.difficulty_levels.clone_from(&self.difficulty_levels);
+        config
+    }
+
+    /// Teste brutalement la résolution en forçant les valeurs possibles pour les cases sélectionnées
+    /// Les grilles ainsi obtenues sont résolues avec un niveau de récursion limité à `max_recursion_level`
+    fn try_and_see_pairs(
+        &mut self,
+        hash_map_line_column: &HashMap<LineColumn, Simple09Set>,
+        max_recursion_level: i32,
+    ) -> SolvingAction {
+        let config = self.try_and_see_config(max_recursion_level);
+
         // Parcourt du hash map avec les cases une paire de valeurs possibles
-        self.try_and_see_recursion_level += 1;
-        for (line_column, simple_09_set) in &hash_map_line_column {
+        for (line_column, simple_09_set) in hash_map_line_column {
             let vec_n = simple_09_set.as_vec_u8();
             for n in &vec_n {
-                // Clone la grille courante pour tenter de la résoudre en forçant la valeur de cette case
-                let mut new_grid = self.grid.clone();
-                let new_cell = new_grid.get_mut_cell(*line_column).unwrap();
-                new_cell.content = CellContent::Number(*n);
-                let mut new_solver = Solver::new(&new_grid);
-                new_solver.max_try_and_see_recursion_level = self.max_try_and_see_recursion_level;
-                new_solver.try_and_see_recursion_level = self.try_and_see_recursion_level;
-                // println!("Recursion level = {}", self.try_and_see_recursion_level);
-                match new_solver.solve(&[]) {
-                    Err(_) => {
+                // Copie le contenu courant des cases pour tenter de résoudre la grille en forçant
+                // la valeur de cette case (la grille d'origine est partagée)
+                let mut new_contents = self.contents.clone();
+                new_contents.insert(*line_column, CellContent::Number(*n));
+                let mut new_solver =
+                    Solver::from_contents(Arc::clone(&self.original_grid), new_contents);
+                new_solver.try_and_see_recursion_level = self.try_and_see_recursion_level + 1;
+                new_solver.record_history = false;
+                let start = self.measure_timings.then(Instant::now);
+                let res_solver = new_solver.solve(&config);
+                if let Some(start) = start {
+                    self.timings.try_and_see_recursion += start.elapsed();
+                }
+
+                #[cfg(feature = "tracing")]
+                tracing::trace!(
+                    line = line_column.line,
+                    column = line_column.column,
+                    n = *n,
+                    recursion_level = new_solver.try_and_see_recursion_level,
+                    outcome = match &res_solver {
+                        SolveOutcome::Solved => "solved",
+                        SolveOutcome::Stuck { .. } => "stuck",
+                        SolveOutcome::Inconsistent(_) => "inconsistent",
+                    },
+                    "essai d'une valeur"
+                );
+
+                // Statistiques de cet essai
+                self.search_stats.trials += 1;
+                self.search_stats.grid_clones += 1;
+                self.search_stats.max_depth_reached = i32::max(
+                    self.search_stats.max_depth_reached,
+                    new_solver.try_and_see_recursion_level,
+                );
+                self.search_stats.merge(&new_solver.search_stats);
+
+                // Essai qui a lui-même nécessité des recherches par 'essai' (quel que soit le
+                // barème des difficultés)
+                let nested_difficulty_level = if new_solver.search_stats.trials > 0 {
+                    DifficultyLevel::Expert
+                } else {
+                    DifficultyLevel::Unknown
+                };
+
+                match res_solver {
+                    SolveOutcome::Inconsistent(_) => {
                         // Bingo !
                         // La valeur n pour line_column entraîne une incohérence de la grille
                         // On force l'autre valeur
                         let autre_n = if vec_n[0] == *n { vec_n[1] } else { vec_n[0] };
-                        let cell = self.grid.get_mut_cell(*line_column).unwrap();
-                        cell.content = CellContent::Number(autre_n);
+                        self.difficulty_level =
+                            DifficultyLevel::max(self.difficulty_level, nested_difficulty_level);
+                        self.set_cell_content(*line_column, CellContent::Number(autre_n));
                         return SolvingAction::TryAndFail(*line_column, *n, autre_n);
                     }
-                    Ok(solved) => {
-                        if solved {
-                            // Bingo !
-                            // La valeur n pour line_column permet de résoudre la grille
-                            // On force cette valeur
-                            let autre_n = if vec_n[0] == *n { vec_n[1] } else { vec_n[0] };
-                            let cell = self.grid.get_mut_cell(*line_column).unwrap();
-                            cell.content = CellContent::Number(*n);
-                            return SolvingAction::TryAndSolve(*line_column, *n, autre_n);
-                        }
-                        // else, on n'a rien trouvé...
+                    SolveOutcome::Solved => {
+                        // Bingo !
+                        // La valeur n pour line_column permet de résoudre la grille
+                        // On force cette valeur
+                        let autre_n = if vec_n[0] == *n { vec_n[1] } else { vec_n[0] };
+                        self.difficulty_level =
+                            DifficultyLevel::max(self.difficulty_level, nested_difficulty_level);
+                        self.set_cell_content(*line_column, CellContent::Number(*n));
+                        return SolvingAction::TryAndSolve(*line_column, *n, autre_n);
+                    }
+                    SolveOutcome::Stuck { .. } => {
+                        // On n'a rien trouvé...
                     }
                 }
             }
         }
-        self.try_and_see_recursion_level -= 1;
 
         SolvingAction::NoAction
     }
 
     /// Vérifie la consistance de la grille
-    fn check(&self) -> Result<(), SolvingError> {
+    pub(crate) fn check(&self) -> Result<(), SolvingError> {
         if !self.init_cell_contents {
-            self.check_zone_too_long()?;
-            self.check_zone_with_unexpected_number()?;
+            self.check_initial()?;
         }
         self.check_neighboring_cells()?;
         self.check_zone_numbers()?;
@@ -707,13 +1710,40 @@ impl Solver {
         Ok(())
     }
 
+    /// Vérifications initiales de la structure de la grille
+    fn check_initial(&self) -> Result<(), SolvingError> {
+        self.check_zone_too_long()?;
+        self.check_zone_with_unexpected_number()?;
+        self.check_zone_contiguous()?;
+        Ok(())
+    }
+
+    /// Vérification (initiale) que chaque zone est d'un seul tenant
+    fn check_zone_contiguous(&self) -> Result<(), SolvingError> {
+        // Parcourt des zones dans l'ordre des lettres pour une erreur reproductible
+        let mut vec_c_zones: Vec<char> = self.original_grid.hashmap_zones.keys().copied().collect();
+        vec_c_zones.sort_unstable();
+        for c_zone in vec_c_zones {
+            if !self.original_grid.is_zone_contiguous(c_zone) {
+                return Err(SolvingError::ZoneNotContiguous(
+                    self.original_grid.zone_name(c_zone),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Vérification (initiale) de la taille des zones
     fn check_zone_too_long(&self) -> Result<(), SolvingError> {
         // Parcourt des zones
-        for (c_zone, zone) in &self.grid.hashmap_zones {
+        for (c_zone, zone) in &self.original_grid.hashmap_zones {
             if zone.set_line_column.len() > 9 {
                 // C'est une erreur si la zone a plus de 9 cases
-                return Err(SolvingError::ZoneTooLong(*c_zone));
+                return Err(SolvingError::ZoneTooLong(
+                    self.original_grid.zone_name(*c_zone),
+                    zone.set_line_column.len(),
+                ));
             }
         }
 
@@ -723,16 +1753,15 @@ impl Solver {
     /// Vérification (initiale) de valeur inattendue dans une zone
     fn check_zone_with_unexpected_number(&self) -> Result<(), SolvingError> {
         // Parcourt des zones
-        for (c_zone, zone) in &self.grid.hashmap_zones {
+        for (c_zone, zone) in &self.original_grid.hashmap_zones {
             // Parcourt des cases de la zone
             let zone_len = zone.set_line_column.len();
             for line_column in &zone.set_line_column {
-                let cell = self.grid.get_cell(*line_column).unwrap();
-                if let CellContent::Number(n) = cell.content {
+                if let CellContent::Number(n) = self.contents[line_column] {
                     // C'est une erreur si une case contient un chiffre plus grand que la taille de la zone
                     if usize::from(n) > zone_len {
                         return Err(SolvingError::ZoneWithUnexpectedNumber(
-                            *c_zone,
+                            self.original_grid.zone_name(*c_zone),
                             *line_column,
                             n,
                         ));
@@ -748,26 +1777,24 @@ impl Solver {
     /// définie avec le même chiffre
     fn check_neighboring_cells(&self) -> Result<(), SolvingError> {
         // Parcourt de toutes les cases de la grille avec un chiffre défini
-        for (line_column, cell) in &self.grid.hashmap_cells {
-            if let CellContent::Number(n) = cell.content {
+        for (line_column, content) in &self.contents {
+            if let CellContent::Number(n) = *content {
                 // Parcourt des cases voisines
                 let neighboring_line_columns = NeighboringLineColumns::new(
                     *line_column,
-                    self.grid.min_line_column,
-                    self.grid.max_line_column,
+                    self.original_grid.min_line_column,
+                    self.original_grid.max_line_column,
                 );
                 for neighboring_line_column in neighboring_line_columns {
-                    let option_cell = self.grid.get_cell(neighboring_line_column);
-                    if let Some(neighboring_cell) = option_cell {
-                        if let CellContent::Number(neighboring_n) = neighboring_cell.content {
-                            // C'est une erreur si une case voisine contient le même chiffre
-                            if n == neighboring_n {
-                                return Err(SolvingError::NeighboringWithSameNumber(
-                                    *line_column,
-                                    neighboring_line_column,
-                                    n,
-                                ));
-                            }
+                    let option_content = self.contents.get(&neighboring_line_column);
+                    if let Some(CellContent::Number(neighboring_n)) = option_content {
+                        // C'est une erreur si une case voisine contient le même chiffre
+                        if n == *neighboring_n {
+                            return Err(SolvingError::NeighboringWithSameNumber(
+                                *line_column,
+                                neighboring_line_column,
+                                n,
+                            ));
                         }
                     }
                 }
@@ -781,18 +1808,24 @@ impl Solver {
     /// qui apparaît 2 fois
     fn check_zone_numbers(&self) -> Result<(), SolvingError> {
         // Parcourt de toutes les zones
-        for (c_zone, zone) in &self.grid.hashmap_zones {
-            // Init liste des chiffres définit dans la zone
-            let mut zone_numbers = Simple09Set::default();
+        for (c_zone, zone) in &self.original_grid.hashmap_zones {
+            // Init des chiffres définis dans la zone avec la case qui les contient
+            let mut zone_numbers: HashMap<u8, LineColumn> = HashMap::new();
             // Parcourt des cases de la zone
             for line_column in &zone.set_line_column {
-                let cell = self.grid.get_cell(*line_column).unwrap();
-                if let CellContent::Number(n) = cell.content {
+                if let CellContent::Number(n) = self.contents[line_column] {
                     // C'est une erreur si un même chiffre apparaît plusieurs fois dans la même zone
-                    if zone_numbers.contains(n) {
-                        return Err(SolvingError::ZoneWithSameNumber(*c_zone, n));
+                    if let Some(other_line_column) = zone_numbers.insert(n, *line_column) {
+                        let mut line_columns = [other_line_column, *line_column];
+                        line_columns
+                            .sort_by_key(|line_column| (line_column.line, line_column.column));
+                        return Err(SolvingError::ZoneWithSameNumber(
+                            self.original_grid.zone_name(*c_zone),
+                            line_columns[0],
+                            line_columns[1],
+                            n,
+                        ));
                     }
-                    zone_numbers.insert(n);
                 }
             }
         }
@@ -802,10 +1835,13 @@ impl Solver {
     /// Vérifie qu'il n'y a pas une case avec aucune valeur possible
     fn check_cell_with_no_possible_values(&self) -> Result<(), SolvingError> {
         // Parcourt de toutes les cases de la grille avec une liste de valeurs possibles
-        for (line_column, cell) in &self.grid.hashmap_cells {
-            if let CellContent::PossibleNumbers(hash_set) = &cell.content {
+        for (line_column, content) in &self.contents {
+            if let CellContent::PossibleNumbers(hash_set) = content {
                 if hash_set.is_empty() {
-                    return Err(SolvingError::NoPossibleNumber(*line_column));
+                    return Err(SolvingError::NoPossibleNumber(
+                        *line_column,
+                        self.original_grid.zone_name(self.cell_zone(*line_column)),
+                    ));
                 }
             }
         }
@@ -853,6 +1889,33 @@ mod test {
         let solver = Solver::new(&grid);
 
         assert!(solver.check().is_err());
+        assert_eq!(
+            solver.check(),
+            Err(SolvingError::ZoneTooLong("a".to_string(), 13))
+        );
+    }
+
+    #[test]
+    fn test_check_zone_not_contiguous() {
+        let grid = Grid::from_str(
+            "
+        # NOK car la zone 'b' est coupée en deux par la zone 'd'
+        a1 b  b2
+        d  d  d
+        b  c  c2
+        ",
+        )
+        .unwrap();
+
+        let solver = Solver::new(&grid);
+        assert_eq!(
+            solver.check(),
+            Err(SolvingError::ZoneNotContiguous("b".to_string()))
+        );
+        assert_eq!(
+            Solver::try_new(&grid).unwrap_err(),
+            SolvingError::ZoneNotContiguous("b".to_string())
+        );
     }
 
     #[test]
@@ -870,6 +1933,48 @@ mod test {
         let solver = Solver::new(&grid);
 
         assert!(solver.check().is_err());
+        assert_eq!(
+            solver.check(),
+            Err(SolvingError::ZoneWithUnexpectedNumber(
+                "b".to_string(),
+                LineColumn::new(1, 1),
+                7
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        assert!(Solver::try_new(&grid).is_ok());
+
+        let grid = Grid::from_str(
+            "
+        # NOK car b7 n'est pas possible dans une zone de 5 cases
+        a1 b  b
+        b  b7  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        assert!(matches!(
+            Solver::try_new(&grid),
+            Err(SolvingError::ZoneWithUnexpectedNumber(zone, _, 7)) if zone == "b"
+        ));
+
+        // Zone désignée par plusieurs lettres
+        let grid = Grid::from_str("aa1 ab  aa").unwrap();
+        assert!(Solver::try_new(&grid)
+            .unwrap_err()
+            .to_string()
+            .contains("'aa'"));
     }
 
     #[test]
@@ -904,6 +2009,15 @@ mod test {
         let solver = Solver::new(&grid);
 
         assert!(solver.check().is_err());
+        assert_eq!(
+            solver.check_zone_numbers(),
+            Err(SolvingError::ZoneWithSameNumber(
+                "b".to_string(),
+                LineColumn::new(0, 2),
+                LineColumn::new(1, 1),
+                2
+            ))
+        );
     }
 
     #[test]
@@ -955,37 +2069,724 @@ mod test {
         .unwrap();
 
         let mut solver = Solver::new(&grid);
-        let _ = solver.solve(&[]);
+        let _ = solver.solve(&SolverConfig::default());
         assert!(solver.is_solved());
     }
 
     #[test]
-    fn test_all_examples() {
-        // Test tous les fichiers ""./examples/*.txt" pour résolution
-        for entry in fs::read_dir("./examples").unwrap() {
-            let entry = entry.unwrap();
-            let path = entry.path();
-            let path_str = path.to_str().unwrap();
-            let path_path = path::Path::new(path_str);
+    fn test_hard_puzzle_is_solved_iterative_deepening() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
 
-            if path_path.is_file()
-                && path_path.file_name().unwrap().to_string_lossy().starts_with("ex")
+        let mut solver = Solver::new(&grid);
+        let res_solver = solver.solve(&SolverConfig::new().iterative_deepening(true));
+        assert!(res_solver.is_solved());
+        assert!(solver.is_solved());
+        assert!(solver.try_and_see_iterative_deepening);
+    }
+
+    #[test]
+    fn test_batch_eliminations() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        // Sans le mode 'batch', une seule case est traitée par étape
+        let mut solver = Solver::new(&grid);
+        let _ = solver.solve_step();
+        let action = solver.solve_numbers_in_zone();
+        assert!(matches!(action, SolvingAction::NumbersInZone(..)));
+
+        // En mode 'batch', toutes les cases des zones 'b' et 'c' sont traitées en une étape
+        let mut solver = Solver::new(&grid);
+        let _ = solver.solve_step();
+        let action = solver.solve_numbers_in_zone_batch();
+        if let SolvingAction::Batch(vec_actions) = action {
+            assert_eq!(vec_actions.len(), 5);
+        } else {
+            panic!("SolvingAction::Batch attendue");
+        }
+        assert_eq!(
+            solver.solve_numbers_in_zone_batch(),
+            SolvingAction::NoAction
+        );
+
+        // Résolution complète en mode 'batch'
+        let mut solver = Solver::new(&grid);
+        let res_solver = solver.solve(&SolverConfig::new().batch_eliminations(true));
+        assert!(res_solver.is_solved());
+
+        // Le mode 'batch' s'applique aussi aux grilles testées par 'essai'
+        let config = solver.try_and_see_config(1);
+        assert!(config.batch_eliminations);
+        assert_eq!(config.max_recursion, 1);
+    }
+
+    #[test]
+    fn test_apply_strategy_all() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+
+        // Initialisation puis élimination de tous les chiffres déjà placés dans les zones
+        let vec_actions = solver
+            .apply_strategy_all(SolvingStrategy::NumbersInZone)
+            .unwrap();
+        assert_eq!(vec_actions[0], SolvingAction::InitPossibleNumbers);
+        assert_eq!(vec_actions.len(), 1 + 5);
+        for action in &vec_actions[1..] {
+            assert!(matches!(action, SolvingAction::NumbersInZone(..)));
+
+            // Chiffres possibles après = chiffres possibles avant - chiffres supprimés
+            let change = action.candidates_change().unwrap();
+            let mut after = Simple09Set::new(&change.before);
+            for n in action.removed_digits() {
+                assert!(after.contains(*n));
+                after.remove(*n);
+            }
+            assert_eq!(after.as_vec_u8(), change.after);
+        }
+        assert_eq!(solver.difficulty_level, DifficultyLevel::Easy);
+
+        // Plus rien à éliminer avec cette stratégie
+        let vec_actions = solver
+            .apply_strategy_all(SolvingStrategy::NumbersInZone)
+            .unwrap();
+        assert!(vec_actions.is_empty());
+
+        // Élimination de tous les chiffres déjà placés dans les cases voisines
+        let vec_actions = solver
+            .apply_strategy_all(SolvingStrategy::NumbersNeighboring)
+            .unwrap();
+        assert!(!vec_actions.is_empty());
+        for action in &vec_actions {
+            assert!(matches!(action, SolvingAction::NumbersNeighboring(..)));
+        }
+        assert_eq!(solver.difficulty_level, DifficultyLevel::Medium);
+    }
+
+    #[test]
+    fn test_solve_outcome() {
+        // Grille résolue
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        assert!(solver.unresolved_cells().is_empty());
+
+        // Grille incohérente
+        let grid = Grid::from_str(
+            "
+        # NOK car a1 et b1 sont voisins
+        a1 b  b2
+        b4 b1 b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+        let mut solver = Solver::new(&grid);
+        assert!(matches!(
+            solver.solve(&SolverConfig::default()),
+            SolveOutcome::Inconsistent(SolvingError::NeighboringWithSameNumber(_, _, 1))
+        ));
+
+        // Grille bloquée sans la recherche par 'essai'
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+        let mut solver = Solver::new(&grid);
+        let outcome = solver.solve(&SolverConfig::new().max_recursion(0));
+        if let SolveOutcome::Stuck {
+            unresolved_cells,
+            hardest_technique_tried,
+        } = outcome
+        {
+            assert!(!unresolved_cells.is_empty());
+            assert_eq!(unresolved_cells, solver.unresolved_cells());
+            assert_eq!(hardest_technique_tried, SolvingStrategy::DualValuesPair);
+        } else {
+            panic!("SolveOutcome::Stuck attendu");
+        }
+
+        // Grille bloquée avec uniquement les stratégies faciles
+        let mut solver = Solver::new(&grid);
+        let outcome = solver.solve(&SolverConfig::new().strategies([
+            SolvingStrategy::SinglePossibleNumber,
+            SolvingStrategy::NumbersInZone,
+            SolvingStrategy::OnlyNumberInZone,
+        ]));
+        if let SolveOutcome::Stuck {
+            hardest_technique_tried,
+            ..
+        } = outcome
+        {
+            assert_eq!(hardest_technique_tried, SolvingStrategy::OnlyNumberInZone);
+            assert_eq!(solver.difficulty_level, DifficultyLevel::Easy);
+        } else {
+            panic!("SolveOutcome::Stuck attendu");
+        }
+    }
+
+    #[test]
+    fn test_error_with_grid() {
+        let grid = Grid::from_str(
+            "
+        # NOK car a1 et b1 sont voisins
+        a1 b  b2
+        b4 b1 b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        let outcome = solver.solve(&SolverConfig::new().snapshot_on_error(true));
+        if let SolveOutcome::Inconsistent(e) = outcome {
+            assert!(matches!(
+                e.root_cause(),
+                SolvingError::NeighboringWithSameNumber(_, _, 1)
+            ));
+            assert_eq!(e.grid_snapshot(), Some(grid.to_string().as_str()));
+            assert!(e.to_string().ends_with(&grid.to_string()));
+        } else {
+            panic!("SolveOutcome::Inconsistent attendu");
+        }
+
+        // Sans l'option, l'erreur n'est pas accompagnée de la grille
+        let mut solver = Solver::new(&grid);
+        if let SolveOutcome::Inconsistent(e) = solver.solve(&SolverConfig::new()) {
+            assert!(e.grid_snapshot().is_none());
+        } else {
+            panic!("SolveOutcome::Inconsistent attendu");
+        }
+    }
+
+    #[test]
+    fn test_difficulty_level_score() {
+        for level in DifficultyLevel::ALL {
+            assert_eq!(DifficultyLevel::from_score(level.as_u8()), level);
+        }
+        assert_eq!(DifficultyLevel::Unknown.as_u8(), 0);
+        assert_eq!(DifficultyLevel::from_score(0), DifficultyLevel::Unknown);
+        assert_eq!(DifficultyLevel::from_score(9), DifficultyLevel::Expert);
+        assert!(DifficultyLevel::ALL.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_expert_difficulty_level() {
+        // http://villemin.gerard.free.fr/aJeux/Tectonic.htm#Difficil (1ere grille)
+        let grid = Grid::from_str(
+            "
+            a  b  b2 c  c2
+            a  b  b  b  c
+            a  d  d  d  c
+            e  e  f  d  d
+            e  e  f1 f4 f
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        assert_eq!(solver.difficulty_level, DifficultyLevel::Expert);
+        assert_eq!(solver.difficulty_level.as_u8(), 5);
+
+        // Les essais imbriqués restent du niveau expert avec un autre barème des difficultés
+        let config =
+            SolverConfig::new().difficulty_level(SolvingStrategy::TryAndSee, DifficultyLevel::Hard);
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&config).is_solved());
+        assert_eq!(solver.difficulty_level, DifficultyLevel::Expert);
+    }
+
+    #[test]
+    fn test_expert_difficulty_level_custom_levels() {
+        // Jeu Le Routard no 13 - page 38 (niveau rouge) : un seul niveau d'essai
+        let grid = Grid::from_str(
+            "
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        // Une grille testée par 'essai' jugée très difficile avec ce barème ne rend pas la grille
+        // de niveau expert si elle n'a pas nécessité d'autre essai
+        let config = SolverConfig::new().max_recursion(1).difficulty_level(
+            SolvingStrategy::NumbersNeighboring,
+            DifficultyLevel::VeryHard,
+        );
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&config).is_solved());
+        assert_eq!(solver.search_stats.max_depth_reached, 1);
+        assert_eq!(solver.difficulty_level, DifficultyLevel::VeryHard);
+    }
+
+    #[test]
+    fn test_search_stats() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert_eq!(solver.search_stats(), SearchStats::default());
+
+        let _ = solver.solve(&SolverConfig::default());
+        let stats = solver.search_stats();
+        assert!(stats.trials > 0);
+        assert!(stats.max_depth_reached >= 1);
+        assert!(stats.max_depth_reached <= DEFAULT_MAX_TRY_AND_SEE_RECURSION_LEVEL);
+        assert_eq!(stats.grid_clones, stats.trials);
+        assert!(stats.nodes_explored > stats.trials);
+    }
+
+    #[test]
+    fn test_search_stats_without_try_and_see() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        let _ = solver.solve_with_options(&[SolvingOption::MaxTryAndSeeRecursionLevel(0)]);
+        let stats = solver.search_stats();
+        assert_eq!(stats.trials, 0);
+        assert_eq!(stats.max_depth_reached, 0);
+        assert!(stats.nodes_explored > 0);
+    }
+
+    #[test]
+    fn test_timings() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        // Temps non mesurés par défaut
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        assert_eq!(*solver.timings(), SolverTimings::default());
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver
+            .solve(&SolverConfig::new().measure_timings(true))
+            .is_solved());
+        let timings = solver.timings();
+        let try_and_see = timings.strategy(SolvingStrategy::TryAndSee);
+        assert!(timings.try_and_see_recursion > Duration::ZERO);
+        assert!(timings.try_and_see_recursion <= try_and_see);
+        assert!(try_and_see <= timings.total());
+
+        solver.reset();
+        assert_eq!(*solver.timings(), SolverTimings::default());
+    }
+
+    #[test]
+    fn test_new_shared() {
+        let grid = Arc::new(
+            Grid::from_str(
+                "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+            )
+            .unwrap(),
+        );
+
+        let mut solver = Solver::new(Arc::clone(&grid));
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+
+        // La grille d'origine partagée n'est pas modifiée par la résolution
+        assert_eq!(solver.original_grid().to_string(), grid.to_string());
+        assert_ne!(solver.to_grid().to_string(), grid.to_string());
+
+        // Un solver construit d'après la grille partagée d'un autre solver ne la copie pas
+        assert!(Arc::ptr_eq(&solver.shared_original_grid(), &grid));
+        let other_solver = Solver::new(solver.shared_original_grid());
+        assert!(Arc::ptr_eq(&other_solver.original_grid, &grid));
+        assert!(!Arc::ptr_eq(&Solver::new(&*grid).original_grid, &grid));
+    }
+
+    #[test]
+    fn test_action_accessors() {
+        let line_column_a = LineColumn::new(0, 0);
+        let line_column_b = LineColumn::new(0, 1);
+        let line_column_c = LineColumn::new(1, 0);
+
+        let action = SolvingAction::SinglePossibleNumber(line_column_a, 2);
+        assert_eq!(action.kind(), SolvingActionKind::SinglePossibleNumber);
+        assert_eq!(action.affected_cells(), vec![line_column_a]);
+        assert_eq!(action.placed_digit(), Some(2));
+        assert!(action.removed_digits().is_empty());
+
+        let change = CandidatesChange {
+            before: vec![1, 2, 3],
+            after: vec![2],
+        };
+        let action = SolvingAction::DualValuesPair(
+            line_column_a,
+            line_column_b,
+            line_column_c,
+            vec![1, 3],
+            change.clone(),
+        );
+        assert_eq!(action.affected_cells(), vec![line_column_c]);
+        assert_eq!(action.placed_digit(), None);
+        assert_eq!(action.removed_digits(), [1, 3]);
+        assert_eq!(action.candidates_change(), Some(&change));
+
+        let action = SolvingAction::Batch(vec![
+            SolvingAction::NumbersNeighboring(line_column_a, vec![4], CandidatesChange::default()),
+            SolvingAction::NumbersInZone(line_column_b, 'a', vec![1], CandidatesChange::default()),
+        ]);
+        assert_eq!(action.kind(), SolvingActionKind::Batch);
+        assert_eq!(action.affected_cells(), vec![line_column_a, line_column_b]);
+
+        assert!(SolvingAction::Solved.affected_cells().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_action_serde() {
+        let action = SolvingAction::Batch(vec![
+            SolvingAction::SinglePossibleNumber(LineColumn::new(1, 2), 3),
+            SolvingAction::NumbersInZone(
+                LineColumn::new(0, 1),
+                'b',
+                vec![1, 2],
+                CandidatesChange {
+                    before: vec![1, 2, 3],
+                    after: vec![3],
+                },
+            ),
+        ]);
+        let json = serde_json::to_string(&action).unwrap();
+        let action_json: SolvingAction = serde_json::from_str(&json).unwrap();
+        assert_eq!(action, action_json);
+    }
+
+    #[test]
+    fn test_techniques_used() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.techniques_used().is_empty());
+        assert!(solver
+            .solve(&SolverConfig::new().batch_eliminations(true))
+            .is_solved());
+
+        let techniques = solver.techniques_used();
+        assert!(techniques.contains(&SolvingStrategy::NumbersNeighboring));
+        assert!(!techniques.contains(&SolvingStrategy::TryAndSee));
+        for action in solver.history() {
+            if let Some(strategy) = action.strategy() {
+                assert!(techniques.contains(&strategy));
+            }
+        }
+        assert_eq!(
+            techniques
+                .last()
+                .map(|strategy| strategy.difficulty_level()),
+            Some(solver.difficulty_level)
+        );
+
+        solver.reset();
+        assert!(solver.techniques_used().is_empty());
+    }
+
+    #[test]
+    fn test_reset() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        assert!(solver.unresolved_cells().is_empty());
+
+        solver.reset();
+        assert_eq!(solver.to_grid().to_string(), grid.to_string());
+        assert_eq!(solver.difficulty_level, DifficultyLevel::Unknown);
+        assert_eq!(solver.search_stats(), SearchStats::default());
+
+        // La grille peut être à nouveau résolue
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        solver.snapshot("début");
+        assert!(solver.solve_step().is_ok());
+        solver.snapshot("après init");
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        assert_eq!(solver.snapshot_labels(), vec!["après init", "début"]);
+
+        // Retour à l'état initial, puis à l'état après l'initialisation des possibilités
+        assert!(solver.restore("début"));
+        assert_eq!(solver.to_grid().to_string(), grid.to_string());
+        assert!(solver.restore("après init"));
+        assert_eq!(solver.unresolved_cells().len(), 5);
+        assert!(matches!(
+            solver.cell_content(LineColumn::new(0, 1)),
+            Some(CellContent::PossibleNumbers(_))
+        ));
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+
+        assert!(!solver.restore("inconnu"));
+        assert!(solver.is_solved());
+    }
+
+    #[test]
+    fn test_replay() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        let history = solver.history().to_vec();
+        assert_eq!(history.first(), Some(&SolvingAction::InitPossibleNumbers));
+        assert_eq!(history.last(), Some(&SolvingAction::Solved));
+
+        // Rejoue la résolution sur la grille d'origine
+        let mut replay_solver = Solver::new(&grid);
+        assert!(replay_solver.replay(&history).is_ok());
+        assert!(replay_solver.is_solved());
+        assert_eq!(
+            replay_solver.to_grid().to_string(),
+            solver.to_grid().to_string()
+        );
+        assert_eq!(replay_solver.history(), history.as_slice());
+
+        // Une séquence qui ne commence pas par l'initialisation ne s'applique pas
+        let mut replay_solver = Solver::new(&grid);
+        assert_eq!(
+            replay_solver.replay(&history[1..]),
+            Err(SolvingError::ActionNotApplicable(0))
+        );
+
+        // Une action rejouée 2 fois ne s'applique plus
+        let mut replay_solver = Solver::new(&grid);
+        let index = history
+            .iter()
+            .position(|action| action.placed_digit().is_some())
+            .unwrap();
+        let mut actions = history[..=index].to_vec();
+        actions.push(history[index].clone());
+        assert_eq!(
+            replay_solver.replay(&actions),
+            Err(SolvingError::ActionNotApplicable(index + 1))
+        );
+    }
+
+    #[test]
+    fn test_all_examples() {
+        // Test tous les fichiers ""./examples/*.txt" pour résolution
+        for entry in fs::read_dir("./examples").unwrap() {
+            let entry = entry.unwrap();
+            let path = entry.path();
+            let path_str = path.to_str().unwrap();
+            let path_path = path::Path::new(path_str);
+
+            if path_path.is_file()
+                && path_path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .starts_with("ex")
                 && path_path
                     .extension()
-                    .map_or(false, |ext| ext.eq_ignore_ascii_case("txt"))
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"))
             {
                 println!("Trying to solve '{path_str}'...");
 
                 let file_content = fs::read_to_string(path_str).unwrap();
                 let grid = Grid::from_str(&file_content).unwrap();
                 let mut solver = Solver::new(&grid);
-                let res_solver = solver.solve(&[SolvingOption::MaxTryAndSeeRecursionLevel(3)]);
+                let res_solver = solver.solve(&SolverConfig::new().max_recursion(3));
 
                 match res_solver {
-                    Err(e) => println!("Erreur résolution avec le fichier '{path_str}': {e}\n"),
-                    Ok(done) => assert!(done),
+                    SolveOutcome::Inconsistent(e) => {
+                        println!("Erreur résolution avec le fichier '{path_str}': {e}\n");
+                    }
+                    outcome => assert!(outcome.is_solved()),
                 }
             }
         }
     }
+
+    /// Événements `tracing` reçus pendant un test : niveau et champs (avec le message) de chaque
+    /// événement
+    #[cfg(feature = "tracing")]
+    #[derive(Clone, Default)]
+    struct CapturedEvents(std::sync::Arc<std::sync::Mutex<Vec<(tracing::Level, String)>>>);
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for CapturedEvents {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            struct Fields(Vec<String>);
+
+            impl tracing::field::Visit for Fields {
+                fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn fmt::Debug) {
+                    self.0.push(format!("{}={value:?}", field.name()));
+                }
+            }
+
+            let mut fields = Fields(Vec::new());
+            event.record(&mut fields);
+            self.0
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), fields.0.join(" ")));
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing_events() {
+        let grid = Grid::from_str(
+            "
+            a  b  b2 c  c2
+            a  b  b  b  c
+            a  d  d  d  c
+            e  e  f  d  d
+            e  e  f1 f4 f
+        ",
+        )
+        .unwrap();
+        let events = CapturedEvents::default();
+        tracing::subscriber::with_default(events.clone(), || {
+            let mut solver = Solver::new(&grid);
+            assert!(solver.solve(&SolverConfig::default()).is_solved());
+        });
+        let vec_events = events.0.lock().unwrap().clone();
+
+        // Une action par étape, puis les essais des recherches par 'essai'
+        assert!(vec_events.contains(&(
+            tracing::Level::DEBUG,
+            "message=Grille résolue kind=Solved recursion_level=0".to_string()
+        )));
+        assert!(vec_events
+            .iter()
+            .any(|(level, fields)| *level == tracing::Level::TRACE
+                && fields.starts_with("message=essai d'une valeur")
+                && fields.contains("recursion_level=1")));
+
+        // Grille incohérente
+        let grid = Grid::from_str("a1 a1").unwrap();
+        let events = CapturedEvents::default();
+        tracing::subscriber::with_default(events.clone(), || {
+            let mut solver = Solver::new(&grid);
+            assert!(solver.solve_step().is_err());
+        });
+        let vec_events = events.0.lock().unwrap().clone();
+        assert_eq!(vec_events.len(), 1);
+        assert!(vec_events[0]
+            .1
+            .starts_with("message=grille incohérente error="));
+    }
 }
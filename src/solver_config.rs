@@ -0,0 +1,395 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::grid_formatter::GridFormatter;
+use crate::solver::{DifficultyLevel, Solver, SolvingAction, SolvingOption, SolvingStrategy};
+
+/// Niveau max de récursion par défaut avec la fonction récursive `solve_try_and_see`.
+///
+/// Cette fonction peut être appelée récursivement si la grille à résoudre est très complexe
+/// (ou si elle est en cours de construction). On stoppe les niveaux trop élevés de recherche par
+/// récursion qui correspondrait à une solution trop difficile à trouver
+pub(crate) const DEFAULT_MAX_TRY_AND_SEE_RECURSION_LEVEL: i32 = 3;
+
+/// Closure appelée avec l'action faite à chaque étape de la résolution
+type ActionCallback = Rc<dyn Fn(&SolvingAction)>;
+
+/// Closure appelée avec le contenu du solver à chaque étape de la résolution
+type SolverCallback = Rc<dyn Fn(&Solver)>;
+
+/// Configuration de la résolution d'une grille par `Solver::solve`
+///
+/// La configuration se construit par chaînage :
+///
+/// ```rust
+/// use tectonic::{SolverConfig, SolvingStrategy};
+///
+/// let config = SolverConfig::new()
+///     .max_recursion(2)
+///     .strategies([
+///         SolvingStrategy::SinglePossibleNumber,
+///         SolvingStrategy::NumbersInZone,
+///         SolvingStrategy::OnlyNumberInZone,
+///         SolvingStrategy::NumbersNeighboring,
+///     ])
+///     .on_step(|action| println!("{action}"));
+/// ```
+#[derive(Clone)]
+pub struct SolverConfig {
+    /// Niveau max de récursion lors de la recherche par 'essai'
+    pub(crate) max_recursion: i32,
+
+    /// Recherche par 'essai' en approfondissement itératif
+    pub(crate) iterative_deepening: bool,
+
+    /// Éliminations appliquées à toute la grille en une seule étape
+    pub(crate) batch_eliminations: bool,
+
+    /// Stratégies de résolution utilisées (de la plus simple à la plus difficile)
+    pub(crate) strategies: Vec<SolvingStrategy>,
+
+    /// Niveaux de difficulté personnalisés des stratégies (voir `SolvingStrategy::difficulty_level`
+    /// pour les stratégies absentes)
+    pub(crate) difficulty_levels: HashMap<SolvingStrategy, DifficultyLevel>,
+
+    /// Mesure du temps passé dans chaque stratégie (voir `Solver::timings`)
+    pub(crate) measure_timings: bool,
+
+    /// État de la grille ajouté à l'erreur si la grille n'est pas (ou plus) cohérente
+    pub(crate) snapshot_on_error: bool,
+
+    /// Affichage de l'action faite à chaque étape
+    pub(crate) print_actions: bool,
+
+    /// Affichage de la grille à chaque étape
+    pub(crate) print_grid: bool,
+
+    /// Options de l'affichage de la grille à chaque étape
+    pub(crate) grid_formatter: GridFormatter,
+
+    /// Affichage de la grille en couleurs (voir `Grid::to_ansi_string`)
+    #[cfg(feature = "color")]
+    pub(crate) print_colors: bool,
+
+    /// Closures appelées avec l'action faite à chaque étape
+    pub(crate) action_callbacks: Vec<ActionCallback>,
+
+    /// Closures appelées avec le solver à chaque étape
+    pub(crate) solver_callbacks: Vec<SolverCallback>,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        SolverConfig {
+            max_recursion: DEFAULT_MAX_TRY_AND_SEE_RECURSION_LEVEL,
+            iterative_deepening: false,
+            batch_eliminations: false,
+            strategies: SolvingStrategy::ALL.to_vec(),
+            difficulty_levels: HashMap::new(),
+            measure_timings: false,
+            snapshot_on_error: false,
+            print_actions: false,
+            print_grid: false,
+            grid_formatter: GridFormatter::default(),
+            #[cfg(feature = "color")]
+            print_colors: false,
+            action_callbacks: Vec::new(),
+            solver_callbacks: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Debug for SolverConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("SolverConfig");
+        debug_struct
+            .field("max_recursion", &self.max_recursion)
+            .field("iterative_deepening", &self.iterative_deepening)
+            .field("batch_eliminations", &self.batch_eliminations)
+            .field("strategies", &self.strategies)
+            .field("difficulty_levels", &self.difficulty_levels)
+            .field("measure_timings", &self.measure_timings)
+            .field("snapshot_on_error", &self.snapshot_on_error)
+            .field("print_actions", &self.print_actions)
+            .field("print_grid", &self.print_grid)
+            .field("grid_formatter", &self.grid_formatter);
+        #[cfg(feature = "color")]
+        debug_struct.field("print_colors", &self.print_colors);
+        debug_struct
+            .field("action_callbacks", &self.action_callbacks.len())
+            .field("solver_callbacks", &self.solver_callbacks.len())
+            .finish()
+    }
+}
+
+impl SolverConfig {
+    /// Configuration par défaut : toutes les stratégies et un niveau max de récursion de 3
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limitation du niveau de récursion lors de la recherche par 'essai' (niveau très difficile)
+    /// Une valeur de 0, inhibe cette possibilité qui peut mener à des temps de calculs relativement long
+    /// Une valeur d'au moins 3 est nécessaire pour des grilles très très difficiles
+    #[must_use]
+    pub fn max_recursion(mut self, level: i32) -> Self {
+        self.max_recursion = level;
+        self
+    }
+
+    /// Recherche par 'essai' en approfondissement itératif (voir
+    /// `SolvingOption::TryAndSeeIterativeDeepening`)
+    #[must_use]
+    pub fn iterative_deepening(mut self, enabled: bool) -> Self {
+        self.iterative_deepening = enabled;
+        self
+    }
+
+    /// Éliminations de chiffres appliquées à toute la grille en une seule étape (voir
+    /// `SolvingOption::BatchEliminations`)
+    #[must_use]
+    pub fn batch_eliminations(mut self, enabled: bool) -> Self {
+        self.batch_eliminations = enabled;
+        self
+    }
+
+    /// Stratégies de résolution utilisées
+    /// Elles sont toujours appliquées de la plus simple à la plus difficile, quel que soit l'ordre donné
+    #[must_use]
+    pub fn strategies(mut self, strategies: impl IntoIterator<Item = SolvingStrategy>) -> Self {
+        let mut strategies: Vec<SolvingStrategy> = strategies.into_iter().collect();
+        strategies.sort();
+        strategies.dedup();
+        self.strategies = strategies;
+        self
+    }
+
+    /// Niveau de difficulté reporté lorsque cette stratégie est utilisée pendant la résolution
+    /// (remplace le niveau par défaut de `SolvingStrategy::difficulty_level`)
+    #[must_use]
+    pub fn difficulty_level(mut self, strategy: SolvingStrategy, level: DifficultyLevel) -> Self {
+        self.difficulty_levels.insert(strategy, level);
+        self
+    }
+
+    /// Mesure du temps passé dans chaque stratégie et dans les recherches par 'essai'
+    /// (voir `Solver::timings`)
+    #[must_use]
+    pub fn measure_timings(mut self, enabled: bool) -> Self {
+        self.measure_timings = enabled;
+        self
+    }
+
+    /// Ajoute l'état de la grille à l'erreur retournée par `SolveOutcome::Inconsistent`
+    /// (voir `SolvingError::WithGrid`)
+    #[must_use]
+    pub fn snapshot_on_error(mut self, enabled: bool) -> Self {
+        self.snapshot_on_error = enabled;
+        self
+    }
+
+    /// Affichage de l'action faite à chaque étape de la résolution
+    #[must_use]
+    pub fn print_actions(mut self, enabled: bool) -> Self {
+        self.print_actions = enabled;
+        self
+    }
+
+    /// Affichage de la grille à chaque étape de la résolution
+    #[must_use]
+    pub fn print_grid(mut self, enabled: bool) -> Self {
+        self.print_grid = enabled;
+        self
+    }
+
+    /// Options de l'affichage de la grille à chaque étape de la résolution (avec `print_grid`)
+    #[must_use]
+    pub fn grid_formatter(mut self, grid_formatter: GridFormatter) -> Self {
+        self.grid_formatter = grid_formatter;
+        self
+    }
+
+    /// Affichage en couleurs de la grille à chaque étape de la résolution (avec `print_grid`) :
+    /// chaque zone a sa couleur de fond et le chiffre placé par l'étape est en rouge
+    #[cfg(feature = "color")]
+    #[must_use]
+    pub fn print_colors(mut self, enabled: bool) -> Self {
+        self.print_colors = enabled;
+        self
+    }
+
+    /// Ajoute une closure appelée avec l'action faite à chaque étape de la résolution
+    #[must_use]
+    pub fn on_step(mut self, callback: impl Fn(&SolvingAction) + 'static) -> Self {
+        self.action_callbacks.push(Rc::new(callback));
+        self
+    }
+
+    /// Ajoute une closure appelée avec le contenu du solver à chaque étape de la résolution
+    #[must_use]
+    pub fn on_solver_step(mut self, callback: impl Fn(&Solver) + 'static) -> Self {
+        self.solver_callbacks.push(Rc::new(callback));
+        self
+    }
+
+    /// Texte de la grille affichée à chaque étape (avec `print_grid`)
+    #[cfg_attr(not(feature = "color"), allow(unused_variables))]
+    fn grid_text(&self, solver: &Solver, action: &SolvingAction) -> String {
+        #[cfg(feature = "color")]
+        if self.print_colors {
+            let highlight = action
+                .placed_digit()
+                .and_then(|_| action.affected_cells().first().copied());
+            return solver.to_grid().to_ansi_string(highlight);
+        }
+        self.grid_formatter.format(&solver.to_grid())
+    }
+
+    /// Appel des affichages et callbacks demandés à chaque étape
+    pub(crate) fn do_step_callback(&self, solver: &Solver, action: &SolvingAction) {
+        if self.print_actions {
+            println!("{action}");
+        }
+        for callback in &self.action_callbacks {
+            callback(action);
+        }
+        if self.print_grid {
+            println!("{}", self.grid_text(solver, action));
+        }
+        for callback in &self.solver_callbacks {
+            callback(solver);
+        }
+    }
+}
+
+impl From<&[SolvingOption]> for SolverConfig {
+    fn from(options: &[SolvingOption]) -> Self {
+        let mut config = SolverConfig::default();
+        for option in options {
+            config = match option {
+                SolvingOption::StepPrintAction => config.print_actions(true),
+                SolvingOption::StepCallbackAction(f) => config.on_step(*f),
+                SolvingOption::StepPrintGrid => config.print_grid(true),
+                SolvingOption::StepCallbackSolver(f) => config.on_solver_step(*f),
+                SolvingOption::MaxTryAndSeeRecursionLevel(level) => config.max_recursion(*level),
+                SolvingOption::TryAndSeeIterativeDeepening => config.iterative_deepening(true),
+                SolvingOption::BatchEliminations => config.batch_eliminations(true),
+            };
+        }
+        config
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    #[cfg(feature = "color")]
+    use crate::line_column::LineColumn;
+    use std::cell::Cell;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_options() {
+        let config = SolverConfig::from(
+            &[
+                SolvingOption::MaxTryAndSeeRecursionLevel(1),
+                SolvingOption::BatchEliminations,
+            ][..],
+        );
+        assert_eq!(config.max_recursion, 1);
+        assert!(config.batch_eliminations);
+        assert!(!config.iterative_deepening);
+        assert_eq!(config.strategies, SolvingStrategy::ALL);
+    }
+
+    #[test]
+    fn test_strategies_sorted() {
+        let config = SolverConfig::new().strategies([
+            SolvingStrategy::NumbersNeighboring,
+            SolvingStrategy::SinglePossibleNumber,
+            SolvingStrategy::NumbersNeighboring,
+        ]);
+        assert_eq!(
+            config.strategies,
+            [
+                SolvingStrategy::SinglePossibleNumber,
+                SolvingStrategy::NumbersNeighboring
+            ]
+        );
+    }
+
+    #[test]
+    fn test_difficulty_levels() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::new()).is_solved());
+        assert_eq!(solver.difficulty_level, DifficultyLevel::Medium);
+
+        // Barème où les éliminations par les cases voisines sont jugées très difficiles
+        let config = SolverConfig::new().difficulty_level(
+            SolvingStrategy::NumbersNeighboring,
+            DifficultyLevel::VeryHard,
+        );
+        assert_eq!(
+            config.difficulty_levels,
+            HashMap::from([(
+                SolvingStrategy::NumbersNeighboring,
+                DifficultyLevel::VeryHard
+            )])
+        );
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&config).is_solved());
+        assert_eq!(solver.difficulty_level, DifficultyLevel::VeryHard);
+    }
+
+    #[test]
+    fn test_on_step() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let nb_steps = Rc::new(Cell::new(0));
+        let nb_steps_callback = Rc::clone(&nb_steps);
+        let config = SolverConfig::new()
+            .on_step(move |_| nb_steps_callback.set(nb_steps_callback.get() + 1));
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&config).is_solved());
+        assert!(nb_steps.get() > 1);
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn test_grid_text_colors() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let solver = Solver::new(&grid);
+        let action = SolvingAction::SinglePossibleNumber(LineColumn::new(0, 1), 3);
+        let config = SolverConfig::new().print_grid(true);
+        assert_eq!(config.grid_text(&solver, &action), solver.to_string());
+        let boxed = config.clone().grid_formatter(GridFormatter::boxed());
+        assert_eq!(boxed.grid_text(&solver, &action), format!("{solver:#}"));
+        let config = config.print_colors(true);
+        assert_eq!(
+            config.grid_text(&solver, &action),
+            solver.to_grid().to_ansi_string(Some(LineColumn::new(0, 1)))
+        );
+    }
+}
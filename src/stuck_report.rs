@@ -0,0 +1,131 @@
+use std::fmt;
+
+use crate::grid::CellContent;
+use crate::line_column::LineColumn;
+use crate::solver::{Solver, SolvingStrategy};
+
+/// Rapport sur l'état d'une résolution bloquée
+///
+/// Ce rapport est construit par `Solver::stuck_report` lorsque le solver ne trouve plus d'action
+/// de résolution possible pour indiquer ce qui reste à trouver.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StuckReport {
+    /// Cases non résolues (triées par ligne puis colonne) avec leurs chiffres encore possibles
+    pub remaining_cells: Vec<(LineColumn, Vec<u8>)>,
+
+    /// Stratégies de résolution tentées (de la plus simple à la plus difficile)
+    pub strategies_attempted: Vec<SolvingStrategy>,
+}
+
+impl StuckReport {
+    /// Retourne true si aucune case ne reste à résoudre
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.remaining_cells.is_empty()
+    }
+}
+
+impl fmt::Display for StuckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Cases non résolues : {}", self.remaining_cells.len())?;
+        for (line_column, vec_n) in &self.remaining_cells {
+            writeln!(f, "  {line_column} : {vec_n:?}")?;
+        }
+        writeln!(f, "Stratégies tentées :")?;
+        for strategy in &self.strategies_attempted {
+            writeln!(f, "  {strategy}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Solver {
+    /// Construit le rapport sur l'état de la résolution : cases restantes avec leurs chiffres
+    /// possibles et stratégies tentées
+    ///
+    /// Ce rapport est surtout utile lorsque la résolution s'arrête sans trouver de solution
+    /// (`SolveOutcome::Stuck`)
+    #[must_use]
+    pub fn stuck_report(&self) -> StuckReport {
+        let remaining_cells = self
+            .unresolved_cells()
+            .into_iter()
+            .map(|line_column| {
+                let vec_n = match self.cell_content(line_column) {
+                    Some(CellContent::PossibleNumbers(simple_09_set)) => simple_09_set.as_vec_u8(),
+                    _ => Vec::new(),
+                };
+                (line_column, vec_n)
+            })
+            .collect();
+
+        let strategies_attempted = self.strategies_tried();
+
+        StuckReport {
+            remaining_cells,
+            strategies_attempted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use crate::solver::SolveOutcome;
+    use crate::solver_config::SolverConfig;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_stuck_report() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        let outcome = solver.solve(&SolverConfig::new().max_recursion(0));
+        assert!(matches!(outcome, SolveOutcome::Stuck { .. }));
+
+        let report = solver.stuck_report();
+        assert!(!report.is_empty());
+        assert_eq!(
+            report.remaining_cells.len(),
+            solver.unresolved_cells().len()
+        );
+        for (_, vec_n) in &report.remaining_cells {
+            assert!(vec_n.len() >= 2);
+        }
+        assert_eq!(
+            report.strategies_attempted.last(),
+            Some(&SolvingStrategy::DualValuesPair)
+        );
+        assert!(!report
+            .strategies_attempted
+            .contains(&SolvingStrategy::TryAndSee));
+    }
+
+    #[test]
+    fn test_stuck_report_solved() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        assert!(solver.stuck_report().is_empty());
+    }
+}
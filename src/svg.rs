@@ -0,0 +1,371 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::solver::SolvingAction;
+use crate::zone_border::CellSide;
+
+/// Couleur des chiffres placés pendant la résolution (les chiffres connus au départ sont noirs)
+const PLACED_COLOR: &str = "#2f5fb3";
+
+/// Couleur des chiffres possibles (pencil marks)
+const CANDIDATE_COLOR: &str = "#777777";
+
+/// Options du dessin SVG d'une grille avec `Grid::to_svg`
+///
+/// Les options se construisent par chaînage :
+///
+/// ```rust
+/// use tectonic::SvgOptions;
+///
+/// let options = SvgOptions::new().cell_size(32).candidates(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SvgOptions {
+    /// Taille d'une case (en pixels)
+    pub(crate) cell_size: u32,
+
+    /// Dessin des chiffres possibles des cases en cours de résolution
+    pub(crate) candidates: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            cell_size: 40,
+            candidates: false,
+        }
+    }
+}
+
+impl SvgOptions {
+    /// Options par défaut : cases de 40 pixels sans les chiffres possibles
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Taille d'une case en pixels (au moins 8 pixels)
+    #[must_use]
+    pub fn cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size.max(8);
+        self
+    }
+
+    /// Dessin des chiffres possibles d'une case en cours de résolution (en petit, chaque chiffre
+    /// à sa place dans un carré de 3x3)
+    #[must_use]
+    pub fn candidates(mut self, candidates: bool) -> Self {
+        self.candidates = candidates;
+        self
+    }
+}
+
+/// Couleur de fond des cases modifiées par une action (voir `Grid::to_action_svg`)
+const TARGET_FILL: &str = "#fff2a8";
+
+/// Couleur de fond des cases qui justifient une action (paire de valeurs, zone, etc.)
+const CAUSE_FILL: &str = "#d6e6ff";
+
+/// Couleur des chiffres supprimés des chiffres possibles par une action
+const REMOVED_COLOR: &str = "#d62728";
+
+/// Annotations du dessin d'une grille pour illustrer une action de résolution
+#[derive(Default)]
+struct ActionAnnotations {
+    /// Cases dont le contenu est modifié par l'action
+    targets: HashSet<LineColumn>,
+
+    /// Cases qui justifient l'action
+    causes: HashSet<LineColumn>,
+
+    /// Chiffre placé par l'action dans une case
+    placed: HashMap<LineColumn, u8>,
+
+    /// Chiffres supprimés par l'action des chiffres possibles d'une case
+    removed: HashMap<LineColumn, Vec<u8>>,
+}
+
+impl ActionAnnotations {
+    fn new(grid: &Grid, action: &SolvingAction) -> Self {
+        let mut annotations = ActionAnnotations::default();
+        annotations.add(grid, action);
+        annotations
+    }
+
+    fn add(&mut self, grid: &Grid, action: &SolvingAction) {
+        let zone_cells = |c_zone: char, line_column: LineColumn| -> Vec<LineColumn> {
+            grid.zones()
+                .filter(|(c, _)| **c == c_zone)
+                .flat_map(|(_, zone)| zone.cells())
+                .filter(|zone_line_column| *zone_line_column != line_column)
+                .collect()
+        };
+        match action {
+            SolvingAction::Batch(vec_actions) => {
+                for action in vec_actions {
+                    self.add(grid, action);
+                }
+                return;
+            }
+            SolvingAction::NumbersInZone(line_column, c_zone, _, _)
+            | SolvingAction::OnlyNumberInZone(c_zone, line_column, _) => {
+                self.causes.extend(zone_cells(*c_zone, *line_column));
+            }
+            SolvingAction::DualValuesPair(line_column_1, line_column_2, _, _, _) => {
+                self.causes.extend([*line_column_1, *line_column_2]);
+            }
+            _ => (),
+        }
+        for line_column in action.affected_cells() {
+            self.targets.insert(line_column);
+            if let Some(n) = action.placed_digit() {
+                self.placed.insert(line_column, n);
+            }
+            let removed_digits = action.removed_digits();
+            if !removed_digits.is_empty() {
+                self.removed
+                    .entry(line_column)
+                    .or_default()
+                    .extend_from_slice(removed_digits);
+            }
+        }
+        self.causes
+            .retain(|line_column| !self.targets.contains(line_column));
+    }
+}
+
+impl Grid {
+    /// Dessin SVG de la grille pour une publication : fines bordures entre les cases d'une même
+    /// zone, bordures épaisses entre deux zones et autour de la grille, chiffres connus au départ
+    /// en gras et, en option, chiffres possibles des cases en cours de résolution
+    ///
+    /// Une case absente de la grille (trou) n'est pas dessinée
+    #[must_use]
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        self.svg_with(options, &ActionAnnotations::default())
+    }
+
+    /// Dessin SVG de la grille (voir `to_svg`) qui illustre une action de résolution faite sur
+    /// cette grille, par exemple pour un tutoriel pas à pas construit avec l'historique de la
+    /// résolution : les cases modifiées par l'action sont sur fond jaune, les cases qui la
+    /// justifient (paire de valeurs, autres cases de la zone) sur fond bleu, le chiffre placé est
+    /// ajouté et les chiffres supprimés des chiffres possibles sont en rouge
+    #[must_use]
+    pub fn to_action_svg(&self, action: &SolvingAction, options: &SvgOptions) -> String {
+        self.svg_with(options, &ActionAnnotations::new(self, action))
+    }
+
+    /// Dessin SVG de la grille avec les annotations d'une action
+    fn svg_with(&self, options: &SvgOptions, annotations: &ActionAnnotations) -> String {
+        let size = options.cell_size;
+        let thick = (size / 12).max(2);
+        let margin = thick;
+        let (min, max) = self
+            .bounds()
+            .unwrap_or((LineColumn::default(), LineColumn::new(-1, -1)));
+        let (width, height) = (
+            u32::try_from(max.column - min.column + 1).unwrap(),
+            u32::try_from(max.line - min.line + 1).unwrap(),
+        );
+        let (svg_width, svg_height) = (width * size + 2 * margin, height * size + 2 * margin);
+
+        // Coin supérieur gauche d'une case dans le dessin
+        let origin = |line_column: LineColumn| {
+            (
+                margin + u32::try_from(line_column.column - min.column).unwrap() * size,
+                margin + u32::try_from(line_column.line - min.line).unwrap() * size,
+            )
+        };
+
+        let mut vec_cells: Vec<_> = self.cells().collect();
+        vec_cells.sort_by_key(|(line_column, _)| (line_column.line, line_column.column));
+
+        let (mut thin_path, mut fills, mut thick_path, mut texts) =
+            (String::new(), String::new(), String::new(), String::new());
+        for (line_column, cell) in vec_cells {
+            let (x, y) = origin(*line_column);
+            write!(thin_path, "M{x},{y}h{size}v{size}h-{size}z").unwrap();
+
+            let option_fill = if annotations.targets.contains(line_column) {
+                Some(TARGET_FILL)
+            } else if annotations.causes.contains(line_column) {
+                Some(CAUSE_FILL)
+            } else {
+                None
+            };
+            if let Some(fill) = option_fill {
+                writeln!(
+                    fills,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{size}\" height=\"{size}\" fill=\"{fill}\"/>"
+                )
+                .unwrap();
+            }
+
+            let removed = annotations
+                .removed
+                .get(line_column)
+                .map_or(&[][..], Vec::as_slice);
+            let option_number = match &cell.content {
+                CellContent::Number(n) => Some(*n),
+                _ => annotations.placed.get(line_column).copied(),
+            };
+            if let Some(n) = option_number {
+                let style = if cell.is_given {
+                    " font-weight=\"bold\"".to_string()
+                } else {
+                    format!(" fill=\"{PLACED_COLOR}\"")
+                };
+                writeln!(
+                    texts,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\"{style}>{n}</text>",
+                    x + size / 2,
+                    y + size / 2,
+                    size * 3 / 5
+                )
+                .unwrap();
+                continue;
+            }
+
+            // Chiffres possibles (si demandés) et chiffres supprimés par l'action
+            let mut candidates: Vec<u8> = match &cell.content {
+                CellContent::PossibleNumbers(simple_09_set) if options.candidates => {
+                    simple_09_set.as_vec_u8()
+                }
+                _ => Vec::new(),
+            };
+            candidates.extend_from_slice(removed);
+            candidates.sort_unstable();
+            candidates.dedup();
+            for n in candidates {
+                let (dx, dy) = (u32::from(n - 1) % 3, u32::from(n - 1) / 3);
+                let style = if removed.contains(&n) {
+                    format!("fill=\"{REMOVED_COLOR}\" text-decoration=\"line-through\"")
+                } else {
+                    format!("fill=\"{CANDIDATE_COLOR}\"")
+                };
+                writeln!(
+                    texts,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" {style}>{n}</text>",
+                    x + size * (2 * dx + 1) / 6,
+                    y + size * (2 * dy + 1) / 6,
+                    size / 4
+                )
+                .unwrap();
+            }
+        }
+
+        // Bordures épaisses entre les zones et autour de la grille
+        for (line_column, side) in self.zone_borders() {
+            let (x, y) = origin(line_column);
+            match side {
+                CellSide::Top => write!(thick_path, "M{x},{y}h{size}"),
+                CellSide::Right => write!(thick_path, "M{},{y}v{size}", x + size),
+                CellSide::Bottom => write!(thick_path, "M{x},{}h{size}", y + size),
+                CellSide::Left => write!(thick_path, "M{x},{y}v{size}"),
+            }
+            .unwrap();
+        }
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{svg_width}\" height=\"{svg_height}\" \
+             viewBox=\"0 0 {svg_width} {svg_height}\">\n\
+             <path d=\"{thin_path}\" fill=\"#ffffff\" stroke=\"#999999\" stroke-width=\"1\"/>\n\
+             {fills}\
+             <path d=\"{thick_path}\" fill=\"none\" stroke=\"#000000\" stroke-width=\"{thick}\" \
+             stroke-linecap=\"square\"/>\n\
+             <g font-family=\"sans-serif\" text-anchor=\"middle\" dominant-baseline=\"central\">\n\
+             {texts}</g>\n\
+             </svg>\n"
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::simple_09_set::Simple09Set;
+    use crate::solver::CandidatesChange;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_svg() {
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let svg = grid.to_svg(&SvgOptions::default());
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        // 3 cases de 40 pixels et une marge de 3 pixels de chaque côté
+        assert!(svg.contains("viewBox=\"0 0 126 126\""));
+        assert_eq!(svg.matches("font-weight=\"bold\"").count(), 4);
+        assert_eq!(svg.matches("<text ").count(), 4);
+        let thick_path = svg
+            .lines()
+            .find(|line| line.contains("fill=\"none\""))
+            .unwrap();
+        assert_eq!(thick_path.matches('M').count(), grid.zone_borders().len());
+
+        // Chiffre placé et chiffres possibles
+        grid.get_mut_cell(LineColumn::new(0, 1)).unwrap().content = CellContent::Number(3);
+        grid.get_mut_cell(LineColumn::new(1, 1)).unwrap().content =
+            CellContent::PossibleNumbers(Simple09Set::new(&[1, 5]));
+        let svg = grid.to_svg(&SvgOptions::default());
+        assert_eq!(svg.matches(PLACED_COLOR).count(), 1);
+        assert_eq!(svg.matches(CANDIDATE_COLOR).count(), 0);
+        let svg = grid.to_svg(&SvgOptions::new().cell_size(60).candidates(true));
+        assert_eq!(svg.matches(CANDIDATE_COLOR).count(), 2);
+        assert!(svg.contains("viewBox=\"0 0 190 190\""));
+
+        // Grille vide
+        assert!(Grid::default()
+            .to_svg(&SvgOptions::default())
+            .contains("viewBox=\"0 0 6 6\""));
+    }
+
+    #[test]
+    fn test_to_action_svg() {
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let options = SvgOptions::default();
+        assert_eq!(
+            grid.to_action_svg(&SolvingAction::NoAction, &options),
+            grid.to_svg(&options)
+        );
+
+        // Chiffre placé
+        let action = SolvingAction::SinglePossibleNumber(LineColumn::new(0, 1), 3);
+        let svg = grid.to_action_svg(&action, &options);
+        assert_eq!(svg.matches(TARGET_FILL).count(), 1);
+        assert!(svg.contains(&format!("fill=\"{PLACED_COLOR}\">3</text>")));
+
+        // Chiffres supprimés à cause des autres cases de la zone
+        grid.get_mut_cell(LineColumn::new(1, 1)).unwrap().content =
+            CellContent::PossibleNumbers(Simple09Set::new(&[1, 2, 3, 4, 5]));
+        let action = SolvingAction::NumbersInZone(
+            LineColumn::new(1, 1),
+            'b',
+            vec![2, 4],
+            CandidatesChange::default(),
+        );
+        let svg = grid.to_action_svg(&action, &options);
+        assert_eq!(svg.matches(TARGET_FILL).count(), 1);
+        assert_eq!(svg.matches(CAUSE_FILL).count(), 4);
+        assert_eq!(svg.matches(REMOVED_COLOR).count(), 2);
+        assert_eq!(svg.matches(CANDIDATE_COLOR).count(), 0);
+        let svg = grid.to_action_svg(&action, &options.clone().candidates(true));
+        assert_eq!(svg.matches(REMOVED_COLOR).count(), 2);
+        assert_eq!(svg.matches(CANDIDATE_COLOR).count(), 3);
+
+        // Paire de valeurs dans une action groupée
+        let action = SolvingAction::Batch(vec![SolvingAction::DualValuesPair(
+            LineColumn::new(0, 1),
+            LineColumn::new(1, 2),
+            LineColumn::new(1, 1),
+            vec![1],
+            CandidatesChange::default(),
+        )]);
+        let svg = grid.to_action_svg(&action, &options);
+        assert_eq!(svg.matches(CAUSE_FILL).count(), 2);
+        assert_eq!(svg.matches(TARGET_FILL).count(), 1);
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::grid::{CellContent, Grid};
+
+/// Symétrie d'une grille (voir `Grid::symmetries`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Symmetry {
+    /// Rotation d'un quart de tour
+    Rotation90,
+
+    /// Rotation d'un demi-tour
+    Rotation180,
+
+    /// Symétrie gauche/droite (axe vertical)
+    MirrorHorizontal,
+
+    /// Symétrie haut/bas (axe horizontal)
+    MirrorVertical,
+
+    /// Symétrie par rapport à la diagonale qui part du coin supérieur gauche
+    MainDiagonal,
+
+    /// Symétrie par rapport à la diagonale qui part du coin supérieur droit
+    AntiDiagonal,
+}
+
+impl Symmetry {
+    /// Liste de toutes les symétries
+    pub const ALL: [Symmetry; 6] = [
+        Symmetry::Rotation90,
+        Symmetry::Rotation180,
+        Symmetry::MirrorHorizontal,
+        Symmetry::MirrorVertical,
+        Symmetry::MainDiagonal,
+        Symmetry::AntiDiagonal,
+    ];
+
+    /// Grille transformée par cette symétrie
+    fn apply(self, grid: &Grid) -> Grid {
+        match self {
+            Symmetry::Rotation90 => grid.rotated_90(),
+            Symmetry::Rotation180 => grid.rotated_90().rotated_90(),
+            Symmetry::MirrorHorizontal => grid.mirrored_horizontal(),
+            Symmetry::MirrorVertical => grid.mirrored_vertical(),
+            Symmetry::MainDiagonal => grid.transposed(),
+            Symmetry::AntiDiagonal => grid.transposed().rotated_90().rotated_90(),
+        }
+    }
+}
+
+impl fmt::Display for Symmetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Symmetry::Rotation90 => "Rotation d'un quart de tour",
+            Symmetry::Rotation180 => "Rotation d'un demi-tour",
+            Symmetry::MirrorHorizontal => "Symétrie gauche/droite",
+            Symmetry::MirrorVertical => "Symétrie haut/bas",
+            Symmetry::MainDiagonal => "Symétrie par rapport à la diagonale principale",
+            Symmetry::AntiDiagonal => "Symétrie par rapport à l'autre diagonale",
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl Grid {
+    /// Liste des symétries de la grille : transformations qui conservent la forme de la grille
+    /// et le découpage en zones
+    ///
+    /// Si `with_clues` est true, une symétrie doit aussi conserver la position des cases dont le
+    /// chiffre est connu (quels que soient ces chiffres)
+    #[must_use]
+    pub fn symmetries(&self, with_clues: bool) -> Vec<Symmetry> {
+        let pattern = self.symmetry_pattern(with_clues);
+        Symmetry::ALL
+            .into_iter()
+            .filter(|symmetry| symmetry.apply(&pattern).normalized() == pattern)
+            .collect()
+    }
+
+    /// Forme de la grille et découpage en zones (avec la position des chiffres connus si
+    /// `with_clues` est true) utilisés pour la recherche des symétries
+    fn symmetry_pattern(&self, with_clues: bool) -> Grid {
+        let mut pattern = self.normalized();
+        for cell in pattern.hashmap_cells.values_mut() {
+            cell.content = match cell.content {
+                CellContent::Number(_) if with_clues => CellContent::Number(1),
+                _ => CellContent::Undefined,
+            };
+        }
+        pattern
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_symmetries() {
+        let grid = Grid::from_str(
+            "
+        a1 a  b
+        c  d  b
+        c  e  e2
+        ",
+        )
+        .unwrap();
+        assert_eq!(
+            grid.symmetries(false),
+            vec![Symmetry::Rotation90, Symmetry::Rotation180]
+        );
+        assert_eq!(grid.symmetries(true), vec![Symmetry::Rotation180]);
+
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        assert!(grid.symmetries(false).is_empty());
+
+        let grid = Grid::from_str("a  b  b\na  b  b\n").unwrap();
+        assert_eq!(grid.symmetries(false), vec![Symmetry::MirrorVertical]);
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+use crate::line_column::LineColumn;
+use crate::solver::{Solver, SolvingAction, SolvingError, SolvingStrategy};
+
+/// Technique de résolution suggérée pour progresser dans une grille (voir
+/// `Solver::suggest_technique`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TechniqueHint {
+    /// Stratégie de résolution la plus simple qui permet de progresser
+    pub strategy: SolvingStrategy,
+
+    /// Cases concernées par cette stratégie (triées par ligne puis colonne)
+    pub cells: Vec<LineColumn>,
+
+    /// Action que produirait cette stratégie
+    pub action: SolvingAction,
+}
+
+impl fmt::Display for TechniqueHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stratégie à utiliser : {}", self.strategy)?;
+        for (index, line_column) in self.cells.iter().enumerate() {
+            let separator = if index == 0 { " pour " } else { ", " };
+            write!(f, "{separator}{line_column}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Solver {
+    /// Suggère la stratégie de résolution la plus simple qui permet de progresser depuis l'état
+    /// courant de la grille, avec les cases concernées
+    ///
+    /// Cette suggestion est utile pour guider une résolution manuelle bloquée. L'état de travail
+    /// du solver n'est pas modifié.
+    ///
+    /// Retourne `None` si la grille est résolue ou si aucune stratégie ne permet de progresser
+    /// # Errors
+    /// Une erreur est retournée si la grille n'est pas (ou plus) cohérente
+    pub fn suggest_technique(&self) -> Result<Option<TechniqueHint>, SolvingError> {
+        self.check()?;
+
+        let mut solver = self.working_copy();
+        if solver.is_solved() {
+            return Ok(None);
+        }
+
+        // Une stratégie qui ne trouve aucune action ne modifie pas la grille
+        for strategy in SolvingStrategy::ALL {
+            let action = solver.apply_strategy(strategy);
+            if action == SolvingAction::NoAction {
+                continue;
+            }
+
+            let mut cells = action.affected_cells();
+            if let SolvingAction::DualValuesPair(line_column_pair_1, line_column_pair_2, ..) =
+                &action
+            {
+                cells.push(*line_column_pair_1);
+                cells.push(*line_column_pair_2);
+            }
+            cells.sort_by_key(|line_column| (line_column.line, line_column.column));
+            cells.dedup();
+
+            return Ok(Some(TechniqueHint {
+                strategy,
+                cells,
+                action,
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use crate::solver_config::SolverConfig;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_suggest_technique() {
+        let grid = Grid::from_str(
+            "
+        a1 b  b2
+        b4 b  b
+        c  c  c2
+        ",
+        )
+        .unwrap();
+
+        let solver = Solver::new(&grid);
+        let hint = solver.suggest_technique().unwrap().unwrap();
+        assert_eq!(hint.action.strategy(), Some(hint.strategy));
+        assert!(!hint.cells.is_empty());
+        for line_column in hint.action.affected_cells() {
+            assert!(hint.cells.contains(&line_column));
+        }
+
+        // L'état de travail du solver n'est pas modifié
+        assert_eq!(solver.to_grid().to_string(), grid.to_string());
+        assert!(solver.history().is_empty());
+    }
+
+    #[test]
+    fn test_suggest_technique_stuck() {
+        let grid = Grid::from_str(
+            "
+            # Jeu Le Routard no 13 - page 38 (niveau rouge)
+            a  a5 a  b  b
+            c  a  a  b3 b
+            d  e  e  e  f
+            d5 d  e2 e  g
+            d  d  g  g1 g
+        ",
+        )
+        .unwrap();
+
+        // Résolution bloquée sans recherche par 'essai' : seule cette stratégie permet de progresser
+        let mut solver = Solver::new(&grid);
+        let outcome = solver.solve(&SolverConfig::new().max_recursion(0));
+        assert!(!outcome.is_solved());
+        solver.max_try_and_see_recursion_level = 1;
+        let hint = solver.suggest_technique().unwrap().unwrap();
+        assert_eq!(hint.strategy, SolvingStrategy::TryAndSee);
+        assert_eq!(hint.cells.len(), 1);
+
+        // Grille résolue : aucune suggestion
+        assert!(solver.solve(&SolverConfig::default()).is_solved());
+        assert_eq!(solver.suggest_technique().unwrap(), None);
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::Write;
+
+use crate::grid::{CellContent, Grid};
+use crate::line_column::LineColumn;
+use crate::zone_border::CellSide;
+
+/// Options du dessin TikZ d'une grille avec `Grid::to_tikz`
+///
+/// Les options se construisent par chaînage :
+///
+/// ```rust
+/// use tectonic::TikzOptions;
+///
+/// let options = TikzOptions::new().cell_size(10).given_font("\\sffamily\\bfseries\\huge");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TikzOptions {
+    /// Taille d'une case (en millimètres)
+    pub(crate) cell_size: u32,
+
+    /// Police des chiffres connus au départ
+    pub(crate) given_font: String,
+
+    /// Police des chiffres placés pendant la résolution
+    pub(crate) placed_font: String,
+}
+
+impl Default for TikzOptions {
+    fn default() -> Self {
+        TikzOptions {
+            cell_size: 8,
+            given_font: "\\bfseries\\Large".to_string(),
+            placed_font: "\\Large".to_string(),
+        }
+    }
+}
+
+impl TikzOptions {
+    /// Options par défaut : cases de 8 mm, chiffres en `\Large` (en gras pour les chiffres connus
+    /// au départ)
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Taille d'une case en millimètres (au moins 1 mm)
+    #[must_use]
+    pub fn cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size.max(1);
+        self
+    }
+
+    /// Commandes LaTeX de la police des chiffres connus au départ (par exemple `\bfseries\Large`)
+    #[must_use]
+    pub fn given_font(mut self, given_font: &str) -> Self {
+        self.given_font = given_font.to_string();
+        self
+    }
+
+    /// Commandes LaTeX de la police des chiffres placés pendant la résolution
+    #[must_use]
+    pub fn placed_font(mut self, placed_font: &str) -> Self {
+        self.placed_font = placed_font.to_string();
+        self
+    }
+}
+
+impl Grid {
+    /// Dessin TikZ (environnement `tikzpicture`) de la grille pour une publication imprimée :
+    /// fines bordures entre les cases d'une même zone, bordures épaisses entre deux zones et
+    /// autour de la grille, chiffres connus au départ et chiffres placés (en bleu)
+    ///
+    /// Le document LaTeX doit charger le paquet `tikz`. Une case absente de la grille (trou) n'est
+    /// pas dessinée
+    #[must_use]
+    pub fn to_tikz(&self, options: &TikzOptions) -> String {
+        let (min, _) = self.bounds().unwrap_or_default();
+
+        // Coin supérieur gauche d'une case dans le dessin (en nombre de cases, y vers le bas)
+        let origin = |line_column: LineColumn| {
+            (line_column.column - min.column, line_column.line - min.line)
+        };
+
+        let (mut thin_path, mut thick_path, mut nodes) =
+            (String::new(), String::new(), String::new());
+        for (line_column, cell) in self.cells() {
+            let (x, y) = origin(*line_column);
+            write!(thin_path, " ({x},{y}) rectangle +(1,1)").unwrap();
+
+            if let CellContent::Number(n) = cell.content {
+                let style = if cell.is_given {
+                    format!("font={{{}}}", options.given_font)
+                } else {
+                    format!("font={{{}}},placed", options.placed_font)
+                };
+                writeln!(nodes, "\\node[{style}] at ({x}.5,{y}.5) {{{n}}};").unwrap();
+            }
+        }
+
+        // Bordures épaisses entre les zones et autour de la grille
+        for (line_column, side) in self.zone_borders() {
+            let (x, y) = origin(line_column);
+            match side {
+                CellSide::Top => write!(thick_path, " ({x},{y}) -- +(1,0)"),
+                CellSide::Right => write!(thick_path, " ({},{y}) -- +(0,1)", x + 1),
+                CellSide::Bottom => write!(thick_path, " ({x},{}) -- +(1,0)", y + 1),
+                CellSide::Left => write!(thick_path, " ({x},{y}) -- +(0,1)"),
+            }
+            .unwrap();
+        }
+
+        let size = options.cell_size;
+        let mut res = format!(
+            "\\begin{{tikzpicture}}[x={size}mm,y=-{size}mm,\
+             placed/.style={{color={{rgb,255:red,47;green,95;blue,179}}}}]\n"
+        );
+        if !thin_path.is_empty() {
+            writeln!(res, "\\draw[gray!60,line width=0.4pt]{thin_path};").unwrap();
+            writeln!(res, "\\draw[line width=1.6pt,line cap=rect]{thick_path};").unwrap();
+        }
+        res.push_str(&nodes);
+        res.push_str("\\end{tikzpicture}\n");
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_tikz() {
+        let mut grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let tikz = grid.to_tikz(&TikzOptions::default());
+        assert!(tikz.starts_with("\\begin{tikzpicture}[x=8mm,y=-8mm,"));
+        assert!(tikz.ends_with("\\end{tikzpicture}\n"));
+        assert_eq!(tikz.matches("rectangle").count(), 9);
+        let thick_path = tikz
+            .lines()
+            .find(|line| line.contains("line width=1.6pt"))
+            .unwrap();
+        assert_eq!(thick_path.matches("--").count(), grid.zone_borders().len());
+        assert!(tikz.contains("\\node[font={\\bfseries\\Large}] at (0.5,0.5) {1};"));
+        assert_eq!(tikz.matches("\\node").count(), 4);
+
+        // Chiffre placé et options
+        grid.get_mut_cell(LineColumn::new(0, 1)).unwrap().content = CellContent::Number(3);
+        let tikz = grid.to_tikz(
+            &TikzOptions::new()
+                .cell_size(12)
+                .given_font("\\sffamily")
+                .placed_font("\\small"),
+        );
+        assert!(tikz.starts_with("\\begin{tikzpicture}[x=12mm,y=-12mm,"));
+        assert!(tikz.contains("\\node[font={\\small},placed] at (1.5,0.5) {3};"));
+        assert!(tikz.contains("\\node[font={\\sffamily}] at (0.5,0.5) {1};"));
+
+        // Grille vide
+        assert_eq!(
+            Grid::default()
+                .to_tikz(&TikzOptions::default())
+                .lines()
+                .count(),
+            2
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Write;
+
+use crate::action_formatter::ActionFormatter;
+use crate::solver::{Solver, SolvingAction};
+use crate::svg::SvgOptions;
+
+/// Feuille de style de la page de résolution pas à pas
+const STYLE: &str = "\
+body{font-family:sans-serif;margin:2em}\
+.controls{display:flex;align-items:center;gap:1em;margin-bottom:1em}\
+.controls input{flex:1;max-width:30em}\
+.step p{margin:0.3em 0}";
+
+/// Script de la page : affichage d'une seule étape, choisie avec le curseur, les boutons ou les
+/// flèches du clavier
+const SCRIPT: &str = "\
+const steps = document.querySelectorAll('.step');
+const slider = document.getElementById('slider');
+const counter = document.getElementById('counter');
+function show(index) {
+  index = Math.max(0, Math.min(steps.length - 1, index));
+  slider.value = index;
+  steps.forEach((step, i) => { step.hidden = i !== index; });
+  counter.textContent = `${index} / ${steps.length - 1}`;
+}
+slider.addEventListener('input', () => show(Number(slider.value)));
+document.getElementById('prev').addEventListener('click', () => show(Number(slider.value) - 1));
+document.getElementById('next').addEventListener('click', () => show(Number(slider.value) + 1));
+document.addEventListener('keydown', (event) => {
+  if (event.key === 'ArrowLeft') show(Number(slider.value) - 1);
+  if (event.key === 'ArrowRight') show(Number(slider.value) + 1);
+});
+show(0);";
+
+/// Texte avec les caractères spéciaux du HTML remplacés par leurs entités
+fn html_escaped(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Solver {
+    /// Page HTML autonome (sans fichier ni bibliothèque externe) de la résolution pas à pas
+    /// enregistrée par le solver (voir `history`) pour la partager comme un tutoriel : la grille
+    /// à résoudre, puis chaque action avec son explication (mise en forme avec `formatter`, par
+    /// exemple `Lang::French`) et le dessin de la grille avant l'action, les cases modifiées et les
+    /// cases qui justifient l'action étant surlignées (voir `Grid::to_action_svg`)
+    ///
+    /// Un curseur, les boutons 'Précédent' et 'Suivant' et les flèches du clavier permettent de
+    /// passer d'une étape à l'autre. Le titre de la première étape, les libellés des boutons et
+    /// la langue de la page sont ceux de `formatter` (voir `ActionFormatter::step_buttons`)
+    #[must_use]
+    pub fn to_html_walkthrough(&self, title: &str, formatter: &dyn ActionFormatter) -> String {
+        let options = SvgOptions::new().candidates(true);
+
+        // Première étape : la grille à résoudre
+        let original_grid = self.shared_original_grid();
+        let mut steps = vec![(formatter.puzzle_step(), original_grid.to_svg(&options))];
+
+        // Une étape par action, en rejouant les actions sur la grille à résoudre
+        let mut solver = Solver::new(original_grid);
+        for action in self.history() {
+            let grid = solver.to_grid();
+            let svg = match action {
+                SolvingAction::Solved => grid.to_svg(&options),
+                _ => grid.to_action_svg(action, &options),
+            };
+            steps.push((action.format_with(formatter), svg));
+            if solver.replay(std::slice::from_ref(action)).is_err() {
+                break;
+            }
+        }
+
+        let title = html_escaped(title);
+        let lang = html_escaped(&formatter.language_tag());
+        let (previous, next) = formatter.step_buttons();
+        let (previous, next) = (html_escaped(&previous), html_escaped(&next));
+        let mut res = format!(
+            "<!DOCTYPE html>\n<html lang=\"{lang}\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n\
+             <div class=\"controls\">\n<button id=\"prev\">{previous}</button>\n\
+             <input id=\"slider\" type=\"range\" min=\"0\" max=\"{}\" value=\"0\">\n\
+             <button id=\"next\">{next}</button>\n<span id=\"counter\"></span>\n</div>\n",
+            steps.len() - 1
+        );
+        for (index, (text, svg)) in steps.iter().enumerate() {
+            let hidden = if index == 0 { "" } else { " hidden" };
+            writeln!(res, "<section class=\"step\"{hidden}>").unwrap();
+            for line in text.lines() {
+                writeln!(res, "<p>{}</p>", html_escaped(line)).unwrap();
+            }
+            writeln!(res, "{svg}</section>").unwrap();
+        }
+        write!(res, "<script>\n{SCRIPT}\n</script>\n</body>\n</html>\n").unwrap();
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::grid::Grid;
+    use crate::lang::Lang;
+    use crate::solver_config::SolverConfig;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_to_html_walkthrough() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let mut solver = Solver::new(&grid);
+        assert!(solver.solve(&SolverConfig::new()).is_solved());
+        let html = solver.to_html_walkthrough("Grille <1>", &Lang::French);
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.ends_with("</html>\n"));
+        assert!(html.contains("<h1>Grille &lt;1&gt;</h1>"));
+
+        // La grille à résoudre puis une étape par action enregistrée
+        let nb_steps = solver.history().len() + 1;
+        assert_eq!(html.matches("<section class=\"step\"").count(), nb_steps);
+        assert_eq!(
+            html.matches("<section class=\"step\" hidden>").count(),
+            nb_steps - 1
+        );
+        assert!(html.contains(&format!("max=\"{}\"", nb_steps - 1)));
+        assert!(html.contains("<html lang=\"fr\">"));
+        assert!(html.contains("<p>Grille à résoudre</p>"));
+        assert!(html.contains("<button id=\"prev\">Précédent</button>"));
+        assert!(html.contains("<p>Grille résolue</p>"));
+        assert_eq!(html.matches("<svg ").count(), nb_steps);
+        assert!(html.contains("#fff2a8"));
+
+        // Explications dans une autre langue
+        let html = solver.to_html_walkthrough("Grid", &Lang::English);
+        assert!(html.contains("<p>Grid solved</p>"));
+        assert!(html.contains("<html lang=\"en\">"));
+        assert!(html.contains("<p>Grid to solve</p>"));
+        assert!(html.contains("<button id=\"next\">Next</button>"));
+    }
+}
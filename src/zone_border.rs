@@ -0,0 +1,116 @@
+use crate::grid::Grid;
+use crate::line_column::LineColumn;
+
+/// Côté d'une case
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CellSide {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl CellSide {
+    /// Les 4 côtés d'une case, dans le sens des aiguilles d'une montre à partir du haut
+    pub const ALL: [CellSide; 4] = [
+        CellSide::Top,
+        CellSide::Right,
+        CellSide::Bottom,
+        CellSide::Left,
+    ];
+
+    /// Position de la case voisine de ce côté de la case `line_column`
+    #[must_use]
+    pub fn neighbor(self, line_column: LineColumn) -> LineColumn {
+        let (line, column) = (line_column.line, line_column.column);
+        match self {
+            CellSide::Top => LineColumn::new(line - 1, column),
+            CellSide::Right => LineColumn::new(line, column + 1),
+            CellSide::Bottom => LineColumn::new(line + 1, column),
+            CellSide::Left => LineColumn::new(line, column - 1),
+        }
+    }
+}
+
+impl Grid {
+    /// Retourne true si le côté `side` de la case `line_column` est une bordure de zone : la case
+    /// voisine de ce côté est dans une autre zone ou absente de la grille
+    #[must_use]
+    pub fn is_zone_border(&self, line_column: LineColumn, side: CellSide) -> bool {
+        let c_zone = self.get_cell(line_column).map(|cell| cell.c_zone);
+        let neighbor_zone = self
+            .get_cell(side.neighbor(line_column))
+            .map(|cell| cell.c_zone);
+        neighbor_zone.is_none() || neighbor_zone != c_zone
+    }
+
+    /// Bordures des zones à dessiner en trait épais (entre deux zones et autour de la grille),
+    /// chacune une seule fois et triées par case : côtés droit et bas d'une case si la case
+    /// voisine est dans une autre zone (ou absente), côtés gauche et haut s'il n'y a pas de case
+    /// voisine
+    #[must_use]
+    pub fn zone_borders(&self) -> Vec<(LineColumn, CellSide)> {
+        let mut vec_line_column: Vec<LineColumn> = self.hashmap_cells.keys().copied().collect();
+        vec_line_column.sort_by_key(|line_column| (line_column.line, line_column.column));
+
+        let mut res = Vec::new();
+        for line_column in vec_line_column {
+            for side in CellSide::ALL {
+                let is_drawn = match side {
+                    CellSide::Right | CellSide::Bottom => self.is_zone_border(line_column, side),
+                    CellSide::Top | CellSide::Left => {
+                        self.get_cell(side.neighbor(line_column)).is_none()
+                    }
+                };
+                if is_drawn {
+                    res.push((line_column, side));
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_zone_borders() {
+        let grid = Grid::from_str("a1 b  b2\nb4 b  b\nc  c  c2").unwrap();
+        let line_column = LineColumn::new(1, 0);
+        assert!(grid.is_zone_border(line_column, CellSide::Top));
+        assert!(!grid.is_zone_border(line_column, CellSide::Right));
+        assert!(grid.is_zone_border(line_column, CellSide::Bottom));
+        assert!(grid.is_zone_border(line_column, CellSide::Left));
+
+        // 12 côtés autour de la grille et 5 côtés entre les zones
+        let vec_borders = grid.zone_borders();
+        assert_eq!(vec_borders.len(), 17);
+        assert_eq!(
+            vec_borders[..3],
+            [
+                (LineColumn::new(0, 0), CellSide::Top),
+                (LineColumn::new(0, 0), CellSide::Right),
+                (LineColumn::new(0, 0), CellSide::Bottom),
+            ]
+        );
+
+        // Côtés d'une case voisine d'un trou
+        let mut grid = Grid::from_str("a1 a2\na  b1").unwrap();
+        grid.remove_cell(LineColumn::new(1, 1));
+        let vec_sides: Vec<CellSide> = grid
+            .zone_borders()
+            .into_iter()
+            .filter(|(line_column, _)| *line_column == LineColumn::new(0, 1))
+            .map(|(_, side)| side)
+            .collect();
+        assert_eq!(
+            vec_sides,
+            vec![CellSide::Top, CellSide::Right, CellSide::Bottom]
+        );
+        assert!(Grid::default().zone_borders().is_empty());
+    }
+}